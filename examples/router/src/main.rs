@@ -83,7 +83,7 @@ fn UsersLayout(route: UsersRoute) -> impl Mount + MountRef {
         )
         .style("border-bottom: 2px solid #eee; padding-bottom: 10px; margin-bottom: 20px;"),
         // 渲染子路由
-        route.render(),
+        Outlet(route.clone()),
     )
 }
 
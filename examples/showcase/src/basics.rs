@@ -131,13 +131,13 @@ pub fn SvgIconDemo() -> impl Mount + MountRef {
     #[component]
     fn ShieldCheck() -> Element {
         svg(path()
-            .attr("stroke-linecap", "round")
-            .attr("stroke-linejoin", "round")
-            .attr("stroke-width", "2")
-            .attr("d", "M9 12l2 2 4-4m6 2a9 9 0 11-18 0 9 9 0 0118 0z"))
-        .attr("viewBox", "0 0 24 24")
-        .attr("fill", "none")
-        .attr("stroke", "currentColor")
+            .stroke_linecap("round")
+            .stroke_linejoin("round")
+            .stroke_width(2)
+            .d("M9 12l2 2 4-4m6 2a9 9 0 11-18 0 9 9 0 0118 0z"))
+        .view_box(0, 0, 24, 24)
+        .fill("none")
+        .stroke("currentColor")
         .attr("width", "24")
         .attr("height", "24")
     }
@@ -1,9 +1,99 @@
+pub mod aspect_ratio;
 pub mod error_boundary;
+pub mod highlight;
 pub mod layout;
+pub mod lazy;
+pub mod modal;
 pub mod portal;
 pub mod suspense;
+pub mod toast;
+pub mod tooltip;
 
+pub use aspect_ratio::*;
 pub use error_boundary::*;
+pub use highlight::*;
 pub use layout::*;
+pub use lazy::*;
+pub use modal::*;
 pub use portal::*;
 pub use suspense::*;
+pub use toast::*;
+pub use tooltip::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::testing::{MountLog, Probe, mount, mount_ref};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[component]
+    fn Panel(#[prop(default)] title: String, #[prop(default)] child: Children) -> impl Mount {
+        let _ = title;
+        child
+    }
+
+    #[component]
+    fn Layout(#[slot] header: Children, #[slot] body: Children) -> impl Mount {
+        view_chain!(header, body)
+    }
+
+    #[component]
+    fn Dialog(#[prop(optional)] on_close: Callback<()>) -> impl Mount {
+        if let Some(on_close) = *on_close {
+            on_close.call(());
+        }
+    }
+
+    #[test]
+    fn omitted_children_mount_nothing() {
+        assert_eq!(Children::default(), SharedView::Empty);
+        assert!(matches!(AnyView::default(), AnyView::Empty));
+
+        create_scope(|| {
+            let log = MountLog::default();
+            mount(Panel());
+            mount_ref(&Panel().title("untitled"));
+            assert!(log.borrow().is_empty());
+
+            mount(Panel().child(Probe::marker(&log, "child")));
+            assert_eq!(*log.borrow(), vec!["child"]);
+        });
+    }
+
+    #[test]
+    fn named_slots_mount_in_position() {
+        create_scope(|| {
+            let log = MountLog::default();
+
+            // 设置顺序不影响挂载顺序
+            mount(
+                Layout()
+                    .body(Probe::marker(&log, "body"))
+                    .header(Probe::marker(&log, "header")),
+            );
+            assert_eq!(*log.borrow(), vec!["header", "body"]);
+
+            log.borrow_mut().clear();
+            mount_ref(&Layout().body(Probe::marker(&log, "body")));
+            assert_eq!(*log.borrow(), vec!["body"]);
+        });
+    }
+
+    #[test]
+    fn optional_prop_defaults_to_none() {
+        create_scope(|| {
+            assert!(Dialog().on_close.is_none());
+
+            let closed = Rc::new(Cell::new(false));
+            let dialog = Dialog().on_close({
+                let closed = closed.clone();
+                move |_| closed.set(true)
+            });
+            assert!(dialog.on_close.is_some());
+
+            mount(dialog);
+            assert!(closed.get());
+        });
+    }
+}
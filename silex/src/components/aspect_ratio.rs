@@ -0,0 +1,97 @@
+use crate::prelude::*;
+use silex_css::types::{ValidFor, props};
+use std::fmt;
+
+/// 宽高比 (宽 : 高)，渲染为 CSS `aspect-ratio` 的取值 (e.g. `16 / 9`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ratio {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Ratio {
+    pub fn new(width: f64, height: f64) -> Self {
+        Self { width, height }
+    }
+}
+
+impl Default for Ratio {
+    fn default() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+impl From<f64> for Ratio {
+    fn from(ratio: f64) -> Self {
+        Self::new(ratio, 1.0)
+    }
+}
+
+impl From<(f64, f64)> for Ratio {
+    fn from((width, height): (f64, f64)) -> Self {
+        Self::new(width, height)
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} / {}", self.width, self.height)
+    }
+}
+
+impl ValidFor<props::AspectRatio> for Ratio {}
+
+/// 宽高比容器：宽度撑满父元素，高度按 `ratio` 自动计算，子元素铺满整个容器。
+///
+/// 常用于响应式图片、视频等媒体，例如 `AspectRatio().ratio(Ratio::new(16.0, 9.0)).children(img())`。
+#[component]
+pub fn AspectRatio(
+    #[prop(default, into)] ratio: Signal<Ratio>,
+    #[prop(default)] children: Children,
+) -> impl Mount {
+    div(children).class(aspect_ratio_class(ratio.clone()))
+}
+
+fn aspect_ratio_class(ratio: Signal<Ratio>) -> DynamicCss {
+    css! {
+        position: relative;
+        width: 100%;
+        aspect-ratio: $(ratio);
+        overflow: hidden;
+
+        & > * {
+            position: absolute;
+            inset: 0;
+            width: 100%;
+            height: 100%;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_is_bound_to_a_scoped_css_variable() {
+        create_scope(|| {
+            let ratio = RwSignal::new(Ratio::new(16.0, 9.0));
+            let class = aspect_ratio_class(ratio.into());
+            assert!(class.class_name.starts_with("slx-"), "{}", class.class_name);
+            assert!(class.rules.is_empty());
+
+            // `aspect-ratio: $(ratio)` 编译为作用域类名下的 CSS 变量
+            let [(name, value)] = class.vars.as_slice() else {
+                panic!("expected a single variable");
+            };
+            assert_eq!(*name, format!("--{}-0", class.class_name));
+            assert_eq!(value.get(), "16 / 9");
+
+            ratio.set(Ratio::from(1.5));
+            assert_eq!(value.get(), "1.5 / 1");
+        });
+
+        assert_eq!(Ratio::default().to_string(), "1 / 1");
+        assert_eq!(Ratio::from((4.0, 3.0)).to_string(), "4 / 3");
+    }
+}
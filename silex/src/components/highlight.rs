@@ -0,0 +1,137 @@
+use crate::prelude::*;
+
+/// 高亮片段：`(文本, 是否匹配)`
+pub type HighlightSegment = (String, bool);
+
+/// 将 `text` 按 `query` 切分为连续的片段
+///
+/// 匹配不区分大小写，从左到右进行且互不重叠；`query` 为空时整段文本不匹配。
+pub fn highlight_segments(text: &str, query: &str) -> Vec<HighlightSegment> {
+    let needle: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    let mut segments = Vec::new();
+    if needle.is_empty() {
+        if !text.is_empty() {
+            segments.push((text.to_string(), false));
+        }
+        return segments;
+    }
+
+    let mut plain_start = 0;
+    let mut cursor = 0;
+    while cursor < text.len() {
+        if let Some(len) = match_len(&text[cursor..], &needle) {
+            if plain_start < cursor {
+                segments.push((text[plain_start..cursor].to_string(), false));
+            }
+            segments.push((text[cursor..cursor + len].to_string(), true));
+            cursor += len;
+            plain_start = cursor;
+        } else {
+            cursor += text[cursor..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    if plain_start < text.len() {
+        segments.push((text[plain_start..].to_string(), false));
+    }
+    segments
+}
+
+/// `text` 开头与 `needle` (已转小写) 匹配时返回匹配部分的字节长度
+fn match_len(text: &str, needle: &[char]) -> Option<usize> {
+    let mut expected = needle.iter();
+    let mut next = expected.next();
+    for (idx, ch) in text.char_indices() {
+        for lower in ch.to_lowercase() {
+            match next {
+                Some(&c) if c == lower => next = expected.next(),
+                _ => return None,
+            }
+        }
+        if next.is_none() {
+            return Some(idx + ch.len_utf8());
+        }
+    }
+    None
+}
+
+/// 随 `text` 与 `query` 变化的高亮片段
+pub fn highlight(text: Signal<String>, query: Signal<String>) -> Memo<Vec<HighlightSegment>> {
+    Memo::new(move |_| text.with(|text| query.with(|query| highlight_segments(text, query))))
+}
+
+/// 搜索高亮：将 `text` 中与 `query` 匹配的部分包裹在 `<mark>` 中，随查询实时更新。
+#[component]
+pub fn Highlight(
+    #[prop(into)] text: Signal<String>,
+    #[prop(default, into)] query: Signal<String>,
+) -> impl Mount {
+    span(Dynamic::bind(
+        highlight(text.clone(), query.clone()),
+        |segments| {
+            segments
+                .into_iter()
+                .map(|(segment, matched)| {
+                    if matched {
+                        mark(segment).into_any()
+                    } else {
+                        segment.into_any()
+                    }
+                })
+                .collect::<Vec<_>>()
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marked(segments: &[HighlightSegment]) -> Vec<&str> {
+        segments
+            .iter()
+            .filter(|(_, matched)| *matched)
+            .map(|(s, _)| s.as_str())
+            .collect()
+    }
+
+    fn joined(segments: &[HighlightSegment]) -> String {
+        segments.iter().map(|(s, _)| s.as_str()).collect()
+    }
+
+    #[test]
+    fn segments_follow_query_changes() {
+        create_scope(|| {
+            let query = RwSignal::new(String::new());
+            let segments = highlight("Rust trusts rustaceans".into(), query.into());
+            assert_eq!(
+                segments.get(),
+                vec![("Rust trusts rustaceans".to_string(), false)]
+            );
+
+            query.set("RUST".to_string());
+            assert_eq!(marked(&segments.get()), vec!["Rust", "rust", "rust"]);
+            assert_eq!(
+                joined(&segments.get()),
+                "Rust trusts rustaceans",
+                "segments cover the whole text"
+            );
+
+            query.set("ts r".to_string());
+            assert_eq!(marked(&segments.get()), vec!["ts r"]);
+        });
+    }
+
+    #[test]
+    fn matches_do_not_overlap() {
+        let segments = highlight_segments("aaaa", "aa");
+        assert_eq!(
+            segments,
+            vec![("aa".to_string(), true), ("aa".to_string(), true)]
+        );
+        assert_eq!(
+            marked(&highlight_segments("ÄBC äbc", "äb")),
+            vec!["ÄB", "äb"]
+        );
+        assert!(highlight_segments("", "x").is_empty());
+    }
+}
@@ -0,0 +1,209 @@
+use silex_core::reactivity::{RwSignal, SuspenseContext, use_suspense_context};
+use silex_core::traits::{RxGet, RxWrite};
+use silex_dom::prelude::{ApplyAttributes, AutoReactiveView, Mount, MountRef};
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use web_sys::Node;
+
+type LazyFuture<V> = Pin<Box<dyn Future<Output = V>>>;
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+struct LazyState<V> {
+    factory: Box<dyn Fn() -> LazyFuture<V>>,
+    view: RefCell<Option<V>>,
+    loading: Cell<bool>,
+    /// 等待加载完成的挂载点及其所在的 Suspense 上下文
+    waiters: RefCell<Vec<(RwSignal<bool>, Option<SuspenseContext>)>>,
+}
+
+/// 延迟加载的视图，见 [`lazy`]
+pub struct Lazy<V> {
+    state: Rc<LazyState<V>>,
+}
+
+impl<V> Clone for Lazy<V> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+/// 延迟加载组件：首次挂载时才执行异步工厂函数，完成后挂载得到的视图。
+///
+/// 加载期间会计入所在的 [`SuspenseContext`]，由 `SuspenseBoundary` 显示 fallback。
+/// 加载结果被缓存，同一个 `Lazy` (及其克隆) 再次挂载时直接复用，不会重新执行工厂函数。
+///
+/// ```rust,ignore
+/// let settings = lazy(|| async { build_settings_page().await });
+///
+/// Suspense::new().children(move |_| {
+///     SuspenseBoundary::new()
+///         .fallback(|| "Loading...")
+///         .children(settings.clone())
+/// })
+/// ```
+pub fn lazy<F, Fut, V>(factory: F) -> Lazy<V>
+where
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = V> + 'static,
+    V: 'static,
+{
+    Lazy {
+        state: Rc::new(LazyState {
+            factory: Box::new(move || Box::pin(factory())),
+            view: RefCell::new(None),
+            loading: Cell::new(false),
+            waiters: RefCell::new(Vec::new()),
+        }),
+    }
+}
+
+impl<V: Clone + 'static> Lazy<V> {
+    /// 已加载时返回缓存的视图；否则登记 `ready`，在加载完成时将其置为 `true`
+    ///
+    /// 工厂函数只在尚未开始加载时执行，加载任务交给 `spawn`。
+    fn resolve(&self, ready: RwSignal<bool>, spawn: impl FnOnce(LocalFuture)) -> Option<V> {
+        if let Some(view) = self.state.view.borrow().clone() {
+            return Some(view);
+        }
+
+        let suspense = use_suspense_context();
+        if let Some(ctx) = &suspense {
+            ctx.increment();
+        }
+        self.state.waiters.borrow_mut().push((ready, suspense));
+
+        if !self.state.loading.replace(true) {
+            let state = self.state.clone();
+            let fut = (state.factory)();
+            spawn(Box::pin(async move {
+                let view = fut.await;
+                *state.view.borrow_mut() = Some(view);
+                state.loading.set(false);
+                let waiters = std::mem::take(&mut *state.waiters.borrow_mut());
+                for (ready, suspense) in waiters {
+                    ready.try_set(true);
+                    if let Some(ctx) = suspense {
+                        ctx.decrement();
+                    }
+                }
+            }));
+        }
+        None
+    }
+
+    fn mount_internal(self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>)
+    where
+        V: Mount,
+    {
+        let ready = RwSignal::new(false);
+        if self
+            .resolve(ready, wasm_bindgen_futures::spawn_local)
+            .is_some()
+        {
+            ready.set(true);
+        }
+
+        let state = self.state;
+        (move || {
+            if ready.get() {
+                state.view.borrow().clone()
+            } else {
+                None
+            }
+        })
+        .mount(parent, attrs);
+    }
+}
+
+impl<V> ApplyAttributes for Lazy<V> {}
+
+impl<V> Mount for Lazy<V>
+where
+    V: Mount + Clone + 'static,
+{
+    fn mount(self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        self.mount_internal(parent, attrs);
+    }
+}
+
+impl<V> AutoReactiveView for Lazy<V> where V: Mount + Clone + 'static {}
+
+impl<V> MountRef for Lazy<V>
+where
+    V: Mount + Clone + 'static,
+{
+    fn mount_ref(&self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        self.clone().mount_internal(parent, attrs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::{Context, Poll, Waker};
+
+    /// 在 `open` 被置为 `true` 之前保持 Pending
+    struct Gate(Rc<Cell<bool>>);
+
+    impl Future for Gate {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+            if self.0.get() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn fallback_until_resolved_then_cached() {
+        SuspenseContext::provide(|| {
+            let ctx = use_suspense_context().unwrap();
+            let runs = Rc::new(Cell::new(0));
+            let open = Rc::new(Cell::new(false));
+            let view = lazy({
+                let runs = runs.clone();
+                let open = open.clone();
+                move || {
+                    runs.set(runs.get() + 1);
+                    let gate = Gate(open.clone());
+                    async move {
+                        gate.await;
+                        "settings"
+                    }
+                }
+            });
+
+            let tasks = Rc::new(RefCell::new(Vec::<LocalFuture>::new()));
+            let spawn = |fut| tasks.borrow_mut().push(fut);
+            let poll = || {
+                let mut cx = Context::from_waker(Waker::noop());
+                tasks
+                    .borrow_mut()
+                    .retain_mut(|fut| fut.as_mut().poll(&mut cx).is_pending());
+            };
+
+            let first = RwSignal::new(false);
+            assert_eq!(view.resolve(first, spawn), None);
+            poll();
+            assert_eq!(ctx.count.get(), 1, "fallback shown while loading");
+            assert!(!first.get());
+
+            open.set(true);
+            poll();
+            assert!(first.get());
+            assert_eq!(ctx.count.get(), 0, "fallback hidden once resolved");
+
+            let second = RwSignal::new(false);
+            assert_eq!(view.clone().resolve(second, spawn), Some("settings"));
+            assert_eq!(runs.get(), 1, "factory is not re-run");
+            assert_eq!(ctx.count.get(), 0);
+        });
+    }
+}
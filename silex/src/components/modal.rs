@@ -0,0 +1,211 @@
+use crate::prelude::*;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+#[derive(Default)]
+struct Answer {
+    confirmed: Option<bool>,
+    waker: Option<Waker>,
+    /// 得到答复后移除对话框
+    teardown: Option<Box<dyn FnOnce()>>,
+}
+
+/// 等待用户答复的确认请求，交给对话框视图
+///
+/// 调用 [`accept`](Self::accept) 或 [`cancel`](Self::cancel) 完成请求，之后的调用被忽略。
+#[derive(Clone)]
+pub struct ConfirmRequest {
+    message: String,
+    answer: Rc<RefCell<Answer>>,
+}
+
+impl ConfirmRequest {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// 确认，`confirm` 返回 `true`
+    pub fn accept(&self) {
+        self.answer(true);
+    }
+
+    /// 取消 (包括点击遮罩与按下 Escape)，`confirm` 返回 `false`
+    pub fn cancel(&self) {
+        self.answer(false);
+    }
+
+    fn answer(&self, confirmed: bool) {
+        let mut answer = self.answer.borrow_mut();
+        if answer.confirmed.is_some() {
+            return;
+        }
+        answer.confirmed = Some(confirmed);
+        let waker = answer.waker.take();
+        let teardown = answer.teardown.take();
+        // 移除对话框会销毁其作用域，需先释放借用
+        drop(answer);
+        if let Some(teardown) = teardown {
+            teardown();
+        }
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// 等待 [`ConfirmRequest`] 被确认或取消
+struct Confirmation(Rc<RefCell<Answer>>);
+
+impl Future for Confirmation {
+    type Output = bool;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        let mut answer = self.0.borrow_mut();
+        match answer.confirmed {
+            Some(confirmed) => Poll::Ready(confirmed),
+            None => {
+                answer.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// 显示对话框，返回移除对话框的函数
+type ModalRenderer = Rc<dyn Fn(ConfirmRequest) -> Box<dyn FnOnce()>>;
+
+/// 以 `await` 等待用户选择的对话框，见 [`use_modal`]
+#[derive(Clone)]
+pub struct Modal {
+    render: ModalRenderer,
+}
+
+impl Modal {
+    fn with_renderer(render: ModalRenderer) -> Self {
+        Self { render }
+    }
+
+    /// 显示确认对话框，用户确认时返回 `true`，取消、点击遮罩或按下 Escape 时返回 `false`。
+    ///
+    /// 对话框在得到答复时立即移除。
+    pub fn confirm(&self, message: impl Into<String>) -> impl Future<Output = bool> + 'static {
+        let answer = Rc::new(RefCell::new(Answer::default()));
+        let teardown = (self.render)(ConfirmRequest {
+            message: message.into(),
+            answer: answer.clone(),
+        });
+        answer.borrow_mut().teardown = Some(teardown);
+        Confirmation(answer)
+    }
+}
+
+/// Hook: 获取确认对话框，将命令式的确认流程写成 `await`：
+///
+/// ```rust,ignore
+/// let modal = use_modal();
+/// button("删除").on(event::click, move |_| {
+///     let modal = modal.clone();
+///     spawn_local(async move {
+///         if modal.confirm("Delete?").await {
+///             delete_item();
+///         }
+///     });
+/// });
+/// ```
+pub fn use_modal() -> Modal {
+    Modal::with_renderer(Rc::new(|request| {
+        let body: web_sys::Node = silex_dom::document().body().expect("Body not found").into();
+        let scope = create_scope(|| Portal::new(confirm_dialog(request)).mount(&body, Vec::new()));
+        Box::new(move || dispose(scope))
+    }))
+}
+
+/// 默认的确认对话框：遮罩、提示文本与确认/取消按钮
+fn confirm_dialog(request: ConfirmRequest) -> impl Mount {
+    use_hotkey_with(
+        "escape",
+        HotkeyOptions::default().allow_in_editable(true),
+        {
+            let request = request.clone();
+            move || request.cancel()
+        },
+    );
+
+    let cancel = request.clone();
+    let backdrop_cancel = request.clone();
+    let accept = request.clone();
+    view_chain!(
+        div(())
+            .class("modal-backdrop")
+            .style("position: fixed; inset: 0; background: rgba(0, 0, 0, 0.4); z-index: 1000")
+            .on(event::click, move |_| backdrop_cancel.cancel()),
+        div!(
+            p(request.message().to_string()),
+            div!(
+                button("Cancel")
+                    .class("modal-cancel")
+                    .on(event::click, move |_| cancel.cancel()),
+                button("OK")
+                    .class("modal-confirm")
+                    .on(event::click, move |_| accept.accept()),
+            )
+            .class("modal-actions"),
+        )
+        .class("modal")
+        .role("dialog")
+        .attr("aria-modal", "true")
+        .style(
+            "position: fixed; left: 50%; top: 50%; transform: translate(-50%, -50%); z-index: 1001"
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn poll(fut: &mut Pin<Box<dyn Future<Output = bool>>>) -> Poll<bool> {
+        fut.as_mut().poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    /// 记录当前显示的请求，移除时清空
+    fn modal(shown: &Rc<RefCell<Option<ConfirmRequest>>>, mounted: &Rc<Cell<bool>>) -> Modal {
+        let shown = shown.clone();
+        let mounted = mounted.clone();
+        Modal::with_renderer(Rc::new(move |request| {
+            *shown.borrow_mut() = Some(request);
+            mounted.set(true);
+            let mounted = mounted.clone();
+            Box::new(move || mounted.set(false))
+        }))
+    }
+
+    #[test]
+    fn confirm_resolves_with_choice_and_removes_dialog() {
+        let shown = Rc::new(RefCell::new(None));
+        let mounted = Rc::new(Cell::new(false));
+        let modal = modal(&shown, &mounted);
+
+        let mut answer: Pin<Box<dyn Future<Output = bool>>> = Box::pin(modal.confirm("Delete?"));
+        assert!(mounted.get());
+        assert_eq!(poll(&mut answer), Poll::Pending);
+
+        let request = shown.borrow_mut().take().unwrap();
+        assert_eq!(request.message(), "Delete?");
+        request.accept();
+        assert!(!mounted.get(), "dialog removed on resolution");
+        assert_eq!(poll(&mut answer), Poll::Ready(true));
+
+        // 遮罩或 Escape 取消时返回 false，之后的确认被忽略
+        let mut answer: Pin<Box<dyn Future<Output = bool>>> = Box::pin(modal.confirm("Leave?"));
+        let request = shown.borrow_mut().take().unwrap();
+        request.cancel();
+        request.accept();
+        assert!(!mounted.get());
+        assert_eq!(poll(&mut answer), Poll::Ready(false));
+    }
+}
@@ -0,0 +1,273 @@
+use crate::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// 通知的类型，决定样式类名 (`toast-info` 等) 与无障碍角色
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastKind {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ToastKind::Info => "info",
+            ToastKind::Success => "success",
+            ToastKind::Warning => "warning",
+            ToastKind::Error => "error",
+        }
+    }
+}
+
+/// 一条待显示的通知
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+    pub id: u64,
+    pub kind: ToastKind,
+    pub message: String,
+}
+
+/// 取消尚未触发的定时器
+type CancelTimer = Box<dyn FnOnce()>;
+type Scheduler = Box<dyn Fn(Duration, Box<dyn FnOnce()>) -> CancelTimer>;
+
+struct ToastState {
+    next_id: Cell<u64>,
+    duration: Cell<Duration>,
+    timers: RefCell<HashMap<u64, CancelTimer>>,
+    schedule: Scheduler,
+}
+
+/// 通知服务：维护通知列表，每条通知在显示时长结束后自动移除。
+///
+/// 通过 [`provide_toast_service`] 提供到 Context，任意子组件以 [`use_toast`] 获取后入队；
+/// [`Toaster`] 将通知列表渲染到 `body` 下的 [`Portal`] 中。
+///
+/// ```rust,ignore
+/// provide_toast_service();
+/// // ...
+/// button("保存").on(event::click, move |_| use_toast().success("已保存"));
+/// // 在根组件中渲染一次
+/// Toaster()
+/// ```
+#[derive(Clone)]
+pub struct ToastService {
+    toasts: RwSignal<Vec<Toast>>,
+    state: Rc<ToastState>,
+}
+
+impl Default for ToastService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToastService {
+    /// 创建通知服务，默认显示时长为 4 秒；所在作用域销毁时取消所有定时器
+    pub fn new() -> Self {
+        Self::with_scheduler(Box::new(|duration, cb| {
+            match silex_dom::helpers::set_timeout_with_handle(cb, duration) {
+                Ok(handle) => Box::new(move || handle.clear()),
+                Err(_) => Box::new(|| {}),
+            }
+        }))
+    }
+
+    fn with_scheduler(schedule: Scheduler) -> Self {
+        let state = Rc::new(ToastState {
+            next_id: Cell::new(0),
+            duration: Cell::new(Duration::from_secs(4)),
+            timers: RefCell::new(HashMap::new()),
+            schedule,
+        });
+        on_cleanup({
+            let state = state.clone();
+            move || {
+                let timers = std::mem::take(&mut *state.timers.borrow_mut());
+                for cancel in timers.into_values() {
+                    cancel();
+                }
+            }
+        });
+        Self {
+            toasts: RwSignal::new(Vec::new()),
+            state,
+        }
+    }
+
+    /// 设置 `info` / `success` 等方法使用的默认显示时长
+    pub fn default_duration(self, duration: Duration) -> Self {
+        self.state.duration.set(duration);
+        self
+    }
+
+    /// 当前显示的通知 (按入队顺序)
+    pub fn toasts(&self) -> ReadSignal<Vec<Toast>> {
+        self.toasts.read_signal()
+    }
+
+    /// 入队一条通知，`duration` 后自动移除，返回通知 id
+    pub fn show(&self, kind: ToastKind, message: impl Into<String>, duration: Duration) -> u64 {
+        let id = self.state.next_id.get();
+        self.state.next_id.set(id + 1);
+        self.toasts.update(|toasts| {
+            toasts.push(Toast {
+                id,
+                kind,
+                message: message.into(),
+            })
+        });
+
+        let this = self.clone();
+        let cancel = (self.state.schedule)(duration, Box::new(move || this.expire(id)));
+        self.state.timers.borrow_mut().insert(id, cancel);
+        id
+    }
+
+    pub fn info(&self, message: impl Into<String>) -> u64 {
+        self.show(ToastKind::Info, message, self.state.duration.get())
+    }
+
+    pub fn success(&self, message: impl Into<String>) -> u64 {
+        self.show(ToastKind::Success, message, self.state.duration.get())
+    }
+
+    pub fn warning(&self, message: impl Into<String>) -> u64 {
+        self.show(ToastKind::Warning, message, self.state.duration.get())
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> u64 {
+        self.show(ToastKind::Error, message, self.state.duration.get())
+    }
+
+    /// 立即移除通知并取消其定时器
+    pub fn dismiss(&self, id: u64) {
+        let cancel = self.state.timers.borrow_mut().remove(&id);
+        if let Some(cancel) = cancel {
+            cancel();
+        }
+        self.remove(id);
+    }
+
+    /// 定时器触发：定时器已失效，只需移除通知
+    fn expire(&self, id: u64) {
+        self.state.timers.borrow_mut().remove(&id);
+        self.remove(id);
+    }
+
+    fn remove(&self, id: u64) {
+        // 服务所在作用域可能已销毁
+        self.toasts
+            .try_update(|toasts| toasts.retain(|toast| toast.id != id));
+    }
+}
+
+/// 创建通知服务并提供到当前作用域的 Context
+pub fn provide_toast_service() -> ToastService {
+    let service = ToastService::new();
+    provide_context(service.clone());
+    service
+}
+
+/// Hook: 获取通知服务
+pub fn use_toast() -> ToastService {
+    use_context::<ToastService>().expect("use_toast called outside of provide_toast_service")
+}
+
+/// 渲染 Context 中通知服务的通知列表，点击通知可立即关闭
+#[component]
+pub fn Toaster() -> impl Mount {
+    let service = use_toast();
+    Portal::new(
+        div(For::new(
+            service.toasts(),
+            |toast: &Toast| toast.id,
+            move |toast: Toast| {
+                let service = service.clone();
+                let role = match toast.kind {
+                    ToastKind::Error => "alert",
+                    _ => "status",
+                };
+                div(toast.message)
+                    .class(format!("toast toast-{}", toast.kind.as_str()))
+                    .role(role)
+                    .on(event::click, move |_| service.dismiss(toast.id))
+            },
+        ))
+        .class("toaster")
+        .style("position: fixed; right: 16px; bottom: 16px; display: flex; flex-direction: column; gap: 8px; z-index: 1000"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Timers = Rc<RefCell<Vec<(Duration, Rc<Cell<bool>>, Option<Box<dyn FnOnce()>>)>>>;
+
+    fn service(timers: &Timers) -> ToastService {
+        let timers = timers.clone();
+        ToastService::with_scheduler(Box::new(move |duration, cb| {
+            let cancelled = Rc::new(Cell::new(false));
+            timers
+                .borrow_mut()
+                .push((duration, cancelled.clone(), Some(cb)));
+            Box::new(move || cancelled.set(true))
+        }))
+    }
+
+    /// 触发所有未取消的定时器
+    fn fire_all(timers: &Timers) {
+        let pending: Vec<_> = timers
+            .borrow_mut()
+            .iter_mut()
+            .filter(|(_, cancelled, _)| !cancelled.get())
+            .filter_map(|(_, _, cb)| cb.take())
+            .collect();
+        for cb in pending {
+            cb();
+        }
+    }
+
+    #[test]
+    fn toasts_are_removed_after_their_timeout() {
+        create_scope(|| {
+            let timers = Timers::default();
+            let toast = service(&timers).default_duration(Duration::from_secs(2));
+            toast.info("saved");
+            toast.error("offline");
+
+            let list = toast.toasts().get();
+            assert_eq!(list.len(), 2);
+            assert_eq!(list[1].kind, ToastKind::Error);
+            assert_eq!(timers.borrow()[0].0, Duration::from_secs(2));
+
+            fire_all(&timers);
+            assert!(toast.toasts().get().is_empty());
+        });
+    }
+
+    #[test]
+    fn dismiss_cancels_the_timer() {
+        let timers = Timers::default();
+        let scope = create_scope(|| {
+            let toast = service(&timers);
+            let first = toast.info("first");
+            toast.show(ToastKind::Warning, "second", Duration::from_secs(10));
+
+            toast.dismiss(first);
+            assert!(timers.borrow()[0].1.get(), "timer cancelled");
+            assert_eq!(toast.toasts().get().len(), 1);
+        });
+
+        // 作用域销毁时取消剩余的定时器
+        dispose(scope);
+        assert!(timers.borrow()[1].1.get());
+    }
+}
@@ -0,0 +1,250 @@
+use crate::prelude::*;
+use silex_dom::element::bind_event;
+use std::cell::Cell;
+use std::rc::Rc;
+use web_sys::HtmlElement;
+
+/// 提示框相对触发元素的位置
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Placement {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// 元素在视口中的位置与尺寸 (与 `getBoundingClientRect` 一致)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AnchorRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// 显示提示框时读取的触发元素位置，DOM 元素取 `getBoundingClientRect`
+trait Measure {
+    fn bounding_rect(&self) -> AnchorRect;
+}
+
+impl<T: AsRef<web_sys::Element>> Measure for T {
+    fn bounding_rect(&self) -> AnchorRect {
+        let rect = self.as_ref().get_bounding_client_rect();
+        AnchorRect {
+            x: rect.x(),
+            y: rect.y(),
+            width: rect.width(),
+            height: rect.height(),
+        }
+    }
+}
+
+/// 计算提示框的定位样式
+///
+/// 提示框以触发元素对应边的中点为锚点，再通过 `transform` 按自身尺寸偏移，
+/// 因此无需测量提示框本身。
+pub fn tooltip_style(anchor: AnchorRect, placement: Placement, offset: f64) -> String {
+    let center_x = anchor.x + anchor.width / 2.0;
+    let center_y = anchor.y + anchor.height / 2.0;
+    let (left, top, translate) = match placement {
+        Placement::Top => (center_x, anchor.y - offset, "-50%, -100%"),
+        Placement::Bottom => (center_x, anchor.y + anchor.height + offset, "-50%, 0"),
+        Placement::Left => (anchor.x - offset, center_y, "-100%, -50%"),
+        Placement::Right => (anchor.x + anchor.width + offset, center_y, "0, -50%"),
+    };
+    format!(
+        "position: fixed; left: {left}px; top: {top}px; transform: translate({translate}); z-index: 1000; pointer-events: none"
+    )
+}
+
+/// 提示框的显示状态与锚点位置
+#[derive(Clone, Copy)]
+struct TooltipState {
+    open: RwSignal<bool>,
+    anchor: RwSignal<AnchorRect>,
+}
+
+impl TooltipState {
+    fn new() -> Self {
+        Self {
+            open: RwSignal::new(false),
+            anchor: RwSignal::new(AnchorRect::default()),
+        }
+    }
+
+    /// 测量触发元素后显示
+    fn show(&self, trigger: &impl Measure) {
+        let rect = trigger.bounding_rect();
+        batch(|| {
+            self.anchor.set(rect);
+            self.open.set(true);
+        });
+    }
+
+    fn hide(&self) {
+        self.open.set(false);
+    }
+
+    fn style(&self, placement: Placement, offset: f64) -> Memo<String> {
+        let anchor = self.anchor;
+        Memo::new(move |_| tooltip_style(anchor.get(), placement, offset))
+    }
+}
+
+/// 在 `trigger` 加载时 (已加载则立即) 以当前作用域为所有者调用 `bind`，
+/// 绑定的监听器随提示框一起清理；提示框先于触发元素销毁时不再绑定。
+fn on_trigger_load<T: Clone + 'static>(trigger: NodeRef<T>, bind: impl FnOnce(&T) + 'static) {
+    let owner = current_owner();
+    let alive = Rc::new(Cell::new(true));
+    on_cleanup({
+        let alive = alive.clone();
+        move || alive.set(false)
+    });
+    trigger.on_load(move |el| {
+        if alive.get() {
+            with_owner(owner, || bind(el));
+        }
+    });
+}
+
+/// 提示框：鼠标移入或获得焦点时，在 `trigger` 旁显示 `children`，移出或失去焦点时移除。
+///
+/// 提示框通过 [`Portal`] 渲染到 `body`，按触发元素的 `getBoundingClientRect` 定位。
+/// 事件在 `trigger` 加载时绑定，因此提示框可以放在触发元素之前或之后：
+///
+/// ```rust,ignore
+/// let trigger = NodeRef::<HtmlElement>::new();
+/// (
+///     Tooltip().trigger(trigger).placement(Placement::Bottom).children("Ctrl + S"),
+///     button("保存").node_ref(trigger),
+/// )
+/// ```
+#[component]
+pub fn Tooltip(
+    trigger: NodeRef<HtmlElement>,
+    #[prop(default)] placement: Placement,
+    #[prop(default = 8.0)] offset: f64,
+    #[prop(default)] children: Children,
+) -> impl Mount {
+    let state = TooltipState::new();
+    on_trigger_load(*trigger, move |el| {
+        let target: &web_sys::Element = el.as_ref();
+        let measured = el.clone();
+        bind_event(target, event::mouseenter, move || state.show(&measured));
+        let measured = el.clone();
+        bind_event(target, event::focus, move || state.show(&measured));
+        bind_event(target, event::mouseleave, move || state.hide());
+        bind_event(target, event::blur, move || state.hide());
+    });
+
+    let style = state.style(*placement, *offset);
+    Show::new(
+        state.open,
+        Portal::new(div(children.clone()).role("tooltip").style(style)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Clone)]
+    struct MockTrigger(AnchorRect);
+
+    impl Measure for MockTrigger {
+        fn bounding_rect(&self) -> AnchorRect {
+            self.0
+        }
+    }
+
+    #[test]
+    fn shows_next_to_trigger_on_hover_and_hides_on_blur() {
+        create_scope(|| {
+            let state = TooltipState::new();
+            let style = state.style(Placement::Bottom, 8.0);
+            let log = Rc::new(RefCell::new(Vec::new()));
+            Effect::new({
+                let log = log.clone();
+                move |_| log.borrow_mut().push((state.open.get(), style.get()))
+            });
+
+            let trigger = MockTrigger(AnchorRect {
+                x: 100.0,
+                y: 40.0,
+                width: 100.0,
+                height: 30.0,
+            });
+            state.show(&trigger);
+            let (open, style) = log.borrow().last().cloned().unwrap();
+            assert!(open, "mounted on hover");
+            assert!(style.contains("left: 150px; top: 78px"), "{style}");
+
+            state.hide();
+            assert!(!log.borrow().last().unwrap().0, "unmounted on blur");
+        });
+    }
+
+    #[test]
+    fn binds_when_the_trigger_loads_after_the_tooltip() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let trigger = NodeRef::<MockTrigger>::new();
+        let tooltip = create_scope({
+            let log = log.clone();
+            move || {
+                on_trigger_load(trigger, move |el| {
+                    log.borrow_mut().push(el.0.x);
+                    let log = log.clone();
+                    // 绑定期间注册的清理归属于提示框
+                    on_cleanup(move || log.borrow_mut().push(-1.0));
+                });
+            }
+        });
+        assert!(log.borrow().is_empty(), "trigger not loaded yet");
+
+        trigger.load(MockTrigger(AnchorRect {
+            x: 5.0,
+            ..AnchorRect::default()
+        }));
+        assert_eq!(*log.borrow(), vec![5.0]);
+
+        dispose(tooltip);
+        assert_eq!(*log.borrow(), vec![5.0, -1.0]);
+    }
+
+    #[test]
+    fn skips_binding_when_disposed_before_the_trigger_loads() {
+        let bound = Rc::new(Cell::new(false));
+        let trigger = NodeRef::<MockTrigger>::new();
+        let tooltip = create_scope({
+            let bound = bound.clone();
+            move || on_trigger_load(trigger, move |_| bound.set(true))
+        });
+        dispose(tooltip);
+        trigger.load(MockTrigger(AnchorRect::default()));
+        assert!(!bound.get());
+    }
+
+    #[test]
+    fn placement_anchors_to_trigger_edges() {
+        let rect = AnchorRect {
+            x: 10.0,
+            y: 20.0,
+            width: 40.0,
+            height: 10.0,
+        };
+        let anchor = |placement| {
+            let style = tooltip_style(rect, placement, 4.0);
+            style
+                .split("; ")
+                .filter(|s| s.starts_with("left") || s.starts_with("top"))
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+        assert_eq!(anchor(Placement::Top), "left: 30px; top: 16px");
+        assert_eq!(anchor(Placement::Bottom), "left: 30px; top: 34px");
+        assert_eq!(anchor(Placement::Left), "left: 6px; top: 25px");
+        assert_eq!(anchor(Placement::Right), "left: 54px; top: 25px");
+    }
+}
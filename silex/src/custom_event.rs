@@ -0,0 +1,120 @@
+use silex_core::error::{SilexError, SilexResult};
+use silex_core::log::console_error;
+use silex_dom::attribute::{AttributeBuilder, PendingAttribute};
+use silex_dom::element::bind_event_impl;
+use std::rc::Rc;
+
+/// 将 detail 编码为 JSON，作为 `CustomEvent.detail` 在 JS 侧的结构
+fn encode_detail<T: serde::Serialize>(detail: &T) -> Result<String, String> {
+    serde_json::to_string(detail).map_err(|err| err.to_string())
+}
+
+/// 包装类型化的处理函数：解码 JSON 形式的 detail，失败时输出错误并跳过处理函数
+fn typed_handler<T, F>(name: String, handler: F) -> impl Fn(Option<String>) + 'static
+where
+    T: serde::de::DeserializeOwned,
+    F: Fn(T) + 'static,
+{
+    move |raw: Option<String>| {
+        let decoded = match raw.as_deref() {
+            Some(raw) => serde_json::from_str::<T>(raw).map_err(|err| err.to_string()),
+            None => Err("detail is not serializable".to_string()),
+        };
+        match decoded {
+            Ok(detail) => handler(detail),
+            Err(err) => console_error(format!(
+                "Malformed detail for custom event '{}': {}",
+                name, err
+            )),
+        }
+    }
+}
+
+/// 类型化的自定义事件 (`CustomEvent`) 监听，detail 经由 JSON 反序列化
+pub trait CustomEventAttributes: AttributeBuilder {
+    /// 监听名为 `name` 的 `CustomEvent`，将 `event.detail` 反序列化为 `T` 后交给处理函数。
+    ///
+    /// detail 无法解析为 `T` 时输出错误并跳过处理函数。
+    ///
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Picked { id: u32 }
+    ///
+    /// div(()).on_custom("picked", move |p: Picked| selected.set(p.id));
+    /// ```
+    fn on_custom<T, F>(self, name: &str, handler: F) -> Self
+    where
+        T: serde::de::DeserializeOwned + 'static,
+        F: Fn(T) + 'static,
+    {
+        let name = name.to_string();
+        let handler = Rc::new(typed_handler(name.clone(), handler));
+        self.apply(PendingAttribute::new_listener(move |el| {
+            let handler = handler.clone();
+            bind_event_impl(
+                el,
+                name.clone(),
+                Box::new(move |e: web_sys::CustomEvent| {
+                    handler(js_sys::JSON::stringify(&e.detail()).ok().map(String::from))
+                }),
+            );
+        }))
+    }
+}
+
+impl<T: AttributeBuilder> CustomEventAttributes for T {}
+
+/// 派发类型化的自定义事件。为所有 DOM 节点实现。
+pub trait DispatchCustom {
+    /// 构造并派发名为 `name` 的冒泡 `CustomEvent`，`detail` 序列化为 JSON 结构。
+    ///
+    /// 返回值与 `dispatchEvent` 一致：事件未被 `preventDefault` 时为 `true`。
+    fn dispatch_custom<T: serde::Serialize>(&self, name: &str, detail: &T) -> SilexResult<bool>;
+}
+
+impl<E: AsRef<web_sys::EventTarget>> DispatchCustom for E {
+    fn dispatch_custom<T: serde::Serialize>(&self, name: &str, detail: &T) -> SilexResult<bool> {
+        let json = encode_detail(detail).map_err(SilexError::Javascript)?;
+        let init = web_sys::CustomEventInit::new();
+        init.set_bubbles(true);
+        init.set_detail(&js_sys::JSON::parse(&json)?);
+        let event = web_sys::CustomEvent::new_with_event_init_dict(name, &init)?;
+        Ok(self.as_ref().dispatch_event(&event)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::cell::RefCell;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Picked {
+        id: u32,
+        label: String,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn detail_round_trips_to_typed_handler() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let handler = typed_handler("picked".to_string(), {
+            let received = received.clone();
+            move |p: Picked| received.borrow_mut().push(p)
+        });
+
+        let picked = Picked {
+            id: 7,
+            label: "seven".into(),
+            tags: vec!["odd".into()],
+        };
+        handler(Some(encode_detail(&picked).unwrap()));
+        assert_eq!(*received.borrow(), vec![picked]);
+
+        // 结构不符或缺少 detail 时跳过处理函数
+        handler(Some(r#"{"id":"seven"}"#.to_string()));
+        handler(None);
+        assert_eq!(received.borrow().len(), 1);
+    }
+}
@@ -1,11 +1,17 @@
 mod dynamic;
 mod for_loop;
+mod grouped_for;
 mod index;
+mod keyed;
+mod provider;
 mod show;
 mod switch;
 
 pub use dynamic::*;
 pub use for_loop::*;
+pub use grouped_for::*;
 pub use index::*;
+pub use keyed::*;
+pub use provider::*;
 pub use show::*;
 pub use switch::*;
@@ -1,4 +1,5 @@
-use silex_core::traits::RxRead;
+use silex_core::reactivity::{Memo, untrack};
+use silex_core::traits::{RxBase, RxRead};
 use silex_dom::prelude::{ApplyAttributes, Mount, MountRef};
 use web_sys::Node;
 
@@ -66,6 +67,39 @@ where
         let combined_accessor = silex_core::rx!(source.with(|val| map_fn(val.clone())));
         Dynamic::new(combined_accessor)
     }
+
+    /// 与 [`Dynamic::bind`] 相同，但只在 `key_fn` 计算出的 key 变化时重新渲染。
+    ///
+    /// key 不变时保留现有视图及其作用域 (不会丢失焦点等 DOM 状态)，
+    /// 视图内部读取的信号照常响应式更新；key 变化时销毁旧作用域并挂载新视图。
+    ///
+    /// # 示例
+    /// ```ignore
+    /// Dynamic::bind_keyed(user, |u| u.id, |u| profile_form(u))
+    /// ```
+    pub fn bind_keyed<S, T, K, KeyFn, Map>(
+        source: S,
+        key_fn: KeyFn,
+        map_fn: Map,
+    ) -> Dynamic<V, silex_core::Rx<V, silex_core::RxValueKind>>
+    where
+        S: RxRead<Value = T> + Clone + 'static,
+        KeyFn: Fn(&T) -> K + 'static,
+        Map: Fn(T) -> V + 'static,
+        T: Clone + 'static,
+        K: PartialEq + Clone + 'static,
+        V: MountRef + 'static,
+    {
+        let key = Memo::new({
+            let source = source.clone();
+            move |_| source.with(|val| key_fn(val))
+        });
+        let keyed_accessor = silex_core::rx! {{
+            key.track();
+            untrack(|| source.with(|val| map_fn(val.clone())))
+        }};
+        Dynamic::new(keyed_accessor)
+    }
 }
 
 impl<V, F> ApplyAttributes for Dynamic<V, F>
@@ -113,3 +147,93 @@ fn mount_dynamic_internal<V, F>(
         }),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MountLog, Probe, mount_ref};
+    use silex_core::reactivity::{Effect, RwSignal, create_scope};
+    use silex_core::traits::RxWrite;
+    use silex_dom::view_match;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mode {
+        List,
+        Grid(u32),
+        Hidden,
+    }
+
+    #[test]
+    fn enum_view_match_follows_signal() {
+        create_scope(|| {
+            let log = MountLog::default();
+            let mode = RwSignal::new(Mode::List);
+            let dynamic = Dynamic::bind(mode, {
+                let log = log.clone();
+                move |m| {
+                    view_match!(m, {
+                        Mode::List => Probe::marker(&log, "list"),
+                        Mode::Grid(columns) if columns > 1 => Probe::marker(&log, format!("grid {columns}")),
+                        _ => (),
+                    })
+                }
+            });
+
+            // 与挂载时相同：依赖变化时重新求值并挂载当前分支的视图
+            Effect::new(move |_| dynamic.view_fn.with(mount_ref));
+
+            mode.set(Mode::Grid(3));
+            mode.set(Mode::Grid(1));
+            mode.set(Mode::Hidden);
+            mode.set(Mode::List);
+            assert_eq!(*log.borrow(), vec!["list", "grid 3", "list"]);
+        });
+    }
+
+    #[test]
+    fn keyed_keeps_scope_until_key_changes() {
+        create_scope(|| {
+            let log = MountLog::default();
+            let mode = RwSignal::new(Mode::Grid(2));
+            let dynamic = Dynamic::bind_keyed(mode, std::mem::discriminant, {
+                let log = log.clone();
+                move |m| match m {
+                    Mode::Grid(columns) => Probe::marker(&log, format!("grid {columns}")),
+                    _ => Probe::marker(&log, "other"),
+                }
+            });
+
+            // 与挂载时相同：在 Effect 中读取视图，并在子作用域中挂载
+            let scopes = Rc::new(RefCell::new(Vec::new()));
+            let disposed = Rc::new(RefCell::new(Vec::new()));
+            Effect::new({
+                let scopes = scopes.clone();
+                let disposed = disposed.clone();
+                move |_| {
+                    let disposed = disposed.clone();
+                    let scope = dynamic
+                        .view_fn
+                        .with(|view| create_scope(|| mount_ref(view)));
+                    silex_core::reactivity::on_cleanup(move || disposed.borrow_mut().push(scope));
+                    scopes.borrow_mut().push(scope);
+                }
+            });
+
+            // 同一个 key：保留原有子作用域
+            mode.set(Mode::Grid(3));
+            mode.set(Mode::Grid(4));
+            assert_eq!(scopes.borrow().len(), 1);
+            assert!(disposed.borrow().is_empty());
+
+            // key 变化：销毁旧作用域并重新渲染
+            mode.set(Mode::List);
+            let scopes = scopes.borrow();
+            assert_eq!(scopes.len(), 2);
+            assert_eq!(*disposed.borrow(), vec![scopes[0]]);
+            assert_ne!(scopes[0], scopes[1]);
+            assert_eq!(*log.borrow(), vec!["grid 2", "other"]);
+        });
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{SilexError, SilexResult};
 use silex_core::reactivity::{Effect, NodeId, batch, create_scope, dispose};
 use silex_core::traits::RxRead;
-use silex_dom::prelude::{ApplyAttributes, AutoReactiveView, Mount, MountRef};
+use silex_dom::prelude::{AnyView, ApplyAttributes, AutoReactiveView, Mount, MountExt, MountRef};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
@@ -89,10 +89,33 @@ where
     }
 }
 
+pub(crate) type FallbackFn = Rc<dyn Fn() -> AnyView>;
+
+/// 空列表占位视图的挂载状态
+pub(crate) struct FallbackSlot<R> {
+    pub(crate) row: Option<R>,
+}
+
+impl<R> FallbackSlot<R> {
+    /// 列表为空且占位视图尚未显示时调用 `create` 显示；
+    /// 列表非空时取出已显示的占位视图，交由调用方移除。
+    pub(crate) fn sync(&mut self, empty: bool, create: impl FnOnce() -> R) -> Option<R> {
+        if empty {
+            if self.row.is_none() {
+                self.row = Some(create());
+            }
+            None
+        } else {
+            self.row.take()
+        }
+    }
+}
+
 pub struct For<ItemsFn, KeyFn, MapFn> {
     items: Rc<ItemsFn>,
     key: Rc<KeyFn>,
     map: Rc<MapFn>,
+    fallback: Option<FallbackFn>,
 }
 
 impl<ItemsFn, KeyFn, MapFn> Clone for For<ItemsFn, KeyFn, MapFn> {
@@ -101,6 +124,7 @@ impl<ItemsFn, KeyFn, MapFn> Clone for For<ItemsFn, KeyFn, MapFn> {
             items: self.items.clone(),
             key: self.key.clone(),
             map: self.map.clone(),
+            fallback: self.fallback.clone(),
         }
     }
 }
@@ -117,8 +141,21 @@ impl<ItemsFn, KeyFn, MapFn> For<ItemsFn, KeyFn, MapFn> {
             items: Rc::new(items),
             key: Rc::new(key),
             map: Rc::new(map),
+            fallback: None,
         }
     }
+
+    /// 设置列表为空时显示的视图。
+    ///
+    /// 列表变为非空时占位视图被移除并销毁其作用域，再次变空时重新创建。
+    pub fn fallback<F, V>(mut self, fallback: F) -> Self
+    where
+        F: Fn() -> V + 'static,
+        V: MountExt,
+    {
+        self.fallback = Some(Rc::new(move || fallback().into_any()));
+        self
+    }
 }
 
 // 3.7 For Loop implementation
@@ -199,6 +236,8 @@ where
         let items_fn = self.items;
         let key_fn = self.key;
         let map_fn = self.map;
+        let fallback_fn = self.fallback;
+        let fallback_slot = RefCell::new(FallbackSlot::<(Vec<Node>, NodeId)> { row: None });
 
         // Store: (Nodes, ScopeId)
         // We must fully qualify the Key type here because type aliases inside functions cannot capture
@@ -224,6 +263,32 @@ where
                 };
 
                 batch(|| {
+                    // 先移除占位视图，使后续从 start_marker 开始的扫描只遇到行节点
+                    if let Some(fallback_fn) = &fallback_fn {
+                        let removed =
+                            fallback_slot.borrow_mut().sync(items_slice.is_empty(), || {
+                                let (nodes, scope_id) = silex_core::reactivity::untrack(|| {
+                                    mount_detached(&document, |parent| {
+                                        fallback_fn().mount(parent, Vec::new())
+                                    })
+                                });
+                                if let Some(parent) = end_node.parent_node() {
+                                    for node in &nodes {
+                                        let _ = parent.insert_before(node, Some(&end_node));
+                                    }
+                                }
+                                (nodes, scope_id)
+                            });
+                        if let Some((nodes, scope_id)) = removed {
+                            for node in &nodes {
+                                if let Some(p) = node.parent_node() {
+                                    let _ = p.remove_child(node);
+                                }
+                            }
+                            dispose(scope_id);
+                        }
+                    }
+
                     let mut new_keys = HashSet::new();
                     // (Key, Nodes, ScopeId, Optional Fragment for initial insert)
                     let mut new_rows_order = Vec::with_capacity(items_slice.len());
@@ -339,3 +404,61 @@ where
         });
     }
 }
+
+/// 在独立的作用域中将视图挂载到文档片段，返回挂载出的节点与作用域
+pub(crate) fn mount_detached(
+    document: &web_sys::Document,
+    mount: impl FnOnce(&Node),
+) -> (Vec<Node>, NodeId) {
+    let fragment = document.create_document_fragment();
+    let fragment_node: Node = fragment.clone().into();
+    let scope_id = create_scope(move || mount(&fragment_node));
+
+    let nodes_list = fragment.child_nodes();
+    let nodes = (0..nodes_list.length())
+        .filter_map(|i| nodes_list.item(i))
+        .collect();
+    (nodes, scope_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_shown_only_while_empty() {
+        let mut slot = FallbackSlot { row: None };
+        let mut created = 0;
+
+        // 初始为空：显示占位视图
+        assert_eq!(
+            slot.sync(true, || {
+                created += 1;
+                created
+            }),
+            None
+        );
+        assert_eq!(
+            slot.sync(true, || {
+                created += 1;
+                created
+            }),
+            None
+        );
+        assert_eq!(created, 1, "fallback is created once");
+
+        // 第一项到达：移除占位视图
+        assert_eq!(slot.sync(false, || unreachable!()), Some(1));
+        assert_eq!(slot.sync(false, || unreachable!()), None);
+
+        // 再次变空：重新创建
+        assert_eq!(
+            slot.sync(true, || {
+                created += 1;
+                created
+            }),
+            None
+        );
+        assert_eq!(slot.row, Some(2));
+    }
+}
@@ -0,0 +1,261 @@
+use crate::flow::for_loop::{For, ForLoopSource};
+use silex_core::reactivity::Memo;
+use silex_core::traits::RxRead;
+use silex_dom::prelude::{ApplyAttributes, AutoReactiveView, Mount, MountRef};
+use silex_dom::view_chain;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+use web_sys::Node;
+
+/// 按 `group_key` 将条目分组：组按首次出现的顺序排列，组内保持原有顺序
+pub fn group_by<Item, G>(items: &[Item], group_key: impl Fn(&Item) -> G) -> Vec<(G, Vec<Item>)>
+where
+    Item: Clone,
+    G: Hash + Eq + Clone,
+{
+    let mut index = HashMap::new();
+    let mut groups: Vec<(G, Vec<Item>)> = Vec::new();
+    for item in items {
+        let key = group_key(item);
+        let slot = *index.entry(key.clone()).or_insert_with(|| {
+            groups.push((key, Vec::new()));
+            groups.len() - 1
+        });
+        groups[slot].1.push(item.clone());
+    }
+    groups
+}
+
+type Groups<G, Item> = Memo<Vec<(G, Vec<Item>)>>;
+
+/// 分组结果，源数据出错时按空列表处理
+fn grouped<ItemsFn, Item, G>(items: ItemsFn, group_key: Rc<dyn Fn(&Item) -> G>) -> Groups<G, Item>
+where
+    ItemsFn: RxRead + 'static,
+    ItemsFn::Value: ForLoopSource<Item = Item>,
+    Item: Clone + PartialEq + 'static,
+    G: Hash + Eq + Clone + 'static,
+{
+    Memo::new(move |_| {
+        items.with(|items| match items.as_slice() {
+            Ok(items) => group_by(items, |item| group_key(item)),
+            Err(e) => {
+                silex_core::error::handle_error(e);
+                Vec::new()
+            }
+        })
+    })
+}
+
+/// 某一组的条目：仅在该组内容变化时通知，其他组的变动不会触发重新渲染
+fn group_items<G, Item>(groups: Groups<G, Item>, key: G) -> Memo<Vec<Item>>
+where
+    G: PartialEq + Clone + 'static,
+    Item: Clone + PartialEq + 'static,
+{
+    Memo::new(move |_| {
+        groups.with(|groups| {
+            groups
+                .iter()
+                .find(|(g, _)| *g == key)
+                .map(|(_, items)| items.clone())
+                .unwrap_or_default()
+        })
+    })
+}
+
+/// GroupedFor 组件：按分组渲染列表，每组先渲染组头，再渲染组内条目。
+///
+/// 组与组内条目均以 [`For`] 进行键控协调：条目在组间移动时，
+/// 只有移出与移入的两组会更新，其余组保持不变。条目以自身作为 key。
+///
+/// ```rust,ignore
+/// GroupedFor::new(
+///     contacts,
+///     |c: &String| c.chars().next().unwrap_or('#'),
+///     |letter| h3(letter.to_string()),
+///     |name| li(name),
+/// )
+/// ```
+pub struct GroupedFor<ItemsFn, Item, G, GroupMap, ItemMap> {
+    items: ItemsFn,
+    group_key: Rc<dyn Fn(&Item) -> G>,
+    group_map: Rc<GroupMap>,
+    item_map: Rc<ItemMap>,
+}
+
+impl<ItemsFn, Item, G, GroupMap, ItemMap> Clone for GroupedFor<ItemsFn, Item, G, GroupMap, ItemMap>
+where
+    ItemsFn: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            group_key: self.group_key.clone(),
+            group_map: self.group_map.clone(),
+            item_map: self.item_map.clone(),
+        }
+    }
+}
+
+impl<ItemsFn, Item, G, GroupMap, ItemMap, GV, IV> GroupedFor<ItemsFn, Item, G, GroupMap, ItemMap>
+where
+    ItemsFn: RxRead + 'static,
+    ItemsFn::Value: ForLoopSource<Item = Item> + 'static,
+    Item: Hash + Eq + Clone + 'static,
+    G: Hash + Eq + Clone + 'static,
+    GroupMap: Fn(G) -> GV + 'static,
+    ItemMap: Fn(Item) -> IV + 'static,
+    GV: Mount + 'static,
+    IV: Mount + 'static,
+{
+    pub fn new(
+        items: ItemsFn,
+        group_key: impl Fn(&Item) -> G + 'static,
+        group_map: GroupMap,
+        item_map: ItemMap,
+    ) -> Self {
+        Self {
+            items,
+            group_key: Rc::new(group_key),
+            group_map: Rc::new(group_map),
+            item_map: Rc::new(item_map),
+        }
+    }
+
+    fn mount_internal(self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        let groups = grouped(self.items, self.group_key);
+        let keys = Memo::new(move |_| {
+            groups.with(|groups| groups.iter().map(|(g, _)| g.clone()).collect::<Vec<_>>())
+        });
+        let group_map = self.group_map;
+        let item_map = self.item_map;
+
+        For::new(
+            keys,
+            |g: &G| g.clone(),
+            move |g: G| {
+                let items = group_items(groups, g.clone());
+                let item_map = item_map.clone();
+                view_chain!(
+                    group_map(g),
+                    For::new(
+                        items,
+                        |item: &Item| item.clone(),
+                        move |item| item_map(item)
+                    ),
+                )
+            },
+        )
+        .mount(parent, attrs);
+    }
+}
+
+impl<ItemsFn, Item, G, GroupMap, ItemMap> ApplyAttributes
+    for GroupedFor<ItemsFn, Item, G, GroupMap, ItemMap>
+{
+}
+
+impl<ItemsFn, Item, G, GroupMap, ItemMap, GV, IV> Mount
+    for GroupedFor<ItemsFn, Item, G, GroupMap, ItemMap>
+where
+    ItemsFn: RxRead + 'static,
+    ItemsFn::Value: ForLoopSource<Item = Item> + 'static,
+    Item: Hash + Eq + Clone + 'static,
+    G: Hash + Eq + Clone + 'static,
+    GroupMap: Fn(G) -> GV + 'static,
+    ItemMap: Fn(Item) -> IV + 'static,
+    GV: Mount + 'static,
+    IV: Mount + 'static,
+{
+    fn mount(self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        self.mount_internal(parent, attrs);
+    }
+}
+
+impl<ItemsFn, Item, G, GroupMap, ItemMap, GV, IV> AutoReactiveView
+    for GroupedFor<ItemsFn, Item, G, GroupMap, ItemMap>
+where
+    ItemsFn: RxRead + Clone + 'static,
+    ItemsFn::Value: ForLoopSource<Item = Item> + 'static,
+    Item: Hash + Eq + Clone + 'static,
+    G: Hash + Eq + Clone + 'static,
+    GroupMap: Fn(G) -> GV + 'static,
+    ItemMap: Fn(Item) -> IV + 'static,
+    GV: Mount + 'static,
+    IV: Mount + 'static,
+{
+}
+
+impl<ItemsFn, Item, G, GroupMap, ItemMap, GV, IV> MountRef
+    for GroupedFor<ItemsFn, Item, G, GroupMap, ItemMap>
+where
+    ItemsFn: RxRead + Clone + 'static,
+    ItemsFn::Value: ForLoopSource<Item = Item> + 'static,
+    Item: Hash + Eq + Clone + 'static,
+    G: Hash + Eq + Clone + 'static,
+    GroupMap: Fn(G) -> GV + 'static,
+    ItemMap: Fn(Item) -> IV + 'static,
+    GV: Mount + 'static,
+    IV: Mount + 'static,
+{
+    fn mount_ref(&self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        self.clone().mount_internal(parent, attrs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{Effect, RwSignal, create_scope};
+    use silex_core::traits::{RxBase, RxGet, RxWrite};
+    use std::cell::RefCell;
+
+    fn initial(name: &str) -> char {
+        name.chars().next().unwrap_or('#')
+    }
+
+    #[test]
+    fn groups_keep_first_appearance_order() {
+        let names: Vec<String> = ["bob", "alice", "bill", "carol", "anna"]
+            .map(String::from)
+            .into();
+        let groups = group_by(&names, |n| initial(n));
+        assert_eq!(
+            groups,
+            vec![
+                ('b', vec!["bob".to_string(), "bill".to_string()]),
+                ('a', vec!["alice".to_string(), "anna".to_string()]),
+                ('c', vec!["carol".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn moving_item_only_updates_affected_groups() {
+        create_scope(|| {
+            let contacts =
+                RwSignal::new(["alice", "anna", "bob", "carol"].map(String::from).to_vec());
+            let groups = grouped(contacts, Rc::new(|n: &String| initial(n)));
+            let renders = Rc::new(RefCell::new(Vec::new()));
+            for letter in ['a', 'b', 'c'] {
+                let items = group_items(groups, letter);
+                let renders = renders.clone();
+                Effect::new(move |_| {
+                    items.track();
+                    renders.borrow_mut().push(letter);
+                });
+            }
+            renders.borrow_mut().clear();
+
+            // "anna" 改名为 "bea"：从 a 组移动到 b 组
+            contacts.update(|c| c[1] = "bea".to_string());
+            assert_eq!(*renders.borrow(), vec!['a', 'b']);
+            assert_eq!(
+                group_items(groups, 'b').get(),
+                vec!["bea".to_string(), "bob".to_string()]
+            );
+        });
+    }
+}
@@ -1,9 +1,7 @@
-use crate::flow::for_loop::ForLoopSource;
-use silex_core::reactivity::{
-    Effect, NodeId, ReadSignal, Signal, WriteSignal, batch, create_scope, dispose,
-};
+use crate::flow::for_loop::{FallbackFn, FallbackSlot, ForLoopSource, mount_detached};
+use silex_core::reactivity::{Effect, NodeId, ReadSignal, Signal, WriteSignal, batch, dispose};
 use silex_core::traits::{IntoRx, RxRead, RxWrite};
-use silex_dom::prelude::{ApplyAttributes, AutoReactiveView, Mount, MountRef};
+use silex_dom::prelude::{ApplyAttributes, AutoReactiveView, Mount, MountExt, MountRef};
 use std::cell::RefCell;
 use std::rc::Rc;
 use web_sys::Node;
@@ -16,6 +14,7 @@ use web_sys::Node;
 pub struct Index<ItemsFn, Item, Items, MapFn, V> {
     items: Rc<ItemsFn>,
     map: Rc<MapFn>,
+    fallback: Option<FallbackFn>,
     _marker: std::marker::PhantomData<(Item, Items, V)>,
 }
 
@@ -31,9 +30,20 @@ where
         Self {
             items: Rc::new(items.into_rx()),
             map: Rc::new(map),
+            fallback: None,
             _marker: std::marker::PhantomData,
         }
     }
+
+    /// 设置列表为空时显示的视图，行为与 [`For::fallback`](crate::flow::For::fallback) 相同
+    pub fn fallback<F, FV>(mut self, fallback: F) -> Self
+    where
+        F: Fn() -> FV + 'static,
+        FV: MountExt,
+    {
+        self.fallback = Some(Rc::new(move || fallback().into_any()));
+        self
+    }
 }
 
 // Helper struct for row state
@@ -64,7 +74,7 @@ where
     Item: Clone + 'static,
 {
     fn mount(self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
-        mount_index_internal(self.items, self.map, parent, attrs);
+        mount_index_internal(self.items, self.map, self.fallback, parent, attrs);
     }
 }
 
@@ -87,13 +97,40 @@ where
     Item: Clone + 'static,
 {
     fn mount_ref(&self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
-        mount_index_internal(self.items.clone(), self.map.clone(), parent, attrs);
+        mount_index_internal(
+            self.items.clone(),
+            self.map.clone(),
+            self.fallback.clone(),
+            parent,
+            attrs,
+        );
+    }
+}
+
+/// 按位置同步行：公共部分调用 `update` 原地更新，新增部分调用 `create`，返回多余的行。
+///
+/// 在中间或头部插入时，之后的行不会被重建，只是各自的数据随位置更新。
+fn sync_rows<R, Item>(
+    rows: &mut Vec<R>,
+    items: &[Item],
+    mut update: impl FnMut(&mut R, &Item),
+    mut create: impl FnMut(usize, &Item) -> R,
+) -> Vec<R> {
+    let common_len = rows.len().min(items.len());
+    for (row, item) in rows.iter_mut().zip(items) {
+        update(row, item);
+    }
+    for (i, item) in items.iter().enumerate().skip(common_len) {
+        let row = create(i, item);
+        rows.push(row);
     }
+    rows.split_off(items.len().min(rows.len()))
 }
 
 fn mount_index_internal<ItemsFn, Item, Items, MapFn, V>(
     items_fn: Rc<ItemsFn>,
     map_fn: Rc<MapFn>,
+    fallback_fn: Option<FallbackFn>,
     parent: &Node,
     _attrs: Vec<silex_dom::attribute::PendingAttribute>,
 ) where
@@ -111,6 +148,7 @@ fn mount_index_internal<ItemsFn, Item, Items, MapFn, V>(
     let _ = parent.append_child(&end_node);
 
     let rows = Rc::new(RefCell::new(Vec::<IndexRow<Item>>::new()));
+    let fallback_slot = RefCell::new(FallbackSlot::<(Vec<Node>, NodeId)> { row: None });
 
     Effect::new(move |_| {
         items_fn.with(|items| {
@@ -125,65 +163,110 @@ fn mount_index_internal<ItemsFn, Item, Items, MapFn, V>(
             let mut rows_lock = rows.borrow_mut();
 
             batch(|| {
-                let new_len = items_slice.len();
-                let old_len = rows_lock.len();
-                let common_len = std::cmp::min(new_len, old_len);
-
-                for (i, item) in items_slice.iter().take(common_len).enumerate() {
-                    rows_lock[i].setter.set(item.clone());
+                if let Some(fallback_fn) = &fallback_fn {
+                    let removed = fallback_slot.borrow_mut().sync(items_slice.is_empty(), || {
+                        let (nodes, scope_id) = silex_core::reactivity::untrack(|| {
+                            mount_detached(&document, |parent| {
+                                fallback_fn().mount(parent, Vec::new())
+                            })
+                        });
+                        if let Some(p) = end_node.parent_node() {
+                            for node in &nodes {
+                                let _ = p.insert_before(node, Some(&end_node));
+                            }
+                        }
+                        (nodes, scope_id)
+                    });
+                    if let Some((nodes, scope_id)) = removed {
+                        remove_nodes(&nodes);
+                        dispose(scope_id);
+                    }
                 }
 
-                if new_len > old_len {
-                    for (i, item) in items_slice[common_len..].iter().enumerate() {
-                        let (set, scope_id, nodes, fragment_node) =
-                            silex_core::reactivity::untrack(|| {
-                                let real_index = common_len + i;
-                                let (get, set) = Signal::pair(item.clone());
-                                let fragment = document.create_document_fragment();
-                                let fragment_node: Node = fragment.clone().into();
-                                let fragment_node_clone = fragment_node.clone();
-                                let map_fn = map_fn.clone();
-
-                                let scope_id = create_scope(move || {
-                                    (map_fn)(get, real_index)
-                                        .mount(&fragment_node_clone, Vec::new());
-                                });
-
-                                let nodes_list = fragment.child_nodes();
-                                let len = nodes_list.length();
-                                let mut nodes = Vec::with_capacity(len as usize);
-                                for j in 0..len {
-                                    if let Some(n) = nodes_list.item(j) {
-                                        nodes.push(n);
-                                    }
-                                }
-                                (set, scope_id, nodes, fragment_node)
-                            });
-
+                let removed = sync_rows(
+                    &mut rows_lock,
+                    items_slice,
+                    |row, item| row.setter.set(item.clone()),
+                    |index, item| {
+                        let (get, set) = Signal::pair(item.clone());
+                        let map_fn = map_fn.clone();
+                        let (nodes, scope_id) = silex_core::reactivity::untrack(|| {
+                            mount_detached(&document, |parent| {
+                                (map_fn)(get, index).mount(parent, Vec::new())
+                            })
+                        });
                         if let Some(p) = end_node.parent_node() {
-                            let _ = p.insert_before(&fragment_node, Some(&end_node));
+                            for node in &nodes {
+                                let _ = p.insert_before(node, Some(&end_node));
+                            }
                         }
-
-                        rows_lock.push(IndexRow {
+                        IndexRow {
                             setter: set,
                             scope_id,
                             nodes,
-                        });
-                    }
-                }
-
-                if old_len > new_len {
-                    let to_remove = rows_lock.split_off(new_len);
-                    for row in to_remove {
-                        dispose(row.scope_id);
-                        for node in row.nodes {
-                            if let Some(p) = node.parent_node() {
-                                let _ = p.remove_child(&node);
-                            }
                         }
-                    }
+                    },
+                );
+                for row in removed {
+                    dispose(row.scope_id);
+                    remove_nodes(&row.nodes);
                 }
             });
         });
     });
 }
+
+fn remove_nodes(nodes: &[Node]) {
+    for node in nodes {
+        if let Some(p) = node.parent_node() {
+            let _ = p.remove_child(node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::create_scope;
+    use silex_core::traits::RxGet;
+
+    /// (行的创建序号, 行信号)
+    type Row = (usize, ReadSignal<&'static str>, WriteSignal<&'static str>);
+
+    fn values(rows: &[Row]) -> Vec<(usize, &'static str)> {
+        rows.iter()
+            .map(|(id, get, _)| (*id, get.get_untracked()))
+            .collect()
+    }
+
+    #[test]
+    fn insert_at_front_updates_rows_in_place() {
+        create_scope(|| {
+            let mut rows: Vec<Row> = Vec::new();
+            let mut created = 0;
+            let mut sync = |rows: &mut Vec<Row>, items: &[&'static str]| {
+                sync_rows(
+                    rows,
+                    items,
+                    |row, item| row.2.set(*item),
+                    |_, item| {
+                        created += 1;
+                        let (get, set) = Signal::pair(*item);
+                        (created, get, set)
+                    },
+                )
+            };
+
+            assert!(sync(&mut rows, &["b", "c"]).is_empty());
+            assert_eq!(values(&rows), [(1, "b"), (2, "c")]);
+
+            // 头部插入：已有行原地更新数据，只在末尾新增一行
+            assert!(sync(&mut rows, &["a", "b", "c"]).is_empty());
+            assert_eq!(values(&rows), [(1, "a"), (2, "b"), (3, "c")]);
+
+            let removed = sync(&mut rows, &["c"]);
+            assert_eq!(removed.len(), 2);
+            assert_eq!(values(&rows), [(1, "c")]);
+        });
+    }
+}
@@ -0,0 +1,348 @@
+use crate::SilexError;
+use silex_core::reactivity::{
+    NodeId, create_scope, current_owner, dispose, on_cleanup, untrack, with_owner,
+};
+use silex_dom::prelude::{ApplyAttributes, AutoReactiveView, Mount, MountRef};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+use web_sys::Node;
+
+/// 按 key 缓存的行，保存最近一次协调后的顺序
+struct KeyedRows<K, R> {
+    rows: HashMap<K, R>,
+    order: Vec<K>,
+}
+
+impl<K: Hash + Eq + Clone, R> KeyedRows<K, R> {
+    fn new() -> Self {
+        Self {
+            rows: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// 按新列表协调：已有 key 的行原样复用，新 key 调用 `create` 创建，返回被移除的行
+    ///
+    /// 重复的 key 只保留第一次出现的条目。
+    fn reconcile<T>(
+        &mut self,
+        items: Vec<T>,
+        key_fn: &dyn Fn(&T) -> K,
+        mut create: impl FnMut(T) -> R,
+    ) -> Vec<R> {
+        let mut next = HashMap::with_capacity(items.len());
+        let mut order = Vec::with_capacity(items.len());
+        for item in items {
+            let key = key_fn(&item);
+            if next.contains_key(&key) {
+                continue;
+            }
+            let row = match self.rows.remove(&key) {
+                Some(row) => row,
+                None => create(item),
+            };
+            next.insert(key.clone(), row);
+            order.push(key);
+        }
+        self.order = order;
+        std::mem::replace(&mut self.rows, next)
+            .into_values()
+            .collect()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &R> {
+        self.order.iter().filter_map(|key| self.rows.get(key))
+    }
+
+    fn take_all(&mut self) -> Vec<R> {
+        self.order.clear();
+        self.rows.drain().map(|(_, row)| row).collect()
+    }
+}
+
+/// 键控列表的条目与已渲染的行
+///
+/// 条目是唯一的数据来源：卸载时只销毁行，再次挂载时按条目重新创建。
+struct KeyedList<T, K, R> {
+    items: Vec<T>,
+    rows: KeyedRows<K, R>,
+}
+
+impl<T: Clone, K: Hash + Eq + Clone, R> KeyedList<T, K, R> {
+    fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            rows: KeyedRows::new(),
+        }
+    }
+
+    /// 按当前条目协调已渲染的行，返回被移除的行
+    fn sync(&mut self, key_fn: &dyn Fn(&T) -> K, create: impl FnMut(T) -> R) -> Vec<R> {
+        self.rows.reconcile(self.items.clone(), key_fn, create)
+    }
+
+    /// 取出全部已渲染的行，条目保持不变
+    fn unmount(&mut self) -> Vec<R> {
+        self.rows.take_all()
+    }
+}
+
+struct KeyedRow {
+    nodes: Vec<Node>,
+    scope: NodeId,
+}
+
+impl KeyedRow {
+    fn remove(self) {
+        for node in &self.nodes {
+            if let Some(parent) = node.parent_node() {
+                let _ = parent.remove_child(node);
+            }
+        }
+        dispose(self.scope);
+    }
+}
+
+struct KeyedState<T, K, V> {
+    list: RefCell<KeyedList<T, K, KeyedRow>>,
+    key_fn: Box<dyn Fn(&T) -> K>,
+    view_fn: Box<dyn Fn(T) -> V>,
+    /// 挂载后的起止锚点
+    anchors: RefCell<Option<(Node, Node)>>,
+    /// 挂载处的所有者，行作用域归属于它 (而不是调用 `rebuild` 时的所有者)
+    owner: Cell<Option<NodeId>>,
+}
+
+/// 由调用方控制重建时机的键控列表，见 [`keyed`]
+pub struct Keyed<T, K, V> {
+    state: Rc<KeyedState<T, K, V>>,
+}
+
+impl<T, K, V> Clone for Keyed<T, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+/// 键控列表：渲染一个普通的 `Vec`，在显式调用 [`Keyed::rebuild`] 时按 key 复用 DOM。
+///
+/// 与 [`For`](crate::flow::For) 不同，`keyed` 不追踪任何信号，列表只在调用方重建时更新：
+/// 已有 key 的行保留原有节点与作用域 (必要时移动到新位置)，新 key 创建新行，消失的 key 被销毁。
+/// 适用于偶尔整体重建的静态列表。
+///
+/// ```rust,ignore
+/// let list = keyed(load_rows(), |row: &Row| row.id, |row| li(row.name));
+///
+/// button("Sort").on(event::click, {
+///     let list = list.clone();
+///     move |_| list.rebuild(sorted_rows())
+/// });
+/// ul(list)
+/// ```
+pub fn keyed<T, K, V>(
+    items: impl IntoIterator<Item = T>,
+    key_fn: impl Fn(&T) -> K + 'static,
+    view_fn: impl Fn(T) -> V + 'static,
+) -> Keyed<T, K, V>
+where
+    K: Hash + Eq + Clone + 'static,
+    V: Mount + 'static,
+    T: Clone + 'static,
+{
+    Keyed {
+        state: Rc::new(KeyedState {
+            list: RefCell::new(KeyedList::new(items.into_iter().collect())),
+            key_fn: Box::new(key_fn),
+            view_fn: Box::new(view_fn),
+            anchors: RefCell::new(None),
+            owner: Cell::new(None),
+        }),
+    }
+}
+
+impl<T, K, V> Keyed<T, K, V>
+where
+    K: Hash + Eq + Clone + 'static,
+    V: Mount + 'static,
+    T: Clone + 'static,
+{
+    /// 以新的条目重建列表，未挂载时在挂载后生效
+    pub fn rebuild(&self, items: impl IntoIterator<Item = T>) {
+        self.state.list.borrow_mut().items = items.into_iter().collect();
+        let anchors = self.state.anchors.borrow().clone();
+        if let Some((start, end)) = anchors {
+            self.render(&start, &end);
+        }
+    }
+
+    fn create_row(&self, item: T) -> KeyedRow {
+        let fragment = silex_dom::document().create_document_fragment();
+        let fragment_node: Node = fragment.clone().into();
+        let state = self.state.clone();
+        let scope = with_owner(self.state.owner.get(), || {
+            create_scope(move || {
+                (state.view_fn)(item).mount(&fragment_node, Vec::new());
+            })
+        });
+
+        let children = fragment.child_nodes();
+        let nodes = (0..children.length())
+            .filter_map(|i| children.item(i))
+            .collect();
+        KeyedRow { nodes, scope }
+    }
+
+    fn render(&self, start: &Node, end: &Node) {
+        let removed = untrack(|| {
+            self.state
+                .list
+                .borrow_mut()
+                .sync(&*self.state.key_fn, |item| self.create_row(item))
+        });
+        for row in removed {
+            row.remove();
+        }
+
+        let Some(parent) = end.parent_node() else {
+            return;
+        };
+        // 从起始锚点开始扫描，已在正确位置的行不做移动
+        let mut cursor = start.next_sibling();
+        for row in self.state.list.borrow().rows.iter() {
+            let Some(first) = row.nodes.first() else {
+                continue;
+            };
+            let in_place = cursor.as_ref().is_some_and(|c| c.is_same_node(Some(first)));
+            if in_place {
+                for _ in 0..row.nodes.len() {
+                    cursor = cursor.and_then(|c| c.next_sibling());
+                }
+            } else {
+                let before = cursor.as_ref().unwrap_or(end);
+                for node in &row.nodes {
+                    let _ = parent.insert_before(node, Some(before));
+                }
+            }
+        }
+    }
+
+    fn clear(&self) {
+        let rows = self.state.list.borrow_mut().unmount();
+        for row in rows {
+            row.remove();
+        }
+    }
+
+    fn mount_internal(self, parent: &Node, _attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        // 同一列表同一时刻只挂载在一处
+        self.clear();
+
+        let document = silex_dom::document();
+        let start: Node = document.create_comment("keyed-start").into();
+        let end: Node = document.create_comment("keyed-end").into();
+        for anchor in [&start, &end] {
+            if let Err(e) = parent.append_child(anchor).map_err(SilexError::from) {
+                silex_core::error::handle_error(e);
+                return;
+            }
+        }
+        *self.state.anchors.borrow_mut() = Some((start.clone(), end.clone()));
+        self.state.owner.set(current_owner());
+        self.render(&start, &end);
+
+        let this = self.clone();
+        on_cleanup(move || {
+            this.state.anchors.borrow_mut().take();
+            this.state.owner.set(None);
+            this.clear();
+        });
+    }
+}
+
+impl<T, K, V> ApplyAttributes for Keyed<T, K, V> {}
+
+impl<T, K, V> Mount for Keyed<T, K, V>
+where
+    K: Hash + Eq + Clone + 'static,
+    V: Mount + 'static,
+    T: Clone + 'static,
+{
+    fn mount(self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        self.mount_internal(parent, attrs);
+    }
+}
+
+impl<T, K, V> AutoReactiveView for Keyed<T, K, V>
+where
+    K: Hash + Eq + Clone + 'static,
+    V: Mount + 'static,
+    T: Clone + 'static,
+{
+}
+
+impl<T, K, V> MountRef for Keyed<T, K, V>
+where
+    K: Hash + Eq + Clone + 'static,
+    V: Mount + 'static,
+    T: Clone + 'static,
+{
+    fn mount_ref(&self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        self.clone().mount_internal(parent, attrs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebuild_reuses_rows_by_key() {
+        let mut rows = KeyedRows::new();
+        let mut next_id = 0;
+        let mut create = |name: &'static str| {
+            next_id += 1;
+            (name, next_id)
+        };
+        let key = |name: &&'static str| *name;
+
+        let removed = rows.reconcile(vec!["a", "b", "c"], &key, &mut create);
+        assert!(removed.is_empty());
+        assert_eq!(
+            rows.iter().copied().collect::<Vec<_>>(),
+            [("a", 1), ("b", 2), ("c", 3)]
+        );
+
+        // 重排后已有 key 复用原来的行，只有新 key 创建新行
+        let removed = rows.reconcile(vec!["c", "a", "d", "a"], &key, &mut create);
+        assert_eq!(removed, [("b", 2)]);
+        assert_eq!(
+            rows.iter().copied().collect::<Vec<_>>(),
+            [("c", 3), ("a", 1), ("d", 4)]
+        );
+
+        assert_eq!(rows.take_all().len(), 3);
+        assert_eq!(rows.iter().count(), 0);
+    }
+
+    #[test]
+    fn remount_renders_current_items_again() {
+        let mut list = KeyedList::new(vec!["a", "b"]);
+        let mut created = Vec::new();
+        let key = |name: &&'static str| *name;
+
+        list.sync(&key, |name| created.push(name));
+        list.items = vec!["b", "c"];
+        list.sync(&key, |name| created.push(name));
+        assert_eq!(created, ["a", "b", "c"]);
+
+        // 卸载只取出行，再次挂载时按最新条目重新创建全部行
+        assert_eq!(list.unmount().len(), 2);
+        list.sync(&key, |name| created.push(name));
+        assert_eq!(created, ["a", "b", "c", "b", "c"]);
+        assert_eq!(list.rows.iter().count(), 2);
+    }
+}
@@ -0,0 +1,87 @@
+use silex_core::reactivity::{create_scope, provide_context};
+use silex_dom::prelude::{ApplyAttributes, Mount, MountRef};
+use web_sys::Node;
+
+/// Provider 组件：在视图树中声明式地提供上下文
+///
+/// 挂载时创建一个子作用域，在其中调用 `provide_context(value)` 后再挂载子视图，
+/// 因此 `use_context::<T>()` 只在该子树内可见，作用域随父作用域一并销毁。
+///
+/// 子视图中读取上下文的部分需在挂载时求值 (组件或闭包)，而不是在构建视图树时。
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// Provider::new(Theme::Dark, div!(Header(), Content()))
+/// ```
+#[derive(Clone)]
+pub struct Provider<T, V> {
+    value: T,
+    children: V,
+}
+
+impl<T, V> Provider<T, V>
+where
+    T: Clone + 'static,
+{
+    pub fn new(value: T, children: V) -> Self {
+        Self { value, children }
+    }
+}
+
+impl<T, V> ApplyAttributes for Provider<T, V> {}
+
+impl<T, V> Mount for Provider<T, V>
+where
+    T: Clone + 'static,
+    V: Mount,
+{
+    fn mount(self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        let Self { value, children } = self;
+        create_scope(move || {
+            provide_context(value);
+            children.mount(parent, attrs);
+        });
+    }
+}
+
+impl<T, V> MountRef for Provider<T, V>
+where
+    T: Clone + 'static,
+    V: MountRef,
+{
+    fn mount_ref(&self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        create_scope(|| {
+            provide_context(self.value.clone());
+            self.children.mount_ref(parent, attrs);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MountLog, Probe, mount, mount_ref};
+    use silex_core::reactivity::use_context;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Theme {
+        Dark,
+    }
+
+    #[test]
+    fn context_is_visible_only_inside_the_provider() {
+        create_scope(|| {
+            let seen = MountLog::default();
+            // 挂载时记录读取到的主题
+            let probe = || Probe::new(&seen, || format!("{:?}", use_context::<Theme>()));
+
+            mount(Provider::new(Theme::Dark, probe()));
+            mount_ref(&Provider::new(Theme::Dark, probe()));
+            // Provider 之外 (兄弟节点) 不可见
+            mount(probe());
+
+            assert_eq!(*seen.borrow(), ["Some(Dark)", "Some(Dark)", "None"]);
+        });
+    }
+}
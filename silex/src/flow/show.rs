@@ -1,4 +1,5 @@
-use silex_core::traits::{IntoRx, RxGet};
+use silex_core::reactivity::Memo;
+use silex_core::traits::{IntoRx, RxGet, RxRead};
 use silex_dom::prelude::{ApplyAttributes, AutoReactiveView, Mount, MountRef};
 use web_sys::Node;
 
@@ -15,11 +16,42 @@ use web_sys::Node;
 /// Show::new(condition, view)
 ///     .fallback(fallback_view);
 /// ```
+///
+/// 条件在内部经过 `Memo` 去重：重复发出相同的布尔值不会重建分支。
+/// 需要在条件不变时强制重建，可使用 [`Show::keyed`]。
 #[derive(Clone)]
-pub struct Show<Cond, V, FV> {
+pub struct Show<Cond, V, FV, K = ()> {
     condition: Cond,
     view: V,
     fallback: FV,
+    key: K,
+    state_attr: bool,
+}
+
+/// 分支重建所依据的 key，见 [`Show::keyed`]
+pub trait ShowKey: Clone + 'static {
+    type Value: PartialEq + Clone + 'static;
+    fn key(&self) -> Self::Value;
+}
+
+impl ShowKey for () {
+    type Value = ();
+    fn key(&self) {}
+}
+
+/// 以信号的值作为 [`Show`] 的 key
+#[derive(Clone)]
+pub struct KeyedBy<S>(S);
+
+impl<S> ShowKey for KeyedBy<S>
+where
+    S: RxGet + Clone + 'static,
+    S::Value: PartialEq + Clone + 'static,
+{
+    type Value = S::Value;
+    fn key(&self) -> S::Value {
+        self.0.get()
+    }
 }
 
 // 默认无 fallback 的构造函数
@@ -33,19 +65,22 @@ where
             condition,
             view,
             fallback: (),
+            key: (),
+            state_attr: false,
         }
     }
 }
 
 // Builder 方法
-impl<Cond, V, FV> Show<Cond, V, FV>
+impl<Cond, V, FV, K> Show<Cond, V, FV, K>
 where
     Cond: RxGet<Value = bool> + 'static,
     V: MountRef + 'static,
     FV: MountRef + 'static,
+    K: ShowKey,
 {
     /// 设置当条件为 false 时的 fallback 视图 (Else 分支)
-    pub fn fallback<NFV>(self, fallback: NFV) -> Show<Cond, V, NFV>
+    pub fn fallback<NFV>(self, fallback: NFV) -> Show<Cond, V, NFV, K>
     where
         NFV: MountRef + 'static,
     {
@@ -53,72 +88,134 @@ where
             condition: self.condition,
             view: self.view,
             fallback,
+            key: self.key,
+            state_attr: self.state_attr,
+        }
+    }
+
+    /// 当 `key` 的值变化时重建当前分支，即使条件未变
+    ///
+    /// ```rust,ignore
+    /// Show::new(logged_in, move || profile_view())
+    ///     .keyed(user_id)
+    /// ```
+    pub fn keyed<S>(self, key: S) -> Show<Cond, V, FV, KeyedBy<S>>
+    where
+        S: RxGet + Clone + 'static,
+        S::Value: PartialEq + Clone + 'static,
+    {
+        Show {
+            condition: self.condition,
+            view: self.view,
+            fallback: self.fallback,
+            key: KeyedBy(key),
+            state_attr: self.state_attr,
         }
     }
+
+    /// 为渲染的元素添加 `data-state` 属性：主视图为 "open"，fallback 为 "closed"，便于编写进入动画
+    ///
+    /// ```rust,ignore
+    /// Show::new(open, panel).with_state_attr()
+    /// // [data-state="open"] { animation: fade-in 150ms; }
+    /// ```
+    pub fn with_state_attr(mut self) -> Self {
+        self.state_attr = true;
+        self
+    }
 }
 
-impl<Cond, V, FV> ApplyAttributes for Show<Cond, V, FV>
+impl<Cond, V, FV, K> ApplyAttributes for Show<Cond, V, FV, K>
 where
     Cond: RxGet<Value = bool> + 'static,
     V: MountRef + 'static,
     FV: MountRef + 'static,
+    K: ShowKey,
 {
 }
 
-impl<Cond, V, FV> Mount for Show<Cond, V, FV>
+impl<Cond, V, FV, K> Mount for Show<Cond, V, FV, K>
 where
     Cond: RxGet<Value = bool> + Clone + 'static,
     V: MountRef + Clone + 'static,
     FV: MountRef + Clone + 'static,
+    K: ShowKey,
 {
     fn mount(self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
-        mount_show_internal(self.condition, self.view, self.fallback, parent, attrs);
+        mount_show_internal(
+            self.condition,
+            self.view,
+            self.fallback,
+            self.key,
+            self.state_attr,
+            parent,
+            attrs,
+        );
     }
 }
 
-impl<Cond, V, FV> AutoReactiveView for Show<Cond, V, FV>
+impl<Cond, V, FV, K> AutoReactiveView for Show<Cond, V, FV, K>
 where
     Cond: RxGet<Value = bool> + Clone + 'static,
     V: MountRef + Clone + 'static,
     FV: MountRef + Clone + 'static,
+    K: ShowKey,
 {
 }
 
-impl<Cond, V, FV> MountRef for Show<Cond, V, FV>
+impl<Cond, V, FV, K> MountRef for Show<Cond, V, FV, K>
 where
     Cond: RxGet<Value = bool> + Clone + 'static,
     V: MountRef + Clone + 'static,
     FV: MountRef + Clone + 'static,
+    K: ShowKey,
 {
     fn mount_ref(&self, parent: &Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
         mount_show_internal(
             self.condition.clone(),
             self.view.clone(),
             self.fallback.clone(),
+            self.key.clone(),
+            self.state_attr,
             parent,
             attrs,
         );
     }
 }
 
-fn mount_show_internal<Cond, V, FV>(
+/// 分支状态：条件与 key 组成的 Memo，只有两者之一真正变化时才通知重建
+fn show_state<Cond, K>(condition: Cond, key: K) -> Memo<(bool, K::Value)>
+where
+    Cond: RxGet<Value = bool> + 'static,
+    K: ShowKey,
+{
+    Memo::new(move |_| (condition.get(), key.key()))
+}
+
+fn mount_show_internal<Cond, V, FV, K>(
     condition: Cond,
     view: V,
     fallback: FV,
+    key: K,
+    state_attr: bool,
     parent: &Node,
     attrs: Vec<silex_dom::attribute::PendingAttribute>,
 ) where
     Cond: RxGet<Value = bool> + 'static,
     V: MountRef + 'static,
     FV: MountRef + 'static,
+    K: ShowKey,
 {
+    let state = show_state(condition, key);
     use silex_dom::view::any::RenderThunk;
     silex_dom::view::mount_dynamic_view_universal(
         parent,
         attrs,
         RenderThunk::new(move |args| {
             let (p, a) = args;
-            if condition.get() {
+            let open = state.with(|(open, _)| *open);
+            let a = branch_attrs(a, open, state_attr);
+            if open {
                 view.mount_ref(&p, a);
             } else {
                 fallback.mount_ref(&p, a);
@@ -127,6 +224,22 @@ fn mount_show_internal<Cond, V, FV>(
     );
 }
 
+/// 当前分支根元素的属性：启用 [`Show::with_state_attr`] 时追加 `data-state`
+fn branch_attrs(
+    mut attrs: Vec<silex_dom::attribute::PendingAttribute>,
+    open: bool,
+    state_attr: bool,
+) -> Vec<silex_dom::attribute::PendingAttribute> {
+    use silex_dom::attribute::{OwnedApplyTarget, PendingAttribute};
+    if state_attr {
+        attrs.push(PendingAttribute::build(
+            if open { "open" } else { "closed" },
+            OwnedApplyTarget::Attr("data-state".into()),
+        ));
+    }
+    attrs
+}
+
 // --- Signal 扩展 ---
 
 /// Signal 扩展特质，提供 .when() 语法糖
@@ -150,3 +263,83 @@ where
         Show::new(self.into_rx(), view)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{Effect, RwSignal, create_scope};
+    use silex_core::traits::RxWrite;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// 模拟分支渲染：记录 Effect 因状态变化而重建的次数
+    fn count_rebuilds<T: Clone + PartialEq + 'static>(state: Memo<T>) -> Rc<Cell<usize>> {
+        let rebuilds = Rc::new(Cell::new(0));
+        Effect::new({
+            let rebuilds = rebuilds.clone();
+            move |_| {
+                state.with(|_| ());
+                rebuilds.set(rebuilds.get() + 1);
+            }
+        });
+        rebuilds
+    }
+
+    #[test]
+    fn repeated_condition_does_not_rebuild() {
+        create_scope(|| {
+            let visible = RwSignal::new(true);
+            let rebuilds = count_rebuilds(show_state(visible, ()));
+            assert_eq!(rebuilds.get(), 1);
+
+            // 条件信号重复发出 true
+            visible.set(true);
+            visible.set(true);
+            assert_eq!(rebuilds.get(), 1);
+
+            visible.set(false);
+            assert_eq!(rebuilds.get(), 2);
+        });
+    }
+
+    #[test]
+    fn key_change_forces_rebuild() {
+        create_scope(|| {
+            let open = RwSignal::new(true);
+            let user = RwSignal::new(1);
+            let rebuilds = count_rebuilds(show_state(open, KeyedBy(user)));
+
+            open.set(true);
+            assert_eq!(rebuilds.get(), 1);
+            user.set(2);
+            assert_eq!(rebuilds.get(), 2);
+        });
+    }
+
+    #[test]
+    fn state_attr_is_opt_in() {
+        use silex_dom::attribute::{AttrData, AttrOp};
+
+        let show = Show::new(RwSignal::new(true), ());
+        assert!(!show.state_attr);
+        assert!(branch_attrs(Vec::new(), true, show.state_attr).is_empty());
+
+        let show = show.fallback(()).with_state_attr();
+        assert!(show.state_attr);
+        for (open, expected) in [(true, "open"), (false, "closed")] {
+            let attrs = branch_attrs(Vec::new(), open, show.state_attr);
+            let [attr] = attrs.as_slice() else {
+                panic!("expected a single attribute");
+            };
+            let AttrOp::Update {
+                name,
+                data: AttrData::StaticString(value),
+                ..
+            } = &attr.op
+            else {
+                panic!("expected a static attribute update");
+            };
+            assert_eq!((name.as_ref(), value.as_ref()), ("data-state", expected));
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::prelude::*;
+use std::rc::Rc;
+
+/// 字段校验器：返回 `Err(message)` 表示校验失败
+pub type Validator<T> = Rc<dyn Fn(&T) -> Result<(), String>>;
+
+/// 常用校验器
+pub mod validators {
+    use super::Validator;
+    use std::rc::Rc;
+
+    /// 值不能为空 (忽略首尾空白)
+    pub fn required(message: impl Into<String>) -> Validator<String> {
+        let message = message.into();
+        Rc::new(move |value: &String| {
+            if value.trim().is_empty() {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// 至少包含 `min` 个字符
+    pub fn min_length(min: usize, message: impl Into<String>) -> Validator<String> {
+        let message = message.into();
+        Rc::new(move |value: &String| {
+            if value.chars().count() < min {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// 至多包含 `max` 个字符
+    pub fn max_length(max: usize, message: impl Into<String>) -> Validator<String> {
+        let message = message.into();
+        Rc::new(move |value: &String| {
+            if value.chars().count() > max {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+/// 表单字段：值、校验错误与是否被用户操作过
+pub struct Field<T: 'static> {
+    /// 字段的值，可直接用于 `bind_value` 等双向绑定
+    pub value: RwSignal<T>,
+    /// 第一个未通过的校验器给出的错误，随值实时更新
+    pub error: Memo<Option<String>>,
+    /// 用户是否已操作过该字段 (通常在 `blur` 时标记)，用于决定何时显示错误
+    pub touched: RwSignal<bool>,
+}
+
+impl<T> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Field<T> {}
+
+impl<T: 'static> Field<T> {
+    /// 当前值是否通过全部校验
+    pub fn is_valid(&self) -> bool {
+        self.error.with(|e| e.is_none())
+    }
+
+    /// 标记为已操作
+    pub fn touch(&self) {
+        self.touched.set(true);
+    }
+
+    /// 仅在字段已操作过时返回错误，适合直接渲染在输入框下方
+    pub fn visible_error(&self) -> Signal<Option<String>> {
+        let (error, touched) = (self.error, self.touched);
+        Memo::new(move |_| if touched.get() { error.get() } else { None }).into()
+    }
+}
+
+/// Hook: 创建一个带校验的表单字段
+///
+/// `validators` 按顺序执行，值变化时自动重新校验，`error` 为第一个失败的校验结果。
+///
+/// ```rust,ignore
+/// use silex::forms::validators::{min_length, required};
+///
+/// let name = use_field(String::new(), [required("必填"), min_length(3, "至少 3 个字符")]);
+/// input().bind_value(name.value).on(event::blur, move || name.touch());
+/// ```
+pub fn use_field<T: 'static>(
+    initial: T,
+    validators: impl IntoIterator<Item = Validator<T>>,
+) -> Field<T> {
+    let validators: Vec<Validator<T>> = validators.into_iter().collect();
+    let value = RwSignal::new(initial);
+    let error = Memo::new(move |_| {
+        value.with(|v| validators.iter().find_map(|validate| validate(v).err()))
+    });
+    Field {
+        value,
+        error,
+        touched: RwSignal::new(false),
+    }
+}
+
+/// 类型擦除后的字段状态，供 [`use_form`] 汇总
+#[derive(Clone, Copy)]
+pub struct FieldState {
+    pub error: Memo<Option<String>>,
+    pub touched: RwSignal<bool>,
+}
+
+impl<T: 'static> From<Field<T>> for FieldState {
+    fn from(field: Field<T>) -> Self {
+        Self {
+            error: field.error,
+            touched: field.touched,
+        }
+    }
+}
+
+/// 表单：汇总多个字段的校验状态
+#[derive(Clone, Copy)]
+pub struct FormState {
+    fields: StoredValue<Vec<FieldState>>,
+    /// 所有字段均通过校验
+    pub valid: Memo<bool>,
+}
+
+impl FormState {
+    /// 当前所有字段的错误
+    pub fn errors(&self) -> Vec<String> {
+        self.fields
+            .with_untracked(|fields| fields.iter().filter_map(|f| f.error.get()).collect())
+    }
+
+    /// 将所有字段标记为已操作 (e.g. 提交时显示全部错误)
+    pub fn touch_all(&self) {
+        batch(|| {
+            self.fields
+                .with_untracked(|fields| fields.iter().for_each(|f| f.touched.set(true)))
+        });
+    }
+}
+
+/// Hook: 汇总字段，得到整体的校验状态
+///
+/// ```rust,ignore
+/// let form = use_form([name.into(), email.into()]);
+/// button("提交").disabled(rx!(!form.valid.get()));
+/// ```
+pub fn use_form(fields: impl IntoIterator<Item = FieldState>) -> FormState {
+    let fields: Vec<FieldState> = fields.into_iter().collect();
+    let errors: Vec<_> = fields.iter().map(|f| f.error).collect();
+    let valid = Memo::new(move |_| errors.iter().all(|e| e.with(|e| e.is_none())));
+    FormState {
+        fields: StoredValue::new(fields),
+        valid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validators::{min_length, required};
+    use super::*;
+
+    #[test]
+    fn errors_follow_value_changes() {
+        create_scope(|| {
+            let name = use_field(
+                String::new(),
+                [required("required"), min_length(3, "too short")],
+            );
+            let agree = use_field(
+                false,
+                [Rc::new(|v: &bool| {
+                    if *v {
+                        Ok(())
+                    } else {
+                        Err("must agree".to_string())
+                    }
+                }) as Validator<bool>],
+            );
+            let form = use_form([name.into(), agree.into()]);
+
+            assert_eq!(name.error.get(), Some("required".to_string()));
+            assert_eq!(name.visible_error().get(), None, "hidden until touched");
+            assert!(!form.valid.get());
+
+            name.value.set("ab".to_string());
+            assert_eq!(name.error.get(), Some("too short".to_string()));
+
+            name.value.set("abc".to_string());
+            assert_eq!(name.error.get(), None);
+            assert!(name.is_valid());
+            assert_eq!(form.errors(), vec!["must agree".to_string()]);
+
+            agree.value.set(true);
+            assert!(form.valid.get());
+
+            name.value.set("  ".to_string());
+            form.touch_all();
+            assert_eq!(name.visible_error().get(), Some("required".to_string()));
+            assert!(!form.valid.get());
+        });
+    }
+}
@@ -1,13 +1,18 @@
 extern crate self as silex;
 
 pub mod components;
+#[cfg(feature = "json")]
+pub mod custom_event;
 pub mod flow;
+pub mod forms;
 #[cfg(feature = "net")]
 pub mod net;
 #[cfg(feature = "persistence")]
 pub mod persist;
 pub mod router;
 pub mod store;
+#[cfg(test)]
+mod testing;
 
 pub use components::*;
 pub use silex_core::error::{SilexError, SilexResult};
@@ -50,7 +55,10 @@ pub mod hash {
 
 pub mod prelude {
     pub use crate::components::*;
+    #[cfg(feature = "json")]
+    pub use crate::custom_event::*;
     pub use crate::flow::*;
+    pub use crate::forms::*;
     #[cfg(feature = "net")]
     pub use crate::net::*;
     #[cfg(feature = "persistence")]
@@ -76,3 +84,54 @@ pub mod prelude {
     #[cfg(feature = "macros")]
     pub use silex_macros::{global, styled, theme};
 }
+
+#[cfg(all(test, feature = "macros"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn shorthand_helpers_expand_to_longhands() {
+        let css = sty().padding_xy(px(8), px(16)).render().css;
+        for decl in [
+            "padding-left: 8px;",
+            "padding-right: 8px;",
+            "padding-top: 16px;",
+            "padding-bottom: 16px;",
+        ] {
+            assert!(css.contains(decl), "{css}");
+        }
+
+        let css = sty()
+            .inset_all(px(0))
+            .size(pct(100), rem(2))
+            .gap_xy(px(4), px(12))
+            .render()
+            .css;
+        for decl in [
+            "top: 0px;",
+            "right: 0px;",
+            "bottom: 0px;",
+            "left: 0px;",
+            "width: 100%;",
+            "height: 2rem;",
+            "row-gap: 12px;",
+            "column-gap: 4px;",
+        ] {
+            assert!(css.contains(decl), "{css}");
+        }
+    }
+
+    #[test]
+    fn css_macro_keeps_descendant_combinators() {
+        // 选择器中含 `$(..)` 的规则作为模板返回，不注入样式表，可直接检查展开结果
+        let item = "x";
+        let css = css! { .dark & .item-$(item) { color: red; } };
+        let [(template, _)] = css.rules.as_slice() else {
+            panic!("expected one dynamic rule");
+        };
+        assert!(
+            template.starts_with(&format!(".dark .{} .item-{{}} {{", css.class_name)),
+            "{template}"
+        );
+    }
+}
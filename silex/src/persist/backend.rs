@@ -377,16 +377,8 @@ mod tests {
     fn test_query_backend(
         map: silex_core::reactivity::ReadSignal<HashMap<String, String>>,
     ) -> QueryBackend {
-        let (path, set_path) = Signal::pair("/".to_string());
-        let (search, set_search) = Signal::pair(String::new());
         QueryBackend {
-            navigator: Some(Navigator {
-                base_path: "/".to_string(),
-                path,
-                search,
-                set_path,
-                set_search,
-            }),
+            navigator: Some(Navigator::for_test(Vec::new())),
             query_map: Some(Memo::new(move |_| map.get())),
         }
     }
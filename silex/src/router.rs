@@ -1,17 +1,36 @@
+pub mod blocker;
 pub mod context;
+pub mod guard;
 pub mod link;
+pub mod loader;
+pub mod outlet;
+pub mod preload;
+pub mod scroll;
 
+pub use blocker::*;
 pub use context::*;
+pub use guard::*;
 pub use link::*;
-
-use crate::router::context::{RouterContextProps, provide_router_context};
-use silex_core::reactivity::{Effect, Signal, on_cleanup};
-use silex_core::traits::{RxGet, RxWrite};
+pub use loader::*;
+pub use outlet::*;
+pub use preload::*;
+pub use scroll::*;
+
+use crate::router::context::{HistoryCursor, RouterContextProps, provide_router_context};
+use crate::router::guard::NavigationGuard;
+use crate::router::loader::{PreloadCache, install_loader, preload_loader};
+use crate::router::scroll::{history_index, history_key, history_scroll, history_state};
+use silex_core::reactivity::{
+    Effect, Signal, StoredValue, SuspenseContext, on_cleanup, provide_context,
+};
+use silex_core::traits::{RxCloneData, RxError};
+use silex_core::traits::{RxGet, RxRead, RxWrite};
 use silex_dom::view::{AnyView, ApplyAttributes, Mount, MountExt, MountRef};
 use silex_html::div;
+use std::future::Future;
 use std::rc::Rc;
-use wasm_bindgen::JsCast;
 use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
 use web_sys::Event;
 
 /// 路由能力特征
@@ -57,10 +76,18 @@ impl<R: Routable> ToRoute for R {
     }
 }
 
+/// 加载器安装函数：挂载时以当前路径安装加载器，并登记其预加载回调
+type LoaderInstaller = Rc<dyn Fn(Signal<String>, &mut PreloadRegistry)>;
+
 /// 路由器组件
 #[derive(Clone)]
 pub struct Router {
     base_path: String,
+    mode: RouterMode,
+    scroll_behavior: ScrollBehavior,
+    loaders: Vec<LoaderInstaller>,
+    preloads: Vec<(String, PreloadHook)>,
+    guards: Vec<NavigationGuard>,
     child: Option<Rc<dyn Fn() -> AnyView>>,
 }
 
@@ -75,6 +102,11 @@ impl Router {
     pub fn new() -> Self {
         Self {
             base_path: "/".to_string(),
+            mode: RouterMode::History,
+            scroll_behavior: ScrollBehavior::Preserve,
+            loaders: Vec::new(),
+            preloads: Vec::new(),
+            guards: Vec::new(),
             child: None,
         }
     }
@@ -92,6 +124,108 @@ impl Router {
         self
     }
 
+    /// 设置路由模式 (默认为 [`RouterMode::History`])
+    pub fn mode(mut self, mode: RouterMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// 设置路由切换时的滚动行为 (默认为 [`ScrollBehavior::Preserve`])
+    pub fn scroll_behavior(mut self, behavior: ScrollBehavior) -> Self {
+        self.scroll_behavior = behavior;
+        self
+    }
+
+    /// 声明路由级数据加载器
+    ///
+    /// 当前路径匹配 `pattern` (e.g. `/users/:id`) 时以提取出的参数执行 `loader`，
+    /// 参数变化时重新加载，过期请求的结果会被丢弃。结果通过 [`use_route_data`] 获取；
+    /// 加载状态驱动 Router 提供的 `SuspenseContext`，子视图中的 `SuspenseBoundary` 可据此显示 fallback。
+    ///
+    /// 指向匹配路径的 [`Link`] 开启预加载 (见 [`Link::preload`]) 时会提前执行加载器，
+    /// 导航后直接使用预加载的结果。
+    pub fn loader<T, E, F, Fut>(mut self, pattern: &str, loader: F) -> Self
+    where
+        T: RxCloneData,
+        E: RxError,
+        F: Fn(Params) -> Fut + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+    {
+        let pattern = pattern.to_string();
+        let loader = Rc::new(loader);
+        self.loaders.push(Rc::new(move |path, preloads| {
+            let cache = PreloadCache::<T, E>::default();
+            let preload = {
+                let loader = loader.clone();
+                preload_loader(
+                    cache.clone(),
+                    move |params| loader(params),
+                    wasm_bindgen_futures::spawn_local,
+                )
+            };
+            preloads.register(pattern.clone(), Rc::new(preload));
+
+            let loader = loader.clone();
+            install_loader(
+                pattern.clone(),
+                path,
+                cache,
+                move |params| loader(params),
+                wasm_bindgen_futures::spawn_local,
+            );
+        }));
+        self
+    }
+
+    /// 添加全局导航守卫：每次导航 (包括前进/后退) 在渲染目标路由之前调用
+    ///
+    /// 返回 [`NavigationDecision::Redirect`] 时改为导航到新路径，返回 [`NavigationDecision::Block`]
+    /// 时保留当前路由，地址栏也保持不变。多个守卫按添加顺序执行。
+    pub fn before_navigate(
+        self,
+        guard: impl Fn(&Navigation) -> NavigationDecision + 'static,
+    ) -> Self {
+        self.before_navigate_async(move |nav| std::future::ready(guard(nav)))
+    }
+
+    /// 添加异步的全局导航守卫 (e.g. 请求服务端校验登录状态)，判定完成前不会切换路由
+    pub fn before_navigate_async<F, Fut>(mut self, guard: F) -> Self
+    where
+        F: Fn(&Navigation) -> Fut + 'static,
+        Fut: Future<Output = NavigationDecision> + 'static,
+    {
+        self.guards.push(NavigationGuard::new(None, guard));
+        self
+    }
+
+    /// 添加路由级导航守卫：仅在目标路径匹配 `pattern` (e.g. `/admin/*`) 时调用
+    pub fn guard(
+        self,
+        pattern: &str,
+        guard: impl Fn(&Navigation) -> NavigationDecision + 'static,
+    ) -> Self {
+        self.guard_async(pattern, move |nav| std::future::ready(guard(nav)))
+    }
+
+    /// 添加异步的路由级导航守卫
+    pub fn guard_async<F, Fut>(mut self, pattern: &str, guard: F) -> Self
+    where
+        F: Fn(&Navigation) -> Fut + 'static,
+        Fut: Future<Output = NavigationDecision> + 'static,
+    {
+        self.guards
+            .push(NavigationGuard::new(Some(pattern.to_string()), guard));
+        self
+    }
+
+    /// 登记预加载回调：指向匹配 `pattern` 路径的 [`Link`] 预加载时调用 (每个路径只调用一次)
+    ///
+    /// 可用于预取懒加载的代码块或其他资源，例如 `.preload("/docs/*", |_| warm_docs())`。
+    pub fn preload(mut self, pattern: &str, hook: impl Fn(&Params) + 'static) -> Self {
+        self.preloads.push((pattern.to_string(), Rc::new(hook)));
+        self
+    }
+
     /// 设置需要渲染的子视图
     pub fn render<F, V>(mut self, view_fn: F) -> Self
     where
@@ -182,67 +316,135 @@ impl Router {
         let raw_path = location.pathname().unwrap_or_else(|_| "/".into());
         let initial_search = location.search().unwrap_or_else(|_| "".into());
         let base_path = self.base_path.clone();
-
-        // 1.5 初始路径处理：剥离 base_path
-        let initial_path =
-            if !base_path.is_empty() && base_path != "/" && raw_path.starts_with(&base_path) {
-                let p = &raw_path[base_path.len()..];
-                if p.is_empty() {
-                    "/".to_string()
-                } else {
-                    p.to_string()
-                }
+        let mode = self.mode;
+
+        // 1.5 初始路径处理：Hash 模式读取 location.hash，否则剥离 base_path
+        let (initial_path, initial_search) = if mode == RouterMode::Hash {
+            parse_hash_location(&location.hash().unwrap_or_default())
+        } else if !base_path.is_empty() && base_path != "/" && raw_path.starts_with(&base_path) {
+            let p = &raw_path[base_path.len()..];
+            if p.is_empty() {
+                ("/".to_string(), initial_search)
             } else {
-                raw_path
-            };
+                (p.to_string(), initial_search)
+            }
+        } else {
+            (raw_path, initial_search)
+        };
 
         // 2. 初始化信号
         let (path, set_path) = Signal::pair(initial_path);
         let (search, set_search) = Signal::pair(initial_search);
 
+        // 2.5 滚动管理：Restore 模式接管浏览器的滚动恢复，并为当前历史条目分配 key；
+        //     所有模式下都在 state 中记录条目位置，用于撤销被拦截的前进/后退
+        let behavior = self.scroll_behavior;
+        let (initial_key, initial_index, initial_scroll) = match window.history() {
+            Ok(history) => {
+                let state = history.state().unwrap_or(JsValue::NULL);
+                let key = match history_key(&state) {
+                    Some(key) => key,
+                    // 以时间戳为起点，避免刷新页面后与已有历史条目的 key 冲突
+                    None if behavior == ScrollBehavior::Restore => js_sys::Date::now() as u64,
+                    None => 0,
+                };
+                let index = history_index(&state).unwrap_or(0);
+                // 刷新页面后 state 中仍保留离开时的滚动位置
+                let saved = history_scroll(&state);
+                if behavior == ScrollBehavior::Restore {
+                    let _ = history.set_scroll_restoration(web_sys::ScrollRestoration::Manual);
+                }
+                let _ = history.replace_state(&history_state(behavior, key, index, saved), "");
+                (key, index, saved)
+            }
+            Err(_) => (0, 0, None),
+        };
+        let mut manager = ScrollManager::new(behavior, initial_key);
+        if let Some(saved) = initial_scroll {
+            manager.remember(saved);
+        }
+        let scroll = StoredValue::new(manager);
+        let history = StoredValue::new(HistoryCursor::new(initial_index));
+
+        let mut registry = PreloadRegistry::default();
+        for (pattern, hook) in &self.preloads {
+            registry.register(pattern.clone(), hook.clone());
+        }
+        let preloads = StoredValue::new(registry);
+
         // 3. 提供 Context
-        provide_router_context(RouterContextProps {
+        let navigator = provide_router_context(RouterContextProps {
             base_path: base_path.clone(),
+            mode,
             path,
             search,
             set_path,
             set_search,
+            scroll,
+            history,
+            preloads,
+            guards: self.guards.clone().into(),
         });
 
-        // 4. 监听 popstate
-        let set_path_clone = set_path;
-        let set_search_clone = set_search;
-        let base_path_clone = base_path.clone();
-
-        let on_popstate = Closure::wrap(Box::new(move |_e: Event| {
-            let win = web_sys::window().unwrap();
-            let loc = win.location();
-
-            // 处理路径变化
-            if let Ok(raw_p) = loc.pathname() {
-                let p = if !base_path_clone.is_empty()
-                    && base_path_clone != "/"
-                    && raw_p.starts_with(&base_path_clone)
-                {
-                    let s = &raw_p[base_path_clone.len()..];
-                    if s.is_empty() {
-                        "/".to_string()
-                    } else {
-                        s.to_string()
+        // 3.2 初始地址同样经过导航守卫，被重定向时替换当前历史条目
+        {
+            let initial = navigator.current_url();
+            let nav = navigator.clone();
+            navigator.guarded(
+                initial.clone(),
+                wasm_bindgen_futures::spawn_local,
+                move |target| {
+                    if let Some(target) = target.filter(|t| *t != initial) {
+                        nav.commit_navigation(&target, true);
                     }
-                } else {
-                    raw_p
-                };
-                set_path_clone.set(p);
-            }
+                },
+            );
+        }
 
-            if let Ok(s) = loc.search() {
-                set_search_clone.set(s);
+        // 3.5 路由级数据加载器：共享一个 SuspenseContext，供子视图中的 SuspenseBoundary 使用
+        if !self.loaders.is_empty() {
+            provide_context(SuspenseContext::new());
+            for install in &self.loaders {
+                preloads.update_untracked(|registry| install(path.into(), registry));
             }
+        }
+
+        // 4. 监听 popstate (Hash 模式监听 hashchange)
+        let event_name = match mode {
+            RouterMode::History => "popstate",
+            RouterMode::Hash => "hashchange",
+        };
+        // 页面隐藏 (刷新、离开或进入 bfcache) 前将滚动位置写入当前条目的 state
+        let on_pagehide = {
+            let navigator = navigator.clone();
+            Closure::wrap(
+                Box::new(move |_e: Event| navigator.save_scroll_state()) as Box<dyn FnMut(Event)>
+            )
+        };
+        let on_popstate = Closure::wrap(Box::new(move |_e: Event| {
+            let state = web_sys::window()
+                .and_then(|w| w.history().ok())
+                .and_then(|h| h.state().ok())
+                .unwrap_or(JsValue::NULL);
+            navigator.handle_pop(
+                navigator.read_location(),
+                history_index(&state),
+                history_key(&state),
+                history_scroll(&state),
+                wasm_bindgen_futures::spawn_local,
+                |delta| {
+                    if let Some(history) = web_sys::window().and_then(|w| w.history().ok()) {
+                        let _ = history.go_with_delta(delta);
+                    }
+                },
+            );
         }) as Box<dyn FnMut(Event)>);
 
         window
-            .add_event_listener_with_callback("popstate", on_popstate.as_ref().unchecked_ref())
+            .add_event_listener_with_callback(event_name, on_popstate.as_ref().unchecked_ref())
+            .unwrap();
+        window
+            .add_event_listener_with_callback("pagehide", on_pagehide.as_ref().unchecked_ref())
             .unwrap();
 
         // 5. 挂载容器
@@ -254,9 +456,13 @@ impl Router {
         on_cleanup(move || {
             let w = web_sys::window().unwrap();
             let _ = w.remove_event_listener_with_callback(
-                "popstate",
+                event_name,
                 on_popstate.as_ref().unchecked_ref(),
             );
+            let _ = w.remove_event_listener_with_callback(
+                "pagehide",
+                on_pagehide.as_ref().unchecked_ref(),
+            );
         });
 
         // 7. 渲染 Child
@@ -274,5 +480,80 @@ impl Router {
                 view.mount(&parent, Vec::new());
             });
         }
+
+        // 8. 刷新页面后恢复 state 中记录的滚动位置
+        if behavior == ScrollBehavior::Restore && initial_scroll.is_some() {
+            scroll.with_untracked(|m| m.finish(&WindowScroll, true));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::Route;
+
+    #[derive(Route, Clone, Debug, PartialEq)]
+    enum FileRoute {
+        #[route("/")]
+        Home,
+        #[route("/files/*path")]
+        Files { path: String },
+        #[route("/posts/:id/:slug?")]
+        Post { id: u32, slug: Option<String> },
+        #[route("/*")]
+        NotFound,
+    }
+
+    #[test]
+    fn named_wildcard_route_captures_rest() {
+        let route = FileRoute::match_path("/files/a/b/c.txt").unwrap();
+        assert_eq!(
+            route,
+            FileRoute::Files {
+                path: "a/b/c.txt".into()
+            }
+        );
+        assert_eq!(route.to_path(), "/files/a/b/c.txt");
+
+        assert_eq!(
+            FileRoute::match_path("/files/my%20notes.md"),
+            Some(FileRoute::Files {
+                path: "my notes.md".into()
+            })
+        );
+        assert_eq!(
+            FileRoute::match_path("/files"),
+            Some(FileRoute::Files {
+                path: String::new()
+            })
+        );
+        assert_eq!(
+            FileRoute::Files {
+                path: String::new()
+            }
+            .to_path(),
+            "/files"
+        );
+        assert_eq!(FileRoute::match_path("/"), Some(FileRoute::Home));
+        assert_eq!(FileRoute::match_path("/other"), Some(FileRoute::NotFound));
+    }
+
+    #[test]
+    fn optional_trailing_segment_route() {
+        let short = FileRoute::Post { id: 5, slug: None };
+        let long = FileRoute::Post {
+            id: 5,
+            slug: Some("hello".into()),
+        };
+        assert_eq!(FileRoute::match_path("/posts/5"), Some(short.clone()));
+        assert_eq!(FileRoute::match_path("/posts/5/hello"), Some(long.clone()));
+        assert_eq!(short.to_path(), "/posts/5");
+        assert_eq!(long.to_path(), "/posts/5/hello");
+
+        assert_eq!(
+            FileRoute::match_path("/posts/5/hello/extra"),
+            Some(FileRoute::NotFound)
+        );
     }
 }
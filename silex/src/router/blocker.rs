@@ -0,0 +1,392 @@
+use crate::router::context::{Navigator, use_router};
+use crate::router::guard::Navigation;
+use silex_core::callback::Callback;
+use silex_core::reactivity::{StoredValue, on_cleanup};
+use silex_core::traits::{RxRead, RxWrite};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+
+/// 未设置确认回调时，浏览器原生确认框中显示的提示
+const DEFAULT_MESSAGE: &str = "You have unsaved changes. Leave this page?";
+
+#[derive(Default)]
+struct Answer {
+    proceed: Option<bool>,
+    waker: Option<Waker>,
+}
+
+/// 被拦截、等待确认的导航
+///
+/// 交给 [`Blocker::on_blocked`] 设置的回调，通常用于显示确认对话框：
+/// 调用 [`proceed`](Self::proceed) 继续导航，调用 [`cancel`](Self::cancel) 保留当前页面。
+#[derive(Clone)]
+pub struct PendingNavigation {
+    pub navigation: Navigation,
+    answer: Rc<RefCell<Answer>>,
+}
+
+impl PendingNavigation {
+    /// 确认离开，继续导航
+    pub fn proceed(&self) {
+        self.answer(true);
+    }
+
+    /// 取消导航，URL 与视图保持不变
+    pub fn cancel(&self) {
+        self.answer(false);
+    }
+
+    fn answer(&self, proceed: bool) {
+        let mut answer = self.answer.borrow_mut();
+        if answer.proceed.is_none() {
+            answer.proceed = Some(proceed);
+            if let Some(waker) = answer.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// 等待 [`PendingNavigation`] 被确认或取消
+struct Confirmation(Rc<RefCell<Answer>>);
+
+impl Future for Confirmation {
+    type Output = bool;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        let mut answer = self.0.borrow_mut();
+        match answer.proceed {
+            Some(proceed) => Poll::Ready(proceed),
+            None => {
+                answer.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct BlockerEntry {
+    id: usize,
+    when: Rc<dyn Fn() -> bool>,
+    on_blocked: Option<Callback<PendingNavigation>>,
+}
+
+impl BlockerEntry {
+    /// 询问是否允许离开：有确认回调时等待用户选择，否则使用浏览器原生确认框
+    async fn confirm(self, navigation: Navigation) -> bool {
+        match self.on_blocked {
+            Some(callback) => {
+                let answer = Rc::new(RefCell::new(Answer::default()));
+                callback.call(PendingNavigation {
+                    navigation,
+                    answer: answer.clone(),
+                });
+                Confirmation(answer).await
+            }
+            None => web_sys::window()
+                .and_then(|w| w.confirm_with_message(DEFAULT_MESSAGE).ok())
+                .unwrap_or(true),
+        }
+    }
+}
+
+/// 当前已注册的导航拦截器
+#[derive(Default)]
+pub struct BlockerRegistry {
+    next_id: usize,
+    entries: Vec<BlockerEntry>,
+}
+
+impl BlockerRegistry {
+    /// 条件成立、需要确认的拦截器
+    fn active(&self) -> Vec<BlockerEntry> {
+        self.entries
+            .iter()
+            .filter(|b| (b.when)())
+            .cloned()
+            .collect()
+    }
+}
+
+/// 依次询问所有生效的拦截器，全部确认后才允许导航
+pub(crate) fn confirm_navigation(
+    blockers: StoredValue<BlockerRegistry>,
+    navigation: &Navigation,
+) -> Option<impl Future<Output = bool> + 'static> {
+    let active = blockers
+        .try_with_untracked(|r| r.active())
+        .unwrap_or_default();
+    if active.is_empty() {
+        return None;
+    }
+    let navigation = navigation.clone();
+    Some(async move {
+        for blocker in active {
+            if !blocker.confirm(navigation.clone()).await {
+                return false;
+            }
+        }
+        true
+    })
+}
+
+/// 导航拦截器句柄，见 [`use_blocker`]
+#[derive(Clone)]
+pub struct Blocker {
+    id: usize,
+    when: Rc<dyn Fn() -> bool>,
+    registry: Option<StoredValue<BlockerRegistry>>,
+}
+
+impl Blocker {
+    /// 设置导航被拦截时的回调 (e.g. 显示确认对话框)；未设置时使用浏览器原生确认框
+    pub fn on_blocked(self, callback: impl Into<Callback<PendingNavigation>>) -> Self {
+        let callback = callback.into();
+        if let Some(registry) = self.registry {
+            registry.try_update_untracked(|r| {
+                if let Some(entry) = r.entries.iter_mut().find(|b| b.id == self.id) {
+                    entry.on_blocked = Some(callback);
+                }
+            });
+        }
+        self
+    }
+
+    /// 条件成立时同样拦截关闭/刷新页面 (`beforeunload`)，由浏览器显示原生提示
+    pub fn before_unload(self) -> Self {
+        let Some(window) = web_sys::window() else {
+            return self;
+        };
+        let when = self.when.clone();
+        let listener = Closure::wrap(Box::new(move |e: web_sys::Event| {
+            if when() {
+                e.prevent_default();
+                let _ = js_sys::Reflect::set(&e, &"returnValue".into(), &"".into());
+            }
+        }) as Box<dyn FnMut(web_sys::Event)>);
+
+        let _ = window
+            .add_event_listener_with_callback("beforeunload", listener.as_ref().unchecked_ref());
+        on_cleanup(move || {
+            let _ = window.remove_event_listener_with_callback(
+                "beforeunload",
+                listener.as_ref().unchecked_ref(),
+            );
+        });
+        self
+    }
+
+    /// 移除拦截器 (所属作用域销毁时也会自动移除)
+    pub fn remove(&self) {
+        if let Some(registry) = self.registry {
+            registry.try_update_untracked(|r| r.entries.retain(|b| b.id != self.id));
+        }
+    }
+}
+
+impl Navigator {
+    /// 注册导航拦截器：`when` 返回 `true` 时，应用内导航 (包括前进/后退) 需要先确认
+    pub fn block(&self, when: impl Fn() -> bool + 'static) -> Blocker {
+        let when: Rc<dyn Fn() -> bool> = Rc::new(when);
+        let id = self.blockers.update_untracked(|r| {
+            r.next_id += 1;
+            r.entries.push(BlockerEntry {
+                id: r.next_id,
+                when: when.clone(),
+                on_blocked: None,
+            });
+            r.next_id
+        });
+        let blocker = Blocker {
+            id,
+            when,
+            registry: Some(self.blockers),
+        };
+        let handle = blocker.clone();
+        on_cleanup(move || handle.remove());
+        blocker
+    }
+}
+
+/// Hook: 在条件成立时拦截导航 (e.g. 表单有未保存的修改)
+///
+/// 多个拦截器同时生效时会依次询问，全部确认后才继续导航；取消时 URL 与视图保持不变。
+///
+/// ```rust,ignore
+/// let dirty = RwSignal::new(false);
+/// use_blocker(move || dirty.get_untracked()).on_blocked(move |pending: PendingNavigation| {
+///     show_dialog.set(Some(pending));
+/// });
+/// ```
+pub fn use_blocker(when: impl Fn() -> bool + 'static) -> Blocker {
+    match use_router() {
+        Some(router) => router.navigator.block(when),
+        None => Blocker {
+            id: 0,
+            when: Rc::new(when),
+            registry: None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::router::loader::LocalFuture;
+    use silex_core::reactivity::create_scope;
+    use silex_core::traits::RxGet;
+    use std::cell::Cell;
+
+    struct Harness {
+        nav: Navigator,
+        tasks: Rc<RefCell<Vec<LocalFuture>>>,
+    }
+
+    impl Harness {
+        fn new() -> Self {
+            Self {
+                nav: Navigator::for_test(Vec::new()),
+                tasks: Rc::default(),
+            }
+        }
+
+        fn push(&self, url: &str) {
+            let nav = self.nav.clone();
+            let tasks = self.tasks.clone();
+            self.nav.guarded(
+                url.to_string(),
+                move |fut| tasks.borrow_mut().push(fut),
+                move |target| {
+                    if let Some(target) = target {
+                        nav.update_location(&target, String::new());
+                    }
+                },
+            );
+            self.poll();
+        }
+
+        /// 模拟前进/后退到位置为 `index` 的条目，`go` 记录撤销跳转时的 `history.go` 偏移量
+        fn pop(&self, url: &str, index: u64, go: &Rc<RefCell<Vec<i32>>>) {
+            let tasks = self.tasks.clone();
+            let go = go.clone();
+            self.nav.handle_pop(
+                (url.to_string(), String::new()),
+                Some(index),
+                None,
+                None,
+                move |fut| tasks.borrow_mut().push(fut),
+                move |delta| go.borrow_mut().push(delta),
+            );
+            self.poll();
+        }
+
+        fn poll(&self) {
+            let mut cx = Context::from_waker(Waker::noop());
+            self.tasks
+                .borrow_mut()
+                .retain_mut(|fut| fut.as_mut().poll(&mut cx).is_pending());
+        }
+    }
+
+    fn prompts(blocker: Blocker) -> Rc<RefCell<Vec<PendingNavigation>>> {
+        let prompts = Rc::new(RefCell::new(Vec::new()));
+        let log = prompts.clone();
+        blocker.on_blocked(move |pending: PendingNavigation| log.borrow_mut().push(pending));
+        prompts
+    }
+
+    #[test]
+    fn denying_blocker_keeps_route_until_removed() {
+        create_scope(|| {
+            let h = Harness::new();
+            let dirty = Rc::new(Cell::new(true));
+            let blocker = h.nav.block({
+                let dirty = dirty.clone();
+                move || dirty.get()
+            });
+            let asked = prompts(blocker.clone());
+
+            h.push("/other");
+            assert_eq!(asked.borrow().len(), 1);
+            assert_eq!(asked.borrow()[0].navigation.to, "/other");
+            asked.borrow()[0].cancel();
+            h.poll();
+            assert_eq!(
+                h.nav.path.get(),
+                "/",
+                "cancelled navigation keeps the route"
+            );
+
+            // 条件不成立时不拦截
+            dirty.set(false);
+            h.push("/clean");
+            assert_eq!(h.nav.path.get(), "/clean");
+            assert_eq!(asked.borrow().len(), 1);
+
+            dirty.set(true);
+            blocker.remove();
+            h.push("/other");
+            assert_eq!(h.nav.path.get(), "/other");
+        });
+    }
+
+    #[test]
+    fn all_active_blockers_are_consulted() {
+        create_scope(|| {
+            let h = Harness::new();
+            let first = prompts(h.nav.block(|| true));
+            let second = prompts(h.nav.block(|| true));
+
+            h.push("/next");
+            assert_eq!((first.borrow().len(), second.borrow().len()), (1, 0));
+            first.borrow()[0].proceed();
+            h.poll();
+            assert_eq!(second.borrow().len(), 1, "second blocker asked after first");
+            second.borrow()[0].cancel();
+            h.poll();
+            assert_eq!(h.nav.path.get(), "/");
+
+            h.push("/next");
+            first.borrow()[1].proceed();
+            h.poll();
+            second.borrow()[1].proceed();
+            h.poll();
+            assert_eq!(h.nav.path.get(), "/next");
+        });
+    }
+
+    #[test]
+    fn blocked_back_returns_with_history_go_instead_of_pushing() {
+        create_scope(|| {
+            let h = Harness::new();
+            // 当前位于会话历史的第 2 个条目
+            h.nav.update_location("/b", String::new());
+            h.nav.history.update_untracked(|c| c.commit(2));
+            let asked = prompts(h.nav.block(|| true));
+            let go = Rc::new(RefCell::new(Vec::new()));
+
+            h.pop("/a", 1, &go);
+            asked.borrow()[0].cancel();
+            h.poll();
+            assert_eq!(*go.borrow(), vec![1], "forward one entry back to /b");
+            assert_eq!(h.nav.path.get(), "/b");
+
+            // history.go(1) 触发的 popstate 不再询问拦截器
+            h.pop("/b", 2, &go);
+            assert_eq!(asked.borrow().len(), 1);
+
+            // 再次后退仍回到同一条目，历史不会变长
+            h.pop("/a", 1, &go);
+            asked.borrow()[1].cancel();
+            h.poll();
+            assert_eq!(*go.borrow(), vec![1, 1]);
+            assert_eq!(h.nav.history.with_untracked(|c| c.index()), 2);
+        });
+    }
+}
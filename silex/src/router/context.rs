@@ -1,8 +1,21 @@
-use silex_core::reactivity::{Memo, ReadSignal, Signal, WriteSignal, provide_context, use_context};
-use silex_core::traits::{RxGet, RxWrite};
+use crate::router::blocker::{BlockerRegistry, confirm_navigation};
+use crate::router::guard::{Navigation, NavigationGuard, resolve_navigation};
+use crate::router::loader::LocalFuture;
+use crate::router::preload::PreloadRegistry;
+use crate::router::scroll::{
+    ScrollBehavior, ScrollHost, ScrollManager, ScrollPosition, WindowScroll, history_state,
+};
+use silex_core::reactivity::{
+    Effect, Memo, ReadSignal, RwSignal, Signal, StoredValue, WriteSignal, provide_context, untrack,
+    use_context,
+};
+use silex_core::traits::{RxGet, RxRead, RxWrite};
 use silex_dom::view::{AnyView, ApplyAttributes, Mount, MountRef};
+use std::cell::Cell;
 use std::collections::HashMap;
+use std::fmt::Display;
 use std::rc::Rc;
+use std::str::FromStr;
 use wasm_bindgen::JsCast;
 use web_sys::Node;
 
@@ -32,61 +45,307 @@ impl MountRef for RouterViewFactory {
     }
 }
 
+/// 路由模式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RouterMode {
+    /// 基于 History API 的路径 (e.g. `/app/users?page=2`)
+    #[default]
+    History,
+    /// 路由位于 `location.hash` 中 (e.g. `#/users?page=2`)，适用于无法配置服务端重写的静态托管
+    Hash,
+}
+
+impl RouterMode {
+    /// 计算逻辑路径在 `<a href>` 中应渲染的地址
+    pub fn href(&self, base_path: &str, url: &str) -> String {
+        match self {
+            RouterMode::History => {
+                if !base_path.is_empty() && base_path != "/" && url.starts_with('/') {
+                    format!("{}{}", base_path.trim_end_matches('/'), url)
+                } else {
+                    url.to_string()
+                }
+            }
+            RouterMode::Hash => {
+                if url.starts_with('#') {
+                    url.to_string()
+                } else {
+                    format!("#{}", url)
+                }
+            }
+        }
+    }
+}
+
+/// 将 `location.hash` 拆分为逻辑路径与查询字符串 (e.g. `#/users?page=2` -> (`/users`, `?page=2`))
+pub fn parse_hash_location(hash: &str) -> (String, String) {
+    let route = hash.trim_start_matches('#');
+    let (path, search) = match route.find('?') {
+        Some(i) => route.split_at(i),
+        None => (route, ""),
+    };
+    let path = if path.is_empty() {
+        "/".to_string()
+    } else if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    };
+    (path, search.to_string())
+}
+
 /// 路由上下文，存储当前的路由状态
 #[derive(Clone)]
 pub struct RouterContext {
     /// 基础路径 (e.g. "/app")
     pub base_path: String,
+    /// 路由模式
+    pub mode: RouterMode,
     /// 当前路径 (pathname, relative to base_path)
     pub path: ReadSignal<String>,
     /// 当前查询参数 (search string)
     pub search: ReadSignal<String>,
     /// 导航控制器
     pub navigator: Navigator,
+    pub(crate) preloads: StoredValue<PreloadRegistry>,
+}
+
+impl RouterContext {
+    /// 预加载 `url` 指向的路由：执行匹配的加载器与预加载回调 (同一路径只触发一次)
+    pub fn preload(&self, url: &str) {
+        let hooks = self.preloads.update_untracked(|r| r.preload(url));
+        for (hook, params) in hooks {
+            hook(&params);
+        }
+    }
 }
 
 /// 导航控制器，用于执行路由跳转
 #[derive(Clone)]
 pub struct Navigator {
     pub(crate) base_path: String,
+    pub(crate) mode: RouterMode,
     pub(crate) path: ReadSignal<String>,
     pub(crate) search: ReadSignal<String>,
     pub(crate) set_path: WriteSignal<String>,
     pub(crate) set_search: WriteSignal<String>,
+    pub(crate) scroll: StoredValue<ScrollManager>,
+    pub(crate) guards: Rc<[NavigationGuard]>,
+    pub(crate) blockers: StoredValue<BlockerRegistry>,
+    pub(crate) history: StoredValue<HistoryCursor>,
+    /// 最近一次导航的序号，异步判定完成时据此丢弃已被后续导航取代的结果
+    pub(crate) navigation: Rc<Cell<u64>>,
+}
+
+/// 当前历史条目在会话历史中的位置 (记录在 `history.state` 中)
+///
+/// 前进/后退被拦截时，根据位置差用 `history.go(delta)` 撤销这次跳转，
+/// 而不是压入新的条目 (那样会丢失前进栈，且每次被拦截都会让历史变长)。
+#[derive(Default)]
+pub(crate) struct HistoryCursor {
+    index: u64,
+    /// 正在通过 `history.go` 返回当前条目，对应的 popstate 不再经过拦截器与守卫
+    restoring: bool,
+}
+
+impl HistoryCursor {
+    pub(crate) fn new(index: u64) -> Self {
+        Self {
+            index,
+            restoring: false,
+        }
+    }
+
+    pub(crate) fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// push 新的历史条目，返回新条目的位置
+    pub(crate) fn push(&mut self) -> u64 {
+        self.index += 1;
+        self.index
+    }
+
+    /// popstate 到达位置为 `index` 的条目 (`None`: 条目没有记录位置，如手动修改 hash，视为新压入的条目)
+    ///
+    /// 返回需要处理的目标位置；撤销被拦截的跳转所产生的 popstate 返回 `None`。
+    pub(crate) fn arrive(&mut self, index: Option<u64>) -> Option<u64> {
+        let index = index.unwrap_or(self.index + 1);
+        if std::mem::take(&mut self.restoring) && index == self.index {
+            return None;
+        }
+        Some(index)
+    }
+
+    /// 跳转已确认，当前条目切换为 `index`
+    pub(crate) fn commit(&mut self, index: u64) {
+        self.index = index;
+    }
+
+    /// 跳转到 `index` 被阻止：返回回到当前条目所需的 `history.go` 偏移量
+    pub(crate) fn undo(&mut self, index: u64) -> i32 {
+        let delta = self.index as i64 - index as i64;
+        self.restoring = delta != 0;
+        delta as i32
+    }
 }
 
 impl Navigator {
+    /// 测试用的导航控制器：History 模式、位于 `/`，只带给定的导航守卫
+    #[cfg(test)]
+    pub(crate) fn for_test(guards: Vec<NavigationGuard>) -> Self {
+        let (path, set_path) = silex_core::reactivity::Signal::pair("/".to_string());
+        let (search, set_search) = silex_core::reactivity::Signal::pair(String::new());
+        Self {
+            base_path: "/".to_string(),
+            mode: RouterMode::History,
+            path,
+            search,
+            set_path,
+            set_search,
+            scroll: StoredValue::new(ScrollManager::new(ScrollBehavior::Preserve, 0)),
+            guards: guards.into(),
+            blockers: StoredValue::new(BlockerRegistry::default()),
+            history: StoredValue::new(HistoryCursor::default()),
+            navigation: Rc::default(),
+        }
+    }
+
     fn handle_navigation(&self, url: &str, replace: bool) {
-        let window = web_sys::window().unwrap();
+        let this = self.clone();
+        self.guarded(
+            url.to_string(),
+            wasm_bindgen_futures::spawn_local,
+            move |target| {
+                if let Some(target) = target {
+                    this.commit_navigation(&target, replace);
+                }
+            },
+        );
+    }
+
+    /// 当前逻辑 URL (路径 + 查询字符串)
+    pub(crate) fn current_url(&self) -> String {
+        format!(
+            "{}{}",
+            self.path.get_untracked(),
+            self.search.get_untracked()
+        )
+    }
 
-        // 1. 构造用于浏览器历史记录的完整 URL
-        let full_url = if url.starts_with('/') {
-            if self.base_path == "/" || self.base_path.is_empty() {
-                url.to_string()
+    /// 依次询问导航拦截器与导航守卫，并以最终目标调用 `commit` (被阻止时为 `None`)
+    ///
+    /// 没有拦截器与守卫时同步调用 `commit`；否则在 `spawn` 的任务中依次执行，
+    /// 判定完成前不会修改历史记录与路由状态。判定期间发起了新的导航时，
+    /// 这次的结果已过期，不再调用 `commit`。
+    pub(crate) fn guarded<S, C>(&self, url: String, spawn: S, commit: C)
+    where
+        S: FnOnce(LocalFuture),
+        C: FnOnce(Option<String>) + 'static,
+    {
+        let id = self.navigation.get().wrapping_add(1);
+        self.navigation.set(id);
+
+        let navigation = Navigation {
+            from: self.current_url(),
+            to: url,
+        };
+        let confirmation = confirm_navigation(self.blockers, &navigation);
+        if confirmation.is_none() && self.guards.is_empty() {
+            commit(Some(navigation.to));
+            return;
+        }
+        let guards = self.guards.clone();
+        let latest = self.navigation.clone();
+        spawn(Box::pin(async move {
+            let confirmed = match confirmation {
+                Some(confirmation) => confirmation.await,
+                None => true,
+            };
+            let target = if confirmed {
+                resolve_navigation(guards, navigation.from, navigation.to).await
             } else {
-                let base = self.base_path.trim_end_matches('/');
-                format!("{}{}", base, url)
+                None
+            };
+            if latest.get() == id {
+                commit(target);
             }
-        } else {
-            url.to_string()
-        };
+        }));
+    }
 
-        // 2. 使用 History API
+    /// 写入历史记录并更新路由状态 (已通过导航守卫)
+    pub(crate) fn commit_navigation(&self, url: &str, replace: bool) {
+        let window = web_sys::window().unwrap();
+
+        // 1. 构造用于浏览器历史记录的完整 URL (Hash 模式下为 `#/path`)
+        let full_url = self.mode.href(&self.base_path, url);
+
+        // 2. 使用 History API，state 中记录历史条目的位置与用于滚动恢复的 key
+        let behavior = self.scroll.with_untracked(|m| m.behavior());
         if let Ok(history) = window.history() {
             if replace {
+                let key = self.scroll.with_untracked(|m| m.current_key());
+                let index = self.history.with_untracked(|h| h.index());
                 let _ = history.replace_state_with_url(
-                    &wasm_bindgen::JsValue::NULL,
+                    &history_state(behavior, key, index, None),
                     "",
                     Some(&full_url),
                 );
             } else {
-                let _ =
-                    history.push_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&full_url));
+                // 离开前将滚动位置写入当前条目的 state
+                self.save_scroll_state();
+                let key = self
+                    .scroll
+                    .update_untracked(|m| m.begin_push(&WindowScroll));
+                let index = self.history.update_untracked(|h| h.push());
+                let _ = history.push_state_with_url(
+                    &history_state(behavior, key, index, None),
+                    "",
+                    Some(&full_url),
+                );
             }
         }
 
-        // 3. 读取当前状态并更新信号 (需要剥离 base_path)
-        let location = window.location();
+        // 3. 读取当前状态并更新信号
+        self.sync_location();
+
+        // 4. 新视图已渲染，调整滚动位置
+        if !replace {
+            self.scroll
+                .with_untracked(|m| m.finish(&WindowScroll, false));
+        }
+    }
+
+    /// `Restore` 模式下将当前滚动位置写入当前历史条目的 `history.state`
+    ///
+    /// 在 push 离开条目前与页面隐藏 (`pagehide`，含刷新与进入 bfcache) 时调用。
+    pub(crate) fn save_scroll_state(&self) {
+        let (behavior, key) = self
+            .scroll
+            .with_untracked(|m| (m.behavior(), m.current_key()));
+        if behavior != ScrollBehavior::Restore {
+            return;
+        }
+        let index = self.history.with_untracked(|h| h.index());
+        if let Some(history) = web_sys::window().and_then(|w| w.history().ok()) {
+            let state = history_state(behavior, key, index, Some(WindowScroll.offset()));
+            let _ = history.replace_state(&state, "");
+        }
+    }
+
+    /// 从 `window.location` 读取逻辑路径与查询字符串并更新信号
+    pub(crate) fn sync_location(&self) {
+        let (path, search) = self.read_location();
+        self.update_location(&path, search);
+    }
+
+    /// 从 `window.location` 读取逻辑路径与查询字符串 (需要剥离 base_path)
+    pub(crate) fn read_location(&self) -> (String, String) {
+        let location = web_sys::window().unwrap().location();
+        if self.mode == RouterMode::Hash {
+            return parse_hash_location(&location.hash().unwrap_or_default());
+        }
+
         let raw_path = location.pathname().unwrap_or_else(|_| "/".to_string());
 
         let logical_path = if !self.base_path.is_empty()
@@ -100,13 +359,60 @@ impl Navigator {
         };
 
         let search = location.search().unwrap_or_default();
+        (logical_path.to_string(), search)
+    }
+
+    /// 处理前进/后退 (popstate)：经过拦截器与守卫后切换路由
+    ///
+    /// * `index` / `key` / `saved`: 目标条目 `history.state` 中记录的位置、滚动 key 与滚动位置
+    /// * `go`: 被阻止时以位置差调用 (即 `history.go(delta)`)，浏览器回到当前条目
+    pub(crate) fn handle_pop<S, G>(
+        &self,
+        (path, search): (String, String),
+        index: Option<u64>,
+        key: Option<u64>,
+        saved: Option<ScrollPosition>,
+        spawn: S,
+        go: G,
+    ) where
+        S: FnOnce(LocalFuture),
+        G: FnOnce(i32) + 'static,
+    {
+        let Some(index) = self.history.update_untracked(|h| h.arrive(index)) else {
+            return;
+        };
+        let requested = format!("{}{}", path, search);
+        let nav = self.clone();
+        self.guarded(requested.clone(), spawn, move |target| match target {
+            Some(target) => {
+                nav.history.update_untracked(|h| h.commit(index));
+                // 在视图切换前记录离开条目的滚动位置
+                nav.scroll
+                    .update_untracked(|m| m.begin_pop(&WindowScroll, key, saved));
+                if target == requested {
+                    nav.update_location(&path, search);
+                    nav.scroll.with_untracked(|m| m.finish(&WindowScroll, true));
+                } else {
+                    nav.commit_navigation(&target, true);
+                }
+            }
+            // 浏览器已切换到目标条目，撤销这次跳转
+            None => {
+                let delta = nav.history.update_untracked(|h| h.undo(index));
+                if delta != 0 {
+                    go(delta);
+                }
+            }
+        });
+    }
 
-        // 更新信号 (带去重，避免不必要的副作用)
+    /// 更新信号 (带去重，避免不必要的副作用)
+    pub(crate) fn update_location(&self, path: &str, search: String) {
         // 核心修复：Silex 的 WriteSignal.set 默认不检查 Equality，
         // 导致只要调用 set 就会触发 Router 重渲染，Input 失去焦点。
         // 这里我们手动检查相等性。
-        if self.path.get_untracked() != logical_path {
-            self.set_path.set(logical_path.to_string());
+        if self.path.get_untracked() != path {
+            self.set_path.set(path.to_string());
         }
 
         if self.search.get_untracked() != search {
@@ -124,6 +430,14 @@ impl Navigator {
         self.handle_navigation(&to.to_route(), true);
     }
 
+    /// 导航到指定路径，并保留当前的查询参数
+    ///
+    /// 目标路径中显式携带的同名参数优先于当前参数。
+    pub fn push_preserving_query<T: crate::router::ToRoute>(&self, to: T) {
+        let url = merge_query(&to.to_route(), &self.search.get_untracked());
+        self.handle_navigation(&url, false);
+    }
+
     /// 设置或更新查询参数
     ///
     /// * `key`: 参数名
@@ -158,33 +472,81 @@ impl Navigator {
     }
 }
 
+/// 将当前查询字符串合并到目标 URL 中
+///
+/// * `target`: 目标 URL，可包含自身的查询参数与 `#hash`
+/// * `current_search`: 当前的查询字符串（可带或不带前导 `?`）
+///
+/// 目标中已存在的参数保持不变，当前参数中其余的键按原顺序追加在后面。
+pub fn merge_query(target: &str, current_search: &str) -> String {
+    let (without_hash, hash) = match target.find('#') {
+        Some(i) => target.split_at(i),
+        None => (target, ""),
+    };
+    let (path, target_query) = match without_hash.find('?') {
+        Some(i) => (&without_hash[..i], &without_hash[i + 1..]),
+        None => (without_hash, ""),
+    };
+
+    let key_of = |pair: &str| pair.split('=').next().unwrap_or_default().to_string();
+    let mut pairs: Vec<&str> = target_query.split('&').filter(|p| !p.is_empty()).collect();
+    let target_keys: Vec<String> = pairs.iter().map(|p| key_of(p)).collect();
+
+    pairs.extend(
+        current_search
+            .trim_start_matches('?')
+            .split('&')
+            .filter(|p| !p.is_empty() && !target_keys.contains(&key_of(p))),
+    );
+
+    if pairs.is_empty() {
+        format!("{}{}", path, hash)
+    } else {
+        format!("{}?{}{}", path, pairs.join("&"), hash)
+    }
+}
+
 /// 路由上下文所需的属性集合
 #[derive(Clone)]
 pub(crate) struct RouterContextProps {
     pub base_path: String,
+    pub mode: RouterMode,
     pub path: ReadSignal<String>,
     pub search: ReadSignal<String>,
     pub set_path: WriteSignal<String>,
     pub set_search: WriteSignal<String>,
+    pub scroll: StoredValue<ScrollManager>,
+    pub history: StoredValue<HistoryCursor>,
+    pub preloads: StoredValue<PreloadRegistry>,
+    pub guards: Rc<[NavigationGuard]>,
 }
 
 /// 提供路由上下文 (由 Router 组件调用)
-pub(crate) fn provide_router_context(props: RouterContextProps) {
+pub(crate) fn provide_router_context(props: RouterContextProps) -> Navigator {
     let navigator = Navigator {
         base_path: props.base_path.clone(),
+        mode: props.mode,
         path: props.path,
         search: props.search,
         set_path: props.set_path,
         set_search: props.set_search,
+        scroll: props.scroll,
+        guards: props.guards,
+        blockers: StoredValue::new(BlockerRegistry::default()),
+        history: props.history,
+        navigation: Rc::default(),
     };
     let ctx = RouterContext {
         base_path: props.base_path,
+        mode: props.mode,
         path: props.path,
         search: props.search,
-        navigator,
+        navigator: navigator.clone(),
+        preloads: props.preloads,
     };
     // 忽略可能的错误（如重复 provide），Router 应该是根级的
     provide_context(ctx);
+    navigator
 }
 
 /// 获取路由上下文
@@ -237,3 +599,210 @@ pub fn use_query_map() -> silex_core::reactivity::Memo<HashMap<String, String>>
         map
     })
 }
+
+/// Hook: 获取类型化的查询参数
+///
+/// 参数缺失或无法解析为 `T` 时返回 `None`，不会 panic。
+pub fn use_query<T>(key: &str) -> Signal<Option<T>>
+where
+    T: FromStr + Clone + PartialEq + 'static,
+{
+    query_param(use_query_map().into(), key.to_string())
+}
+
+/// Hook: 与查询参数双向绑定的类型化信号
+///
+/// 参数缺失或无法解析时使用 `T::default()`；写入信号时通过 `Display` 序列化并更新 URL
+/// (见 [`Navigator::set_query`])。
+pub fn use_query_signal_typed<T>(key: &str) -> RwSignal<T>
+where
+    T: FromStr + Display + Default + Clone + PartialEq + 'static,
+{
+    let navigator = use_navigate();
+    bind_query_param(
+        use_query_map().into(),
+        key.to_string(),
+        move |key, value| navigator.set_query(key, value),
+    )
+}
+
+fn query_param<T>(query: Signal<HashMap<String, String>>, key: String) -> Signal<Option<T>>
+where
+    T: FromStr + Clone + PartialEq + 'static,
+{
+    Memo::new(move |_| query.with(|map| map.get(&key).and_then(|v| v.parse().ok()))).into()
+}
+
+fn bind_query_param<T, W>(
+    query: Signal<HashMap<String, String>>,
+    key: String,
+    write: W,
+) -> RwSignal<T>
+where
+    T: FromStr + Display + Default + Clone + PartialEq + 'static,
+    W: Fn(&str, Option<&str>) + 'static,
+{
+    let parsed = query_param::<T>(query, key.clone());
+    let signal = RwSignal::new(parsed.get_untracked().unwrap_or_default());
+
+    // URL -> 信号
+    Effect::new(move |_| {
+        let value = parsed.get().unwrap_or_default();
+        if signal.get_untracked() != value {
+            signal.set(value);
+        }
+    });
+
+    // 信号 -> URL：仅在与 URL 中的值不一致时写回，避免初始化时写入默认值
+    Effect::new(move |_| {
+        let value = signal.get();
+        if untrack(|| parsed.get().unwrap_or_default()) != value {
+            write(&key, Some(&value.to_string()));
+        }
+    });
+
+    signal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RouterMode, bind_query_param, merge_query, parse_hash_location, query_param};
+    use crate::router::Routable;
+    use silex_core::reactivity::{Memo, RwSignal, Signal, create_scope};
+    use silex_core::traits::{RxGet, RxWrite};
+    use std::cell::Cell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    #[derive(Clone, PartialEq, Debug)]
+    enum Page {
+        Home,
+        User(u32),
+    }
+
+    impl Routable for Page {
+        fn match_path(path: &str) -> Option<Self> {
+            match path.trim_start_matches('/').split('/').collect::<Vec<_>>()[..] {
+                [""] => Some(Page::Home),
+                ["users", id] => id.parse().ok().map(Page::User),
+                _ => None,
+            }
+        }
+
+        fn to_path(&self) -> String {
+            match self {
+                Page::Home => "/".to_string(),
+                Page::User(id) => format!("/users/{}", id),
+            }
+        }
+    }
+
+    #[test]
+    fn hash_mode_renders_hash_hrefs() {
+        let mode = RouterMode::Hash;
+        assert_eq!(
+            mode.href("/app", "/users/7?tab=posts"),
+            "#/users/7?tab=posts"
+        );
+        assert_eq!(mode.href("/", "#/already"), "#/already");
+        assert_eq!(
+            RouterMode::History.href("/app/", "/users/7"),
+            "/app/users/7"
+        );
+        assert_eq!(RouterMode::History.href("/", "/users/7"), "/users/7");
+    }
+
+    #[test]
+    fn hash_location_round_trips_and_matches_routes() {
+        let href = RouterMode::Hash.href("/", &Page::User(7).to_path());
+        let (path, search) = parse_hash_location(&format!("{}?tab=posts", href));
+        assert_eq!(path, "/users/7");
+        assert_eq!(search, "?tab=posts");
+        assert_eq!(Page::match_path(&path), Some(Page::User(7)));
+
+        for empty in ["", "#", "#/"] {
+            let (path, search) = parse_hash_location(empty);
+            assert_eq!(path, "/");
+            assert_eq!(search, "");
+            assert_eq!(Page::match_path(&path), Some(Page::Home));
+        }
+    }
+
+    #[test]
+    fn merge_query_carries_current_params_over() {
+        assert_eq!(
+            merge_query("/users", "?utm_source=mail&ref=home"),
+            "/users?utm_source=mail&ref=home"
+        );
+    }
+
+    #[test]
+    fn merge_query_prefers_target_params() {
+        assert_eq!(
+            merge_query("/users?page=2#top", "?page=1&utm_source=mail"),
+            "/users?page=2&utm_source=mail#top"
+        );
+    }
+
+    #[test]
+    fn merge_query_without_current_params_keeps_target() {
+        assert_eq!(merge_query("/about", ""), "/about");
+        assert_eq!(merge_query("/about?x=1", "?"), "/about?x=1");
+    }
+
+    #[test]
+    fn typed_query_param_round_trips() {
+        create_scope(|| {
+            let url = RwSignal::new(HashMap::from([("page".to_string(), "2".to_string())]));
+            let query: Signal<HashMap<String, String>> = Memo::new(move |_| url.get()).into();
+
+            let page = bind_query_param::<u32, _>(query, "page".to_string(), move |key, value| {
+                url.update(|map| match value {
+                    Some(v) => {
+                        map.insert(key.to_string(), v.to_string());
+                    }
+                    None => {
+                        map.remove(key);
+                    }
+                })
+            });
+            let page_param = query_param::<u32>(query, "page".to_string());
+            assert_eq!(page.get(), 2);
+
+            // 信号 -> URL
+            page.set(5);
+            assert_eq!(url.get()["page"], "5");
+            assert_eq!(page_param.get(), Some(5));
+
+            // URL -> 信号
+            url.update(|map| {
+                map.insert("page".to_string(), "9".to_string());
+            });
+            assert_eq!(page.get(), 9);
+        });
+    }
+
+    #[test]
+    fn non_numeric_query_value_yields_none() {
+        create_scope(|| {
+            let url = RwSignal::new(HashMap::from([("page".to_string(), "abc".to_string())]));
+            let query: Signal<HashMap<String, String>> = Memo::new(move |_| url.get()).into();
+            let writes = Rc::new(Cell::new(0));
+
+            let page_param = query_param::<u32>(query, "page".to_string());
+            let page = bind_query_param::<u32, _>(query, "page".to_string(), {
+                let writes = writes.clone();
+                move |_, _| writes.set(writes.get() + 1)
+            });
+
+            assert_eq!(page_param.get(), None);
+            assert_eq!(page.get(), 0, "unparsable values fall back to the default");
+            assert_eq!(
+                writes.get(),
+                0,
+                "the default is not written back to the URL"
+            );
+            assert_eq!(query_param::<u32>(query, "missing".to_string()).get(), None);
+        });
+    }
+}
@@ -0,0 +1,241 @@
+use crate::router::loader::match_pattern;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// 导航守卫的判定结果
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NavigationDecision {
+    /// 允许导航
+    Allow,
+    /// 改为导航到指定路径
+    Redirect(String),
+    /// 阻止导航：保留当前路由与地址栏
+    Block,
+}
+
+/// 一次导航请求 (均为不含 base_path 的逻辑 URL)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Navigation {
+    /// 当前 URL
+    pub from: String,
+    /// 目标 URL (可带查询参数)
+    pub to: String,
+}
+
+pub type GuardFuture = Pin<Box<dyn Future<Output = NavigationDecision>>>;
+
+/// 导航守卫：全局守卫对所有导航生效，路由守卫仅在目标路径匹配 `pattern` 时生效
+#[derive(Clone)]
+pub(crate) struct NavigationGuard {
+    pattern: Option<String>,
+    check: Rc<dyn Fn(&Navigation) -> GuardFuture>,
+}
+
+impl NavigationGuard {
+    pub(crate) fn new<F, Fut>(pattern: Option<String>, check: F) -> Self
+    where
+        F: Fn(&Navigation) -> Fut + 'static,
+        Fut: Future<Output = NavigationDecision> + 'static,
+    {
+        Self {
+            pattern,
+            check: Rc::new(move |nav| Box::pin(check(nav))),
+        }
+    }
+
+    fn applies_to(&self, url: &str) -> bool {
+        match &self.pattern {
+            Some(pattern) => {
+                let path = url.split(['?', '#']).next().unwrap_or_default();
+                match_pattern(pattern, path).is_some()
+            }
+            None => true,
+        }
+    }
+}
+
+/// 重定向次数上限，避免守卫之间互相重定向导致死循环
+const MAX_REDIRECTS: usize = 8;
+
+/// 依次执行守卫，返回最终允许导航到的 URL；被阻止时返回 `None`
+///
+/// 任一守卫返回 `Redirect` 时，以新的目标重新执行全部守卫。
+pub(crate) async fn resolve_navigation(
+    guards: Rc<[NavigationGuard]>,
+    from: String,
+    mut to: String,
+) -> Option<String> {
+    'redirect: for _ in 0..=MAX_REDIRECTS {
+        let nav = Navigation {
+            from: from.clone(),
+            to: to.clone(),
+        };
+        for guard in guards.iter().filter(|g| g.applies_to(&to)) {
+            match (guard.check)(&nav).await {
+                NavigationDecision::Allow => {}
+                NavigationDecision::Redirect(target) => {
+                    to = target;
+                    continue 'redirect;
+                }
+                NavigationDecision::Block => return None,
+            }
+        }
+        return Some(to);
+    }
+
+    silex_core::log::console_error(format!(
+        "Navigation to '{}' exceeded {} redirects",
+        to, MAX_REDIRECTS
+    ));
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::router::Navigator;
+    use crate::router::loader::LocalFuture;
+    use silex_core::reactivity::create_scope;
+    use silex_core::traits::RxGet;
+    use std::cell::RefCell;
+    use std::task::{Context, Poll, Waker};
+
+    /// 模拟 `push`：守卫判定后写入地址栏与路由状态
+    fn push(nav: &Navigator, url: &str, address_bar: &Rc<RefCell<Vec<String>>>) {
+        let this = nav.clone();
+        let address_bar = address_bar.clone();
+        nav.guarded(
+            url.to_string(),
+            |mut fut: LocalFuture| {
+                let mut cx = Context::from_waker(Waker::noop());
+                assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(()));
+            },
+            move |target| {
+                if let Some(target) = target {
+                    address_bar.borrow_mut().push(target.clone());
+                    this.update_location(&target, String::new());
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn redirect_lands_on_login_route() {
+        create_scope(|| {
+            let logged_in = Rc::new(std::cell::Cell::new(false));
+            let nav = Navigator::for_test(vec![NavigationGuard::new(Some("/admin/*".into()), {
+                let logged_in = logged_in.clone();
+                move |_: &Navigation| {
+                    let logged_in = logged_in.get();
+                    async move {
+                        if logged_in {
+                            NavigationDecision::Allow
+                        } else {
+                            NavigationDecision::Redirect("/login".into())
+                        }
+                    }
+                }
+            })]);
+            let address_bar = Rc::new(RefCell::new(vec!["/".to_string()]));
+
+            push(&nav, "/admin/users", &address_bar);
+            assert_eq!(nav.path.get(), "/login");
+            assert_eq!(*address_bar.borrow(), vec!["/", "/login"]);
+
+            logged_in.set(true);
+            push(&nav, "/admin/users", &address_bar);
+            assert_eq!(nav.path.get(), "/admin/users");
+        });
+    }
+
+    #[test]
+    fn block_keeps_current_route_and_address() {
+        create_scope(|| {
+            let seen = Rc::new(RefCell::new(Vec::new()));
+            let nav = Navigator::for_test(vec![NavigationGuard::new(None, {
+                let seen = seen.clone();
+                move |nav: &Navigation| {
+                    seen.borrow_mut().push(nav.clone());
+                    let decision = if nav.to == "/settings" {
+                        NavigationDecision::Block
+                    } else {
+                        NavigationDecision::Allow
+                    };
+                    std::future::ready(decision)
+                }
+            })]);
+            let address_bar = Rc::new(RefCell::new(vec!["/".to_string()]));
+
+            push(&nav, "/about", &address_bar);
+            push(&nav, "/settings", &address_bar);
+            assert_eq!(nav.path.get(), "/about", "prior route stays visible");
+            assert_eq!(*address_bar.borrow(), vec!["/", "/about"]);
+            assert_eq!(
+                seen.borrow().last(),
+                Some(&Navigation {
+                    from: "/about".into(),
+                    to: "/settings".into(),
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn stale_guard_result_does_not_override_newer_navigation() {
+        create_scope(|| {
+            // 各目标的判定在放行之前一直处于 pending
+            let released = Rc::new(RefCell::new(Vec::<String>::new()));
+            let nav = Navigator::for_test(vec![NavigationGuard::new(None, {
+                let released = released.clone();
+                move |nav: &Navigation| {
+                    let released = released.clone();
+                    let to = nav.to.clone();
+                    std::future::poll_fn(move |_| {
+                        if released.borrow().contains(&to) {
+                            Poll::Ready(NavigationDecision::Allow)
+                        } else {
+                            Poll::Pending
+                        }
+                    })
+                }
+            })]);
+            let tasks = Rc::new(RefCell::new(Vec::<LocalFuture>::new()));
+            let address_bar = Rc::new(RefCell::new(vec!["/".to_string()]));
+
+            for url in ["/slow", "/fast"] {
+                let this = nav.clone();
+                let tasks = tasks.clone();
+                let address_bar = address_bar.clone();
+                nav.guarded(
+                    url.to_string(),
+                    move |fut| tasks.borrow_mut().push(fut),
+                    move |target| {
+                        if let Some(target) = target {
+                            address_bar.borrow_mut().push(target.clone());
+                            this.update_location(&target, String::new());
+                        }
+                    },
+                );
+            }
+            let poll_all = || {
+                let mut cx = Context::from_waker(Waker::noop());
+                tasks
+                    .borrow_mut()
+                    .retain_mut(|fut| fut.as_mut().poll(&mut cx).is_pending());
+            };
+
+            poll_all();
+            released.borrow_mut().push("/fast".into());
+            poll_all();
+            assert_eq!(nav.path.get(), "/fast");
+
+            // 先发起的导航后完成：结果已过期，不再覆盖
+            released.borrow_mut().push("/slow".into());
+            poll_all();
+            assert!(tasks.borrow().is_empty());
+            assert_eq!(nav.path.get(), "/fast");
+            assert_eq!(*address_bar.borrow(), vec!["/", "/fast"]);
+        });
+    }
+}
@@ -1,5 +1,7 @@
-use crate::router::context::use_router;
+use crate::router::context::{merge_query, use_router};
+use crate::router::preload::PreloadStrategy;
 
+use silex_core::reactivity::Effect;
 use silex_core::traits::RxGet;
 use silex_dom::attribute::{AttributeBuilder, GlobalAttributes};
 use silex_dom::element::TypedElement;
@@ -11,6 +13,8 @@ use silex_html::a;
 #[derive(Clone)]
 pub struct Link {
     href: String,
+    preserve_query: bool,
+    preload: PreloadStrategy,
     inner: TypedElement<TagA>,
 }
 
@@ -26,6 +30,8 @@ pub fn Link<T: ToRoute, V: MountRef + 'static>(to: T, child: V) -> Link {
     let element = a(child).attr("href", &href);
     Link {
         href,
+        preserve_query: false,
+        preload: PreloadStrategy::None,
         inner: element,
     }
 }
@@ -47,6 +53,23 @@ impl Link {
         }
     }
 
+    /// 导航时保留当前的查询参数 (目标中显式指定的同名参数优先)
+    pub fn preserve_query(self) -> Self {
+        Self {
+            preserve_query: true,
+            ..self
+        }
+    }
+
+    /// 设置预加载策略：如 [`PreloadStrategy::Hover`] 在鼠标移入或获得焦点时
+    /// 提前执行目标路由的加载器与预加载回调 (见 [`Router::preload`](crate::router::Router::preload))
+    pub fn preload(self, strategy: PreloadStrategy) -> Self {
+        Self {
+            preload: strategy,
+            ..self
+        }
+    }
+
     /// 设置激活时的 CSS 类 (当当前路径匹配 href 时添加)
     pub fn active_class(self, name: impl Into<String>) -> Self {
         // 尝试获取 Router 上下文中的 path 信号
@@ -88,6 +111,8 @@ impl ApplyAttributes for Link {}
 impl Mount for Link {
     fn mount(self, parent: &web_sys::Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
         let href = self.href.clone();
+        let preserve_query = self.preserve_query;
+        let preload = self.preload;
 
         // 尝试获取 Router，用于：
         // 1. base_path处理
@@ -96,26 +121,55 @@ impl Mount for Link {
 
         // 在绑定事件前，根据 Router 的 base_path 更新 DOM 元素的 href 属性
         // 这样可以保证原生行为（如右键打开新标签页）指向正确的物理路径
-        if let Some(ctx) = &router_ctx
-            && !ctx.base_path.is_empty()
-            && ctx.base_path != "/"
-            && href.starts_with('/')
-        {
-            let base = ctx.base_path.trim_end_matches('/');
-            let full_href = format!("{}{}", base, href);
-            let _ = self.inner.dom_element.set_attribute("href", &full_href);
+        // Hash 模式下则渲染为 `#/path`
+        if let Some(ctx) = &router_ctx {
+            let mode = ctx.mode;
+            let base = ctx.base_path.clone();
+
+            if preserve_query {
+                // 查询参数变化时同步更新 href，保证新标签页打开时同样携带参数
+                let search = ctx.search;
+                let href = href.clone();
+                let dom_element = self.inner.dom_element.clone();
+                Effect::new(move |_| {
+                    let target = merge_query(&href, &search.get());
+                    let _ = dom_element.set_attribute("href", &mode.href(&base, &target));
+                });
+            } else {
+                let full_href = mode.href(&base, &href);
+                if full_href != href {
+                    let _ = self.inner.dom_element.set_attribute("href", &full_href);
+                }
+            }
+        }
+
+        // 预加载：鼠标移入或获得焦点时触发，同一路径只预加载一次
+        let mut inner = self.inner;
+        if let (PreloadStrategy::Hover, Some(ctx)) = (preload, &router_ctx) {
+            let warm = {
+                let ctx = ctx.clone();
+                let href = href.clone();
+                move || ctx.preload(&href)
+            };
+            inner = inner
+                .on(silex_dom::event::mouseenter, warm.clone())
+                .on(silex_dom::event::focus, warm);
         }
 
         // 绑定点击事件
         // 我们必须将 router_ctx 移动 to 闭包中，因为在事件回调运行时，可能无法通过 use_context 获取到（Scope 问题）
-        let element = self.inner.on_click(move |e: web_sys::MouseEvent| {
+        let element = inner.on_click(move |e: web_sys::MouseEvent| {
             // 阻止默认跳转行为
             e.prevent_default();
 
             // 使用 router 导航
             if let Some(ctx) = &router_ctx {
                 // 注意：这里仍然传递逻辑路径 (href)，Navigator 会自动处理 base_path
-                ctx.navigator.push(href.as_str());
+                if preserve_query {
+                    ctx.navigator.push_preserving_query(href.as_str());
+                } else {
+                    ctx.navigator.push(href.as_str());
+                }
             } else {
                 // 如果没有 router，尝试再次获取（以防万一），或者回退
                 // 注意：通常在事件处理程序中 use_router 会失败，所以主要依赖捕获的 ctx
@@ -0,0 +1,411 @@
+use silex_core::SilexError;
+use silex_core::reactivity::{
+    Effect, Memo, ReadSignal, ResourceState, Signal, on_cleanup, provide_context, use_context,
+    use_suspense_context,
+};
+use silex_core::traits::{RxCloneData, RxError, RxGet, RxRead, RxWrite};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// 路由参数 (路径模式中的 `:name` 段)
+pub type Params = HashMap<String, String>;
+
+/// 按路径模式匹配逻辑路径，成功时返回提取出的参数
+///
+/// 模式语法与 `#[route]` 一致：`:name` 匹配单个路径段，末尾的 `*` 匹配剩余路径，
+/// `*name` 额外将剩余路径 (保留 `/`，URL 解码后) 记录为参数 `name`。
+/// 末尾的 `:name?` 为可选段，路径中缺少该段时参数不存在；
+/// 可选段之后只能是可选段，否则模式无效，不匹配任何路径。
+pub fn match_pattern(pattern: &str, path: &str) -> Option<Params> {
+    let mut params = Params::new();
+    let mut segments = path.trim_matches('/').split('/').filter(|s| !s.is_empty());
+    let mut optional_seen = false;
+
+    for part in pattern
+        .trim_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+    {
+        if let Some(name) = part.strip_prefix(':').and_then(|p| p.strip_suffix('?')) {
+            optional_seen = true;
+            if let Some(segment) = segments.next() {
+                params.insert(name.to_string(), segment.to_string());
+            }
+            continue;
+        }
+        if optional_seen {
+            return None;
+        }
+        if let Some(name) = part.strip_prefix('*') {
+            if !name.is_empty() {
+                let rest = segments.collect::<Vec<_>>().join("/");
+                params.insert(name.to_string(), decode_path(&rest));
+            }
+            return Some(params);
+        }
+        let segment = segments.next()?;
+        if let Some(name) = part.strip_prefix(':') {
+            params.insert(name.to_string(), segment.to_string());
+        } else if part != segment {
+            return None;
+        }
+    }
+
+    segments.next().is_none().then_some(params)
+}
+
+/// 对路径进行 URL 解码 (`%XX`)，无效的转义序列原样保留
+pub fn decode_path(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// 路由加载器的数据，通过 Context 提供给路由视图
+pub struct RouteData<T, E = SilexError> {
+    pub state: ReadSignal<ResourceState<T, E>>,
+}
+
+impl<T, E> Clone for RouteData<T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T, E> Copy for RouteData<T, E> {}
+
+impl<T: RxCloneData, E: RxError> RouteData<T, E> {
+    /// 最近一次成功加载的数据 (加载新参数期间仍保留旧数据)
+    pub fn get(&self) -> Option<T> {
+        self.state.with(|s| s.as_option().cloned())
+    }
+
+    /// 是否正在加载
+    pub fn loading(&self) -> bool {
+        self.state.with(|s| s.is_loading())
+    }
+
+    /// 最近一次加载的错误
+    pub fn error(&self) -> Option<E> {
+        self.state.with(|s| match s {
+            ResourceState::Error(e) => Some(e.clone()),
+            _ => None,
+        })
+    }
+}
+
+/// Hook: 获取路由加载器 (见 [`Router::loader`](crate::router::Router::loader)) 提供的数据
+pub fn use_route_data<T: RxCloneData, E: RxError>() -> Option<RouteData<T, E>> {
+    use_context::<RouteData<T, E>>()
+}
+
+pub(crate) type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// 预加载结果缓存，以排序后的参数为 key；导航到对应参数时取出并直接使用
+pub(crate) type PreloadCache<T, E> = Rc<RefCell<HashMap<Vec<(String, String)>, Result<T, E>>>>;
+
+fn params_key(params: &Params) -> Vec<(String, String)> {
+    let mut key: Vec<_> = params.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+    key.sort();
+    key
+}
+
+/// 构造加载器的预加载回调：提前执行 `loader` 并将结果写入 `cache`
+pub(crate) fn preload_loader<T, E, F, Fut, S>(
+    cache: PreloadCache<T, E>,
+    loader: F,
+    spawn: S,
+) -> impl Fn(&Params) + 'static
+where
+    T: 'static,
+    E: 'static,
+    F: Fn(Params) -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    S: Fn(LocalFuture) + 'static,
+{
+    move |params: &Params| {
+        let key = params_key(params);
+        let fut = loader(params.clone());
+        let cache = cache.clone();
+        spawn(Box::pin(async move {
+            let res = fut.await;
+            cache.borrow_mut().insert(key, res);
+        }));
+    }
+}
+
+/// 安装路由加载器并提供 [`RouteData`]
+///
+/// 路径匹配 `pattern` 且参数变化时重新执行 `loader`；过期请求的结果会被丢弃。
+/// 加载期间递增当前 `SuspenseContext` 的计数，以驱动路由级 `SuspenseBoundary`。
+/// 若 `cache` 中已有预加载完成的结果，则直接使用而不再执行 `loader`。
+pub(crate) fn install_loader<T, E, F, Fut, S>(
+    pattern: String,
+    path: Signal<String>,
+    cache: PreloadCache<T, E>,
+    loader: F,
+    spawn: S,
+) -> RouteData<T, E>
+where
+    T: RxCloneData,
+    E: RxError,
+    F: Fn(Params) -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    S: Fn(LocalFuture) + 'static,
+{
+    let params = Memo::new(move |_| match_pattern(&pattern, &path.get()));
+    let (state, set_state) = Signal::<ResourceState<T, E>>::pair(ResourceState::Idle);
+
+    let alive = Rc::new(Cell::new(true));
+    let alive_clone = alive.clone();
+    on_cleanup(move || alive_clone.set(false));
+
+    let request_id = Rc::new(Cell::new(0usize));
+    let suspense_ctx = use_suspense_context();
+
+    Effect::new(move |_| {
+        let current_id = request_id.get().wrapping_add(1);
+        request_id.set(current_id);
+
+        // 离开匹配的路径：作废进行中的请求，避免其结果在导航离开后写入
+        let Some(params) = params.get() else {
+            set_state.set(ResourceState::Idle);
+            return;
+        };
+
+        if let Some(res) = cache.borrow_mut().remove(&params_key(&params)) {
+            set_state.set(match res {
+                Ok(val) => ResourceState::Ready(val),
+                Err(e) => ResourceState::Error(e),
+            });
+            return;
+        }
+
+        if let Some(ctx) = &suspense_ctx {
+            ctx.increment();
+        }
+
+        set_state.update(|s| {
+            *s = match &*s {
+                ResourceState::Ready(data) | ResourceState::Reloading(data) => {
+                    ResourceState::Reloading(data.clone())
+                }
+                _ => ResourceState::Loading,
+            };
+        });
+
+        let fut = loader(params);
+        let alive = alive.clone();
+        let request_id = request_id.clone();
+        let suspense_ctx = suspense_ctx;
+
+        spawn(Box::pin(async move {
+            let res = fut.await;
+
+            if alive.get() && request_id.get() == current_id {
+                set_state.set(match res {
+                    Ok(val) => ResourceState::Ready(val),
+                    Err(e) => ResourceState::Error(e),
+                });
+            }
+
+            if let Some(ctx) = &suspense_ctx {
+                ctx.decrement();
+            }
+        }));
+    });
+
+    let data = RouteData { state };
+    provide_context(data);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{RwSignal, SuspenseContext, create_scope};
+    use std::task::{Context, Poll, Waker};
+
+    #[test]
+    fn match_pattern_extracts_params() {
+        let params = match_pattern("/users/:id", "/users/42/").unwrap();
+        assert_eq!(params["id"], "42");
+        assert!(match_pattern("/users/:id", "/users").is_none());
+        assert!(match_pattern("/users/:id", "/users/1/posts").is_none());
+        assert!(match_pattern("/users/*", "/users/1/posts").is_some());
+        assert!(match_pattern("/", "/").is_some());
+    }
+
+    #[test]
+    fn named_wildcard_captures_decoded_rest() {
+        let params = match_pattern("/files/*path", "/files/a/b/c.txt").unwrap();
+        assert_eq!(params["path"], "a/b/c.txt");
+
+        let params =
+            match_pattern("/files/*path", "/files/my%20docs/r%C3%A9sum%C3%A9.pdf").unwrap();
+        assert_eq!(params["path"], "my docs/résumé.pdf");
+
+        let params = match_pattern("/files/*path", "/files").unwrap();
+        assert_eq!(params["path"], "");
+        assert!(match_pattern("/files/*path", "/images/a.png").is_none());
+        assert_eq!(decode_path("100%"), "100%");
+    }
+
+    #[test]
+    fn optional_trailing_segment() {
+        let params = match_pattern("/posts/:id/:slug?", "/posts/5").unwrap();
+        assert_eq!(params["id"], "5");
+        assert!(!params.contains_key("slug"));
+
+        let params = match_pattern("/posts/:id/:slug?", "/posts/5/hello").unwrap();
+        assert_eq!(params["slug"], "hello");
+        assert!(match_pattern("/posts/:id/:slug?", "/posts/5/hello/x").is_none());
+        assert!(match_pattern("/posts/:id/:slug?", "/posts").is_none());
+
+        // 可选段位于中间时模式无效
+        assert!(match_pattern("/posts/:id?/edit", "/posts/5/edit").is_none());
+    }
+
+    #[test]
+    fn loader_reruns_on_param_change_and_ignores_stale_results() {
+        create_scope(|| {
+            let suspense = SuspenseContext::new();
+            provide_context(suspense);
+
+            let url = RwSignal::new("/users/1".to_string());
+            let path: Signal<String> = Memo::new(move |_| url.get()).into();
+            let calls = Rc::new(RefCell::new(Vec::new()));
+            let queue = Rc::new(RefCell::new(Vec::<LocalFuture>::new()));
+
+            let data = install_loader(
+                "/users/:id".to_string(),
+                path,
+                PreloadCache::default(),
+                {
+                    let calls = calls.clone();
+                    move |params: Params| {
+                        let id = params["id"].clone();
+                        calls.borrow_mut().push(id.clone());
+                        async move { Ok::<_, String>(format!("user {}", id)) }
+                    }
+                },
+                {
+                    let queue = queue.clone();
+                    move |fut| queue.borrow_mut().push(fut)
+                },
+            );
+            assert!(use_route_data::<String, String>().is_some());
+            assert!(data.loading());
+
+            url.set("/users/2".to_string());
+            // 参数未变化时不会重新加载
+            url.set("/users/2/".to_string());
+            assert_eq!(*calls.borrow(), vec!["1", "2"]);
+            assert_eq!(suspense.count.get(), 2);
+
+            // 新请求先完成，旧请求延后完成
+            let mut cx = Context::from_waker(Waker::noop());
+            let mut pending = std::mem::take(&mut *queue.borrow_mut());
+            while let Some(mut fut) = pending.pop() {
+                assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(()));
+            }
+
+            assert_eq!(data.get(), Some("user 2".to_string()));
+            assert!(!data.loading());
+            assert_eq!(suspense.count.get(), 0);
+        });
+    }
+
+    #[test]
+    fn leaving_the_route_discards_in_flight_load() {
+        create_scope(|| {
+            let url = RwSignal::new("/users/1".to_string());
+            let path: Signal<String> = Memo::new(move |_| url.get()).into();
+            let queue = Rc::new(RefCell::new(Vec::<LocalFuture>::new()));
+
+            let data = install_loader(
+                "/users/:id".to_string(),
+                path,
+                PreloadCache::default(),
+                |params: Params| {
+                    let id = params["id"].clone();
+                    async move { Ok::<_, String>(format!("user {}", id)) }
+                },
+                {
+                    let queue = queue.clone();
+                    move |fut| queue.borrow_mut().push(fut)
+                },
+            );
+            assert!(data.loading());
+
+            // 请求完成前导航到不匹配的路径
+            url.set("/about".to_string());
+            assert!(!data.loading());
+
+            let mut cx = Context::from_waker(Waker::noop());
+            for mut fut in std::mem::take(&mut *queue.borrow_mut()) {
+                assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(()));
+            }
+            assert_eq!(data.get(), None, "stale result is not written");
+        });
+    }
+
+    #[test]
+    fn preloaded_result_is_used_without_refetching() {
+        create_scope(|| {
+            let url = RwSignal::new("/".to_string());
+            let path: Signal<String> = Memo::new(move |_| url.get()).into();
+            let calls = Rc::new(Cell::new(0));
+            let cache = PreloadCache::default();
+            let loader = {
+                let calls = calls.clone();
+                move |params: Params| {
+                    calls.set(calls.get() + 1);
+                    let id = params["id"].clone();
+                    async move { Ok::<_, String>(format!("user {}", id)) }
+                }
+            };
+            // 测试中加载器的 Future 均立即完成
+            let spawn = |mut fut: LocalFuture| {
+                let mut cx = Context::from_waker(Waker::noop());
+                assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(()));
+            };
+
+            let data = install_loader(
+                "/users/:id".to_string(),
+                path,
+                cache.clone(),
+                loader.clone(),
+                spawn,
+            );
+            let preload = preload_loader(cache.clone(), loader, spawn);
+
+            preload(&match_pattern("/users/:id", "/users/7").unwrap());
+            assert_eq!(calls.get(), 1);
+
+            url.set("/users/7".to_string());
+            assert_eq!(calls.get(), 1, "navigation reuses the preloaded result");
+            assert_eq!(data.get(), Some("user 7".to_string()));
+            assert!(cache.borrow().is_empty());
+        });
+    }
+}
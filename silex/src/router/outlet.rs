@@ -0,0 +1,141 @@
+use crate::router::RouteView;
+use silex_core::reactivity::{NodeId, create_scope, provide_context, use_context};
+use silex_dom::view::{ApplyAttributes, Mount, MountRef};
+use std::rc::Rc;
+
+/// 仅对 [`Outlet`] 渲染的子路由可见的上下文值，避免与同类型的全局 Context 冲突
+#[derive(Clone)]
+struct OutletContext<T>(T);
+
+type OutletProvider = Rc<dyn Fn()>;
+
+/// 嵌套路由的出口：在布局组件中渲染匹配到的子路由
+///
+/// 通过 [`Outlet::context`] 传入的值只在子路由的作用域内可见 (`use_outlet_context`)，
+/// 路由切换时随子路由的作用域一并销毁，切换到未提供该值的兄弟路由时读取为 `None`。
+///
+/// ```rust,ignore
+/// #[component]
+/// fn UsersLayout(route: UsersRoute) -> impl Mount + MountRef {
+///     let team = load_team();
+///     div!(h2("Users"), Outlet(route).context(team))
+/// }
+///
+/// #[component]
+/// fn UserList() -> impl Mount + MountRef {
+///     let team = use_outlet_context::<Team>();
+///     // ...
+/// }
+/// ```
+pub struct Outlet<R> {
+    route: R,
+    providers: Vec<OutletProvider>,
+}
+
+#[allow(non_snake_case)]
+pub fn Outlet<R: RouteView>(route: R) -> Outlet<R> {
+    Outlet {
+        route,
+        providers: Vec::new(),
+    }
+}
+
+impl<R: RouteView> Outlet<R> {
+    /// 为子路由提供一个上下文值，可多次调用以提供不同类型的值
+    pub fn context<T: Clone + 'static>(mut self, value: T) -> Self {
+        self.providers.push(Rc::new(move || {
+            provide_context(OutletContext(value.clone()))
+        }));
+        self
+    }
+
+    /// 在新的子作用域中提供出口上下文后执行 `f`，作用域随当前 (布局) 作用域销毁
+    fn scoped(&self, f: impl FnOnce()) -> NodeId {
+        create_scope(|| {
+            for provide in &self.providers {
+                provide();
+            }
+            f();
+        })
+    }
+}
+
+/// 读取最近的 [`Outlet`] 为当前子路由提供的上下文值
+pub fn use_outlet_context<T: Clone + 'static>() -> Option<T> {
+    use_context::<OutletContext<T>>().map(|ctx| ctx.0)
+}
+
+impl<R> ApplyAttributes for Outlet<R> {}
+
+impl<R: RouteView> Mount for Outlet<R> {
+    fn mount(self, parent: &web_sys::Node, attrs: Vec<silex_dom::attribute::PendingAttribute>) {
+        self.mount_ref(parent, attrs);
+    }
+}
+
+impl<R: RouteView> MountRef for Outlet<R> {
+    fn mount_ref(
+        &self,
+        parent: &web_sys::Node,
+        attrs: Vec<silex_dom::attribute::PendingAttribute>,
+    ) {
+        self.scoped(|| self.route.render().mount(parent, attrs));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::router::Routable;
+    use silex_core::reactivity::dispose;
+    use silex_dom::view::AnyView;
+    use std::cell::RefCell;
+
+    #[derive(Clone, PartialEq)]
+    enum Tab {
+        Members,
+        Settings,
+    }
+
+    impl Routable for Tab {
+        fn match_path(_path: &str) -> Option<Self> {
+            None
+        }
+
+        fn to_path(&self) -> String {
+            String::new()
+        }
+    }
+
+    impl RouteView for Tab {
+        fn render(&self) -> AnyView {
+            AnyView::new(())
+        }
+    }
+
+    #[test]
+    fn context_is_scoped_to_the_rendered_child() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let child = {
+            let seen = seen.clone();
+            move || seen.borrow_mut().push(use_outlet_context::<&str>())
+        };
+
+        // 每次路由切换都会重新渲染布局，旧的布局作用域随之销毁
+        let layout = create_scope(|| {
+            Outlet(Tab::Members).context("team-a").scoped(&child);
+            // 布局自身不可见
+            seen.borrow_mut().push(use_outlet_context::<&str>());
+        });
+        dispose(layout);
+
+        create_scope(|| {
+            Outlet(Tab::Members).context("team-b").scoped(&child);
+        });
+        create_scope(|| {
+            Outlet(Tab::Settings).scoped(&child);
+        });
+
+        assert_eq!(*seen.borrow(), [Some("team-a"), None, Some("team-b"), None]);
+    }
+}
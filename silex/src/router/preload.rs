@@ -0,0 +1,76 @@
+use crate::router::loader::{Params, match_pattern};
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// 链接预加载策略
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PreloadStrategy {
+    /// 不预加载
+    #[default]
+    None,
+    /// 鼠标移入 (`mouseenter`) 或获得焦点 (`focus`) 时预加载目标路由
+    Hover,
+}
+
+/// 预加载回调，参数为目标路径按模式提取出的参数
+pub type PreloadHook = Rc<dyn Fn(&Params)>;
+
+/// 预加载注册表：按路径模式登记回调，同一路径只预加载一次
+#[derive(Default)]
+pub struct PreloadRegistry {
+    hooks: Vec<(String, PreloadHook)>,
+    visited: HashSet<String>,
+}
+
+impl PreloadRegistry {
+    /// 登记路径匹配 `pattern` (e.g. `/users/:id`) 时执行的预加载回调
+    pub fn register(&mut self, pattern: impl Into<String>, hook: PreloadHook) {
+        self.hooks.push((pattern.into(), hook));
+    }
+
+    /// 标记 `url` 已预加载，返回需要执行的回调及其参数
+    ///
+    /// 查询参数与 hash 不参与匹配；该路径此前已预加载过时返回空列表。
+    /// 回调由调用方在释放注册表之后执行，以便回调中再次访问路由上下文。
+    pub fn preload(&mut self, url: &str) -> Vec<(PreloadHook, Params)> {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        if !self.visited.insert(path.to_string()) {
+            return Vec::new();
+        }
+        self.hooks
+            .iter()
+            .filter_map(|(pattern, hook)| match_pattern(pattern, path).map(|p| (hook.clone(), p)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn hover(registry: &mut PreloadRegistry, url: &str) {
+        for (hook, params) in registry.preload(url) {
+            hook(&params);
+        }
+    }
+
+    #[test]
+    fn repeated_hovers_preload_once_per_path() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut registry = PreloadRegistry::default();
+        registry.register("/users/:id", {
+            let calls = calls.clone();
+            Rc::new(move |params: &Params| calls.borrow_mut().push(params["id"].clone()))
+        });
+
+        hover(&mut registry, "/users/1");
+        hover(&mut registry, "/users/1");
+        hover(&mut registry, "/users/1?tab=posts");
+        assert_eq!(*calls.borrow(), vec!["1"]);
+
+        hover(&mut registry, "/users/2");
+        hover(&mut registry, "/about");
+        assert_eq!(*calls.borrow(), vec!["1", "2"]);
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use wasm_bindgen::JsValue;
+
+/// 路由切换时的滚动行为
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollBehavior {
+    /// 导航到新页面 (push) 时滚动到顶部
+    Top,
+    /// 导航到新页面时滚动到顶部；前进/后退时恢复该历史条目离开时的滚动位置
+    Restore,
+    /// 不做任何处理，保留当前滚动位置
+    #[default]
+    Preserve,
+}
+
+/// 滚动位置
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollPosition {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl ScrollPosition {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+/// 滚动宿主：抽象滚动位置的读写，浏览器中即为 `window`
+pub trait ScrollHost {
+    /// 当前滚动位置
+    fn offset(&self) -> ScrollPosition;
+    /// 滚动到指定位置
+    fn scroll_to(&self, position: ScrollPosition);
+}
+
+/// 浏览器窗口的滚动
+pub struct WindowScroll;
+
+impl ScrollHost for WindowScroll {
+    fn offset(&self) -> ScrollPosition {
+        let window = web_sys::window().unwrap();
+        ScrollPosition::new(
+            window.scroll_x().unwrap_or_default(),
+            window.scroll_y().unwrap_or_default(),
+        )
+    }
+
+    fn scroll_to(&self, position: ScrollPosition) {
+        let window = web_sys::window().unwrap();
+        window.scroll_to_with_x_and_y(position.x, position.y);
+    }
+}
+
+/// 滚动管理器：按历史条目记录滚动位置
+///
+/// `Restore` 模式下每个历史条目的 `history.state` 中保存一个唯一的 key 与滚动位置 (`x`, `y`)：
+/// push 离开条目前以 `replaceState` 写入其位置，`popstate` 时优先读取新条目 state 中的位置，
+/// 因此刷新页面或 bfcache 失效后仍能恢复。内存中按 key 的记录仅作为后备
+/// (前进/后退离开的条目在 popstate 时已不是当前条目，无法再写入其 state)。
+pub struct ScrollManager {
+    behavior: ScrollBehavior,
+    positions: HashMap<u64, ScrollPosition>,
+    current: u64,
+    next: u64,
+}
+
+impl ScrollManager {
+    /// * `current`: 当前历史条目的 key
+    pub fn new(behavior: ScrollBehavior, current: u64) -> Self {
+        Self {
+            behavior,
+            positions: HashMap::new(),
+            current,
+            next: current,
+        }
+    }
+
+    pub fn behavior(&self) -> ScrollBehavior {
+        self.behavior
+    }
+
+    /// 当前历史条目的 key
+    pub fn current_key(&self) -> u64 {
+        self.current
+    }
+
+    /// 即将 push 新的历史条目：记录当前条目的滚动位置，返回新条目的 key
+    pub fn begin_push(&mut self, host: &impl ScrollHost) -> u64 {
+        self.positions.insert(self.current, host.offset());
+        self.next += 1;
+        self.current = self.next;
+        self.current
+    }
+
+    /// 历史条目已切换 (前进/后退)：记录离开条目的滚动位置并切换到 `key`
+    ///
+    /// 须在新视图渲染之前调用，此时页面仍停留在离开条目的滚动位置。
+    /// * `saved`: 新条目 `history.state` 中记录的滚动位置，优先于内存中的记录
+    pub fn begin_pop(
+        &mut self,
+        host: &impl ScrollHost,
+        key: Option<u64>,
+        saved: Option<ScrollPosition>,
+    ) {
+        self.positions.insert(self.current, host.offset());
+        if let Some(key) = key {
+            self.current = key;
+            self.next = self.next.max(key);
+        }
+        if let Some(saved) = saved {
+            self.remember(saved);
+        }
+    }
+
+    /// 记录当前历史条目的滚动位置 (如页面加载时从 `history.state` 读取的位置)
+    pub fn remember(&mut self, position: ScrollPosition) {
+        self.positions.insert(self.current, position);
+    }
+
+    /// 新视图渲染完成后，按滚动行为调整滚动位置
+    ///
+    /// * `popped`: 是否由前进/后退触发
+    pub fn finish(&self, host: &impl ScrollHost, popped: bool) {
+        match (self.behavior, popped) {
+            (ScrollBehavior::Preserve, _) | (ScrollBehavior::Top, true) => {}
+            (ScrollBehavior::Top, false) | (ScrollBehavior::Restore, false) => {
+                host.scroll_to(ScrollPosition::default());
+            }
+            (ScrollBehavior::Restore, true) => {
+                let position = self
+                    .positions
+                    .get(&self.current)
+                    .copied()
+                    .unwrap_or_default();
+                host.scroll_to(position);
+            }
+        }
+    }
+}
+
+/// 从 `history.state` 中读取历史条目的 key
+pub(crate) fn history_key(state: &JsValue) -> Option<u64> {
+    state_field(state, "key")
+}
+
+/// 从 `history.state` 中读取历史条目在会话历史中的位置
+pub(crate) fn history_index(state: &JsValue) -> Option<u64> {
+    state_field(state, "index")
+}
+
+/// 从 `history.state` 中读取离开条目时记录的滚动位置
+pub(crate) fn history_scroll(state: &JsValue) -> Option<ScrollPosition> {
+    Some(ScrollPosition::new(
+        state_number(state, "x")?,
+        state_number(state, "y")?,
+    ))
+}
+
+fn state_field(state: &JsValue, name: &str) -> Option<u64> {
+    state_number(state, name).map(|v| v as u64)
+}
+
+fn state_number(state: &JsValue, name: &str) -> Option<f64> {
+    if !state.is_object() {
+        return None;
+    }
+    js_sys::Reflect::get(state, &JsValue::from_str(name))
+        .ok()
+        .and_then(|v| v.as_f64())
+}
+
+/// 构造写入 `history.state` 的值：记录条目位置，`Restore` 模式下还记录滚动 key 与滚动位置
+pub(crate) fn history_state(
+    behavior: ScrollBehavior,
+    key: u64,
+    index: u64,
+    scroll: Option<ScrollPosition>,
+) -> JsValue {
+    let state = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(
+        &state,
+        &JsValue::from_str("index"),
+        &JsValue::from_f64(index as f64),
+    );
+    if behavior == ScrollBehavior::Restore {
+        let _ = js_sys::Reflect::set(
+            &state,
+            &JsValue::from_str("key"),
+            &JsValue::from_f64(key as f64),
+        );
+        if let Some(scroll) = scroll {
+            for (name, value) in [("x", scroll.x), ("y", scroll.y)] {
+                let _ = js_sys::Reflect::set(
+                    &state,
+                    &JsValue::from_str(name),
+                    &JsValue::from_f64(value),
+                );
+            }
+        }
+    }
+    state.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[derive(Default)]
+    struct MockScroll {
+        position: Cell<ScrollPosition>,
+    }
+
+    impl MockScroll {
+        fn user_scrolls(&self, y: f64) {
+            self.position.set(ScrollPosition::new(0.0, y));
+        }
+
+        fn y(&self) -> f64 {
+            self.position.get().y
+        }
+    }
+
+    impl ScrollHost for MockScroll {
+        fn offset(&self) -> ScrollPosition {
+            self.position.get()
+        }
+
+        fn scroll_to(&self, position: ScrollPosition) {
+            self.position.set(position);
+        }
+    }
+
+    /// 模拟浏览器历史栈，每个条目保存其 `history.state` 中的 key 与滚动位置
+    struct History {
+        entries: Vec<(u64, Option<ScrollPosition>)>,
+        index: usize,
+    }
+
+    impl History {
+        fn new(key: u64) -> Self {
+            Self {
+                entries: vec![(key, None)],
+                index: 0,
+            }
+        }
+
+        fn push(&mut self, manager: &mut ScrollManager, host: &MockScroll) {
+            // 离开前写入当前条目的 state
+            self.entries[self.index].1 = Some(host.offset());
+            let key = manager.begin_push(host);
+            self.entries.truncate(self.index + 1);
+            self.entries.push((key, None));
+            self.index += 1;
+            manager.finish(host, false);
+        }
+
+        fn go(&mut self, delta: isize, manager: &mut ScrollManager, host: &MockScroll) {
+            self.index = self.index.checked_add_signed(delta).unwrap();
+            let (key, saved) = self.entries[self.index];
+            manager.begin_pop(host, Some(key), saved);
+            manager.finish(host, true);
+        }
+    }
+
+    #[test]
+    fn forward_navigation_scrolls_to_top_and_back_restores_offset() {
+        let host = MockScroll::default();
+        let mut manager = ScrollManager::new(ScrollBehavior::Restore, 100);
+        let mut history = History::new(manager.current_key());
+
+        host.user_scrolls(640.0);
+        history.push(&mut manager, &host);
+        assert_eq!(host.y(), 0.0, "push scrolls the new page to top");
+
+        host.user_scrolls(120.0);
+        history.go(-1, &mut manager, &host);
+        assert_eq!(host.y(), 640.0, "back restores the previous offset");
+
+        history.go(1, &mut manager, &host);
+        assert_eq!(host.y(), 120.0, "forward restores the offset left behind");
+    }
+
+    #[test]
+    fn top_and_preserve_behaviors() {
+        let host = MockScroll::default();
+        let mut top = ScrollManager::new(ScrollBehavior::Top, 0);
+        let mut history = History::new(0);
+
+        host.user_scrolls(300.0);
+        history.push(&mut top, &host);
+        assert_eq!(host.y(), 0.0);
+        host.user_scrolls(50.0);
+        history.go(-1, &mut top, &host);
+        assert_eq!(host.y(), 50.0, "Top leaves back/forward alone");
+
+        let mut preserve = ScrollManager::new(ScrollBehavior::Preserve, 0);
+        let mut history = History::new(0);
+        host.user_scrolls(300.0);
+        history.push(&mut preserve, &host);
+        assert_eq!(host.y(), 300.0);
+    }
+
+    #[test]
+    fn offsets_in_history_state_survive_a_reload() {
+        let host = MockScroll::default();
+        let mut manager = ScrollManager::new(ScrollBehavior::Restore, 7);
+        let mut history = History::new(manager.current_key());
+
+        host.user_scrolls(480.0);
+        history.push(&mut manager, &host);
+
+        // 刷新页面：内存中的记录丢失，只剩 history.state
+        let mut reloaded = ScrollManager::new(ScrollBehavior::Restore, history.entries[1].0);
+        host.user_scrolls(0.0);
+        history.go(-1, &mut reloaded, &host);
+        assert_eq!(host.y(), 480.0, "offset read back from history state");
+    }
+}
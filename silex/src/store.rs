@@ -1,4 +1,12 @@
+mod history;
+mod list;
+
+pub use history::*;
+pub use list::*;
+
 use crate::prelude::*;
+#[cfg(all(feature = "persistence", feature = "json"))]
+use silex_core::log::console_error;
 
 /// 状态管理 Trait
 ///
@@ -11,10 +19,18 @@ pub trait Store: Sized + Clone + 'static {
     ///
     /// 如果 Context 中未找到该 Store，将会 panic。
     /// 使用 `try_get` 以避免 panic。
+    #[track_caller]
     fn get() -> Self {
         expect_context::<Self>()
     }
 
+    /// 与 [`get`](Self::get) 相同，但未找到时以 `msg` 作为 panic 信息，
+    /// 例如 `UserStore::expect("UserStore must be provided by App")`
+    #[track_caller]
+    fn expect(msg: &str) -> Self {
+        expect_context_msg::<Self>(msg)
+    }
+
     /// 尝试从当前 Context 中获取 Store 实例
     fn try_get() -> Option<Self> {
         use_context::<Self>()
@@ -31,9 +47,298 @@ pub trait Store: Sized + Clone + 'static {
     }
 }
 
+/// 可整体读取与恢复的 Store，`#[derive(Store)]` 会自动实现
+///
+/// 用于 [`StoreHistory`] 等需要对整个 Store 做快照的场景。
+pub trait StoreSnapshot: Copy + 'static {
+    type Value: 'static;
+
+    /// 当前所有字段的值
+    fn snapshot(&self) -> Self::Value;
+
+    /// 在一个 `batch` 中将所有字段设置为 `value`
+    fn restore(&self, value: Self::Value);
+}
+
+/// 由多个条目（通常是子 Store）组成的集合，例如 `#[derive(Store)]` 生成的 `RwSignal<Vec<TodoStore>>` 字段
+pub trait StoreCollection<T> {
+    /// 批量更新 `indices` 指定的条目
+    ///
+    /// 所有更新在同一个 `batch` 中完成：各条目字段的通知会被合并，
+    /// 集合本身（及依赖它的长度等派生值）只会收到一次通知。越界的索引会被忽略。
+    fn update_all<I, F>(&self, indices: I, f: F)
+    where
+        I: IntoIterator<Item = usize>,
+        F: FnMut(&mut T);
+}
+
+impl<T: 'static> StoreCollection<T> for RwSignal<Vec<T>> {
+    fn update_all<I, F>(&self, indices: I, mut f: F)
+    where
+        I: IntoIterator<Item = usize>,
+        F: FnMut(&mut T),
+    {
+        batch(|| {
+            self.update(|items| {
+                for index in indices {
+                    if let Some(item) = items.get_mut(index) {
+                        f(item);
+                    }
+                }
+            })
+        });
+    }
+}
+
+/// 将整个 Store 以 JSON 形式持久化到 `backend` 的 `key` 下，供 `#[store(persisted)]` 生成的构造函数使用
+///
+/// 创建时若存储中已有数据则用其初始化各字段，否则使用 `defaults`；
+/// 此后任一字段变化都会将 `snapshot` 得到的完整值写回存储。
+/// 存储的 JSON 损坏或读取失败时输出错误并回退到 `defaults`。
+#[cfg(all(feature = "persistence", feature = "json"))]
+pub fn persist_store<S, T, B>(
+    backend: B,
+    key: &str,
+    defaults: T,
+    new: impl FnOnce(T) -> S,
+    snapshot: impl Fn(&S) -> T + 'static,
+) -> S
+where
+    S: Copy + 'static,
+    T: serde::Serialize + serde::de::DeserializeOwned + 'static,
+    B: crate::persist::PersistenceBackend,
+{
+    let initial = match backend.get(key) {
+        Ok(Some(raw)) => serde_json::from_str(&raw).unwrap_or_else(|err| {
+            console_error(format!("Corrupt persisted store '{}': {}", key, err));
+            defaults
+        }),
+        Ok(None) => defaults,
+        Err(err) => {
+            console_error(format!(
+                "Failed to read persisted store '{}': {}",
+                key,
+                err.message()
+            ));
+            defaults
+        }
+    };
+
+    let store = new(initial);
+    let key = key.to_string();
+    Effect::new(move |written: Option<()>| {
+        let value = snapshot(&store);
+        // 首次运行只用于收集依赖，不回写刚读出的值
+        if written.is_some() {
+            let result = serde_json::to_string(&value)
+                .map_err(|err| err.to_string())
+                .and_then(|json| backend.set(&key, &json).map_err(|err| err.message()));
+            if let Err(message) = result {
+                console_error(format!("Failed to persist store '{}': {}", key, message));
+            }
+        }
+    });
+    store
+}
+
+/// 列表字段的细粒度 Store，由 `#[store(keyed)]` 为 `Vec<T>` 字段生成
+///
+/// 每一行都是独立的 `RwSignal<T>`：修改某一行只会通知该行的订阅者，
+/// 只有增删行才会通知列表本身。行信号在其生命周期内保持不变，可直接作为 `For` 的 key。
+/// 行信号归属于 Store 自身的作用域 (而非调用 `push` 时的作用域)，被移除的行随即销毁：
+///
+/// ```rust,ignore
+/// For::new(store.todos.rows(), |row| *row, |row| li(move || row.get().title))
+/// ```
+pub struct KeyedStore<T> {
+    rows: RwSignal<Vec<RwSignal<T>>>,
+    /// 所有行信号的所有者，随创建 Store 的作用域一起销毁
+    owner: NodeId,
+}
+
+impl<T> Clone for KeyedStore<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for KeyedStore<T> {}
+
+impl<T: 'static> KeyedStore<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        let owner = create_scope(|| {});
+        let rows = with_owner(Some(owner), || {
+            items.into_iter().map(RwSignal::new).collect()
+        });
+        Self {
+            rows: RwSignal::new(rows),
+            owner,
+        }
+    }
+
+    /// 在 Store 的作用域中创建行信号
+    fn new_row(&self, value: T) -> RwSignal<T> {
+        with_owner(Some(self.owner), || RwSignal::new(value))
+    }
+
+    /// 销毁已从列表中移除的行
+    fn dispose_row(row: RwSignal<T>) {
+        if let Some(id) = row.id() {
+            dispose(id);
+        }
+    }
+
+    /// 所有行信号，仅在增删行时通知
+    pub fn rows(&self) -> ReadSignal<Vec<RwSignal<T>>> {
+        self.rows.read_signal()
+    }
+
+    /// 第 `index` 行的信号，越界时返回 `None`
+    pub fn get(&self, index: usize) -> Option<RwSignal<T>> {
+        self.rows.with(|rows| rows.get(index).copied())
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.with(Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 在末尾追加一行，返回新行的信号
+    pub fn push(&self, value: T) -> RwSignal<T> {
+        let row = self.new_row(value);
+        self.rows.update(|rows| rows.push(row));
+        row
+    }
+
+    /// 将所有行替换为 `items`
+    ///
+    /// 已有的行信号按位置复用并原地更新，只有行数变化时才通知列表；多出的旧行被销毁。
+    pub fn set(&self, items: Vec<T>) {
+        let rows = self.rows.get_untracked();
+        let len = items.len();
+        batch(|| {
+            let mut items = items.into_iter();
+            for (row, value) in rows.iter().zip(items.by_ref()) {
+                row.set(value);
+            }
+            if len != rows.len() {
+                let extra: Vec<_> = items.map(|value| self.new_row(value)).collect();
+                self.rows.update(|rows| {
+                    rows.truncate(len);
+                    rows.extend(extra);
+                });
+                rows.into_iter().skip(len).for_each(Self::dispose_row);
+            }
+        });
+    }
+
+    /// 移除第 `index` 行并返回其值，越界时返回 `None`。被移除的行信号随即销毁。
+    pub fn remove(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        if index >= self.rows.with_untracked(Vec::len) {
+            return None;
+        }
+        let row = self.rows.try_update(|rows| rows.remove(index))?;
+        let value = row.get_untracked();
+        Self::dispose_row(row);
+        Some(value)
+    }
+
+    /// 当前所有行的值
+    pub fn snapshot(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.rows
+            .with(|rows| rows.iter().map(|row| row.get()).collect())
+    }
+
+    /// 只读视图，见 [`ReadKeyedStore`]
+    pub fn read(&self) -> ReadKeyedStore<T> {
+        ReadKeyedStore {
+            rows: self.rows.read_signal(),
+        }
+    }
+}
+
+/// [`KeyedStore`] 的只读视图，行信号以 `ReadSignal` 形式给出
+pub struct ReadKeyedStore<T> {
+    rows: ReadSignal<Vec<RwSignal<T>>>,
+}
+
+impl<T> Clone for ReadKeyedStore<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ReadKeyedStore<T> {}
+
+impl<T: 'static> ReadKeyedStore<T> {
+    /// 第 `index` 行的只读信号，越界时返回 `None`
+    pub fn get(&self, index: usize) -> Option<ReadSignal<T>> {
+        self.rows
+            .with(|rows| rows.get(index).map(RwSignal::read_signal))
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.with(Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 当前所有行的值
+    pub fn snapshot(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.rows
+            .with(|rows| rows.iter().map(|row| row.get()).collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Clone, Store)]
+    struct Todo {
+        done: bool,
+    }
+
+    #[derive(Clone, PartialEq, Debug, Store)]
+    struct Address {
+        city: String,
+        zip: String,
+    }
+
+    #[derive(Clone, PartialEq, Debug, Store)]
+    struct User {
+        name: String,
+        #[store(nested)]
+        address: Address,
+    }
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Row {
+        id: u32,
+        title: String,
+    }
+
+    #[derive(Clone, Store)]
+    struct Board {
+        #[store(keyed)]
+        rows: Vec<Row>,
+    }
 
     #[derive(Clone, PartialEq, Debug)]
     struct MyStore {
@@ -71,4 +376,431 @@ mod tests {
             assert_eq!(result, store);
         });
     }
+
+    #[test]
+    fn update_all_notifies_collection_once() {
+        create_scope(|| {
+            let todos = RwSignal::new(
+                (0..4)
+                    .map(|_| TodoStore::new(Todo { done: false }))
+                    .collect::<Vec<_>>(),
+            );
+
+            let collection_runs = Rc::new(Cell::new(0));
+            let len = Memo::new({
+                let collection_runs = collection_runs.clone();
+                move |_| {
+                    collection_runs.set(collection_runs.get() + 1);
+                    todos.with(Vec::len)
+                }
+            });
+            let aggregate_runs = Rc::new(Cell::new(0));
+            let completed = Memo::new({
+                let aggregate_runs = aggregate_runs.clone();
+                move |_| {
+                    aggregate_runs.set(aggregate_runs.get() + 1);
+                    todos.with(|items| items.iter().filter(|t| t.done.get()).count())
+                }
+            });
+            assert_eq!((len.get(), completed.get()), (4, 0));
+            assert_eq!((collection_runs.get(), aggregate_runs.get()), (1, 1));
+
+            todos.update_all([0, 2, 3, 9], |todo| todo.done.set(true));
+
+            assert_eq!((len.get(), completed.get()), (4, 3));
+            assert_eq!(collection_runs.get(), 2, "one collection notification");
+            assert_eq!(aggregate_runs.get(), 2, "entry updates are coalesced");
+        });
+    }
+
+    #[test]
+    fn patch_notifies_each_field_once() {
+        create_scope(|| {
+            let address = AddressStore::new(Address {
+                city: "London".into(),
+                zip: "N1".into(),
+            });
+
+            let runs = Rc::new(Cell::new((0, 0)));
+            let both = Rc::new(Cell::new(0));
+            Effect::new({
+                let runs = runs.clone();
+                move |_| {
+                    address.city.track();
+                    runs.set((runs.get().0 + 1, runs.get().1));
+                }
+            });
+            Effect::new({
+                let runs = runs.clone();
+                move |_| {
+                    address.zip.track();
+                    runs.set((runs.get().0, runs.get().1 + 1));
+                }
+            });
+            // 同时读取两个字段：若补丁未合并在一个 batch 中会运行两次
+            Effect::new({
+                let both = both.clone();
+                move |_| {
+                    address.city.track();
+                    address.zip.track();
+                    both.set(both.get() + 1);
+                }
+            });
+
+            address.patch(|p| {
+                p.city = Some("Paris".into());
+                p.zip = Some("75001".into());
+            });
+
+            assert_eq!(runs.get(), (2, 2));
+            assert_eq!(both.get(), 2, "applied within one batch");
+            assert_eq!(
+                address.get(),
+                Address {
+                    city: "Paris".into(),
+                    zip: "75001".into(),
+                }
+            );
+
+            // 未填写的字段保持不变
+            address.apply(AddressPatch {
+                zip: Some("75002".into()),
+                ..Default::default()
+            });
+            assert_eq!(runs.get(), (2, 3));
+            assert_eq!(address.city.get(), "Paris");
+        });
+    }
+
+    #[test]
+    fn nested_store_updates_stay_fine_grained() {
+        create_scope(|| {
+            let user = UserStore::new(User {
+                name: "Ada".into(),
+                address: Address {
+                    city: "London".into(),
+                    zip: "N1".into(),
+                },
+            });
+
+            let name_runs = Rc::new(Cell::new(0));
+            Effect::new({
+                let name_runs = name_runs.clone();
+                move |_| {
+                    user.name.track();
+                    name_runs.set(name_runs.get() + 1);
+                }
+            });
+            let city_runs = Rc::new(Cell::new(0));
+            Effect::new({
+                let city_runs = city_runs.clone();
+                move |_| {
+                    user.address.city.track();
+                    city_runs.set(city_runs.get() + 1);
+                }
+            });
+
+            user.address.city.set("Paris".into());
+
+            assert_eq!(name_runs.get(), 1, "name subscribers are not notified");
+            assert_eq!(city_runs.get(), 2);
+            assert_eq!(
+                user.get(),
+                User {
+                    name: "Ada".into(),
+                    address: Address {
+                        city: "Paris".into(),
+                        zip: "N1".into(),
+                    },
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn read_view_exposes_live_read_signals() {
+        create_scope(|| {
+            let user = UserStore::new(User {
+                name: "Ada".into(),
+                address: Address {
+                    city: "London".into(),
+                    zip: "N1".into(),
+                },
+            });
+            let view = user.read();
+            // 只读句柄：类型上不提供 set / update
+            let name: ReadSignal<String> = view.name;
+            let city: ReadSignal<String> = view.address.city;
+
+            let seen = Rc::new(Cell::new(0));
+            Effect::new({
+                let seen = seen.clone();
+                move |_| {
+                    // 按引用读取，不克隆字段
+                    seen.set(name.with(String::len));
+                }
+            });
+            assert_eq!(seen.get(), 3);
+
+            user.name.set("Grace".into());
+            user.address.city.set("Paris".into());
+            assert_eq!(seen.get(), 5);
+            assert_eq!(city.get(), "Paris");
+            assert_eq!(view.get(), user.get());
+
+            let board = BoardStore::new(Board {
+                rows: vec![Row {
+                    id: 0,
+                    title: "first".into(),
+                }],
+            });
+            let rows = board.read().rows;
+            let first: ReadSignal<Row> = rows.get(0).unwrap();
+            board
+                .rows
+                .get(0)
+                .unwrap()
+                .update(|row| row.title = "edited".into());
+            assert_eq!(first.with(|row| row.title.clone()), "edited");
+            assert_eq!(rows.len(), 1);
+            assert!(rows.get(1).is_none());
+        });
+    }
+
+    #[test]
+    fn keyed_store_row_edits_notify_only_that_row() {
+        create_scope(|| {
+            let board = BoardStore::new(Board {
+                rows: (0..3)
+                    .map(|id| Row {
+                        id,
+                        title: format!("row {id}"),
+                    })
+                    .collect(),
+            });
+
+            let list_runs = Rc::new(Cell::new(0));
+            Effect::new({
+                let list_runs = list_runs.clone();
+                move |_| {
+                    board.rows.rows().track();
+                    list_runs.set(list_runs.get() + 1);
+                }
+            });
+            let row_runs: Vec<_> = (0..3)
+                .map(|index| {
+                    let runs = Rc::new(Cell::new(0));
+                    let row = board.rows.get(index).unwrap();
+                    Effect::new({
+                        let runs = runs.clone();
+                        move |_| {
+                            row.track();
+                            runs.set(runs.get() + 1);
+                        }
+                    });
+                    runs
+                })
+                .collect();
+
+            let second = board.rows.get(1).unwrap();
+            second.update(|row| row.title = "edited".into());
+            let runs: Vec<_> = row_runs.iter().map(|r| r.get()).collect();
+            assert_eq!(runs, vec![1, 2, 1], "only the edited row is notified");
+            assert_eq!(list_runs.get(), 1, "the list is not notified");
+
+            // 增删行只通知列表，行信号保持稳定
+            board.rows.push(Row {
+                id: 3,
+                title: "row 3".into(),
+            });
+            assert_eq!(board.rows.remove(0).map(|row| row.id), Some(0));
+            assert_eq!(board.rows.remove(9), None);
+            assert_eq!(list_runs.get(), 3);
+            assert!(board.rows.get(0) == Some(second));
+            assert_eq!(
+                board
+                    .get()
+                    .rows
+                    .iter()
+                    .map(|row| row.id)
+                    .collect::<Vec<_>>(),
+                vec![1, 2, 3]
+            );
+            assert_eq!(board.rows.snapshot()[0].title, "edited");
+        });
+    }
+
+    #[test]
+    fn keyed_store_set_reuses_rows_by_position() {
+        create_scope(|| {
+            let board = BoardStore::new(Board {
+                rows: (0..2)
+                    .map(|id| Row {
+                        id,
+                        title: format!("row {id}"),
+                    })
+                    .collect(),
+            });
+            let first = board.rows.get(0).unwrap();
+            let list_runs = Rc::new(Cell::new(0));
+            Effect::new({
+                let list_runs = list_runs.clone();
+                move |_| {
+                    board.rows.rows().track();
+                    list_runs.set(list_runs.get() + 1);
+                }
+            });
+
+            let row = |id: u32| Row {
+                id,
+                title: format!("new {id}"),
+            };
+            // 行数不变：原地更新，列表不收到通知
+            board.rows.set(vec![row(5), row(6)]);
+            assert_eq!(list_runs.get(), 1);
+            assert!(board.rows.get(0) == Some(first));
+            assert_eq!(first.get_untracked().id, 5);
+
+            // 行数变化：只通知一次列表
+            board.rows.set(vec![row(7), row(8), row(9)]);
+            assert_eq!(list_runs.get(), 2);
+            assert!(board.rows.get(0) == Some(first));
+            assert_eq!(
+                board
+                    .rows
+                    .snapshot()
+                    .iter()
+                    .map(|r| r.id)
+                    .collect::<Vec<_>>(),
+                vec![7, 8, 9]
+            );
+
+            // `#[derive(Store)]` 生成的 `set` 对 keyed 字段使用同一逻辑 (`StoreHistory` 的恢复依赖于此)
+            board.set(Board { rows: vec![row(1)] });
+            assert_eq!(list_runs.get(), 3);
+            assert_eq!(board.rows.snapshot(), vec![row(1)]);
+        });
+    }
+
+    #[test]
+    fn keyed_store_rows_belong_to_the_store() {
+        create_scope(|| {
+            let board = BoardStore::new(Board { rows: Vec::new() });
+
+            // 在短暂的作用域中 (如事件处理函数) 追加的行不随该作用域销毁
+            let handler = create_scope(move || {
+                board.rows.push(Row {
+                    id: 1,
+                    title: "row 1".into(),
+                });
+            });
+            dispose(handler);
+            let row = board.rows.get(0).unwrap();
+            assert_eq!(row.try_get().map(|row| row.id), Some(1));
+
+            // 被移除的行随即销毁
+            assert!(board.rows.remove(0).is_some());
+            assert_eq!(row.try_get(), None);
+
+            board.rows.set(vec![
+                Row {
+                    id: 2,
+                    title: "row 2".into(),
+                },
+                Row {
+                    id: 3,
+                    title: "row 3".into(),
+                },
+            ]);
+            let last = board.rows.get(1).unwrap();
+            board.rows.set(Vec::new());
+            assert_eq!(last.try_get(), None, "truncated rows are disposed");
+        });
+    }
+
+    #[cfg(all(feature = "persistence", feature = "json"))]
+    mod persisted {
+        use super::*;
+        use crate::persist::{
+            BackendEvent, BackendSubscription, PersistenceBackend, PersistenceError,
+        };
+        use serde::{Deserialize, Serialize};
+        use std::cell::RefCell;
+        use std::collections::HashMap;
+
+        #[derive(Clone, Default)]
+        struct MemoryBackend(Rc<RefCell<HashMap<String, String>>>);
+
+        impl PersistenceBackend for MemoryBackend {
+            fn get(&self, key: &str) -> Result<Option<String>, PersistenceError> {
+                Ok(self.0.borrow().get(key).cloned())
+            }
+
+            fn set(&self, key: &str, value: &str) -> Result<(), PersistenceError> {
+                self.0
+                    .borrow_mut()
+                    .insert(key.to_string(), value.to_string());
+                Ok(())
+            }
+
+            fn remove(&self, key: &str) -> Result<(), PersistenceError> {
+                self.0.borrow_mut().remove(key);
+                Ok(())
+            }
+
+            fn subscribe(
+                &self,
+                _key: String,
+                _callback: Rc<dyn Fn(BackendEvent)>,
+            ) -> Result<BackendSubscription, PersistenceError> {
+                Ok(BackendSubscription::new(|| {}))
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Store)]
+        #[store(persisted)]
+        struct UserSettings {
+            theme: String,
+            font_size: u32,
+        }
+
+        fn defaults() -> UserSettings {
+            UserSettings {
+                theme: "light".into(),
+                font_size: 14,
+            }
+        }
+
+        #[test]
+        fn mutations_are_written_and_hydrated() {
+            create_scope(|| {
+                let backend = MemoryBackend::default();
+                let settings =
+                    UserSettingsStore::persisted_in(backend.clone(), "settings", defaults());
+                assert_eq!(settings.get(), defaults());
+                assert!(backend.0.borrow().is_empty(), "nothing written on creation");
+
+                settings.theme.set("dark".into());
+                let stored = backend.0.borrow().get("settings").cloned().unwrap();
+                assert_eq!(
+                    serde_json::from_str::<UserSettings>(&stored).unwrap().theme,
+                    "dark"
+                );
+
+                let restored = UserSettingsStore::persisted_in(backend, "settings", defaults());
+                assert_eq!(restored.theme.get(), "dark");
+                assert_eq!(restored.font_size.get(), 14);
+            });
+        }
+
+        #[test]
+        fn corrupt_json_falls_back_to_defaults() {
+            create_scope(|| {
+                let backend = MemoryBackend::default();
+                backend.set("settings", "{not json").unwrap();
+                let settings = UserSettingsStore::persisted_in(backend, "settings", defaults());
+                assert_eq!(settings.get(), defaults());
+            });
+        }
+    }
 }
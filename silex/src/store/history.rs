@@ -0,0 +1,262 @@
+use crate::store::StoreSnapshot;
+use silex_core::reactivity::{Effect, RwSignal};
+use silex_core::traits::{RxBase, RxWrite};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::Duration;
+
+struct HistoryState<T> {
+    /// 最近一次提交的快照
+    current: T,
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    /// 上一次记录编辑的时间 (ms)，撤销/重做后清空，使下一次编辑开启新的历史条目
+    last_edit: Option<f64>,
+    coalesce_ms: f64,
+    max_depth: usize,
+}
+
+impl<T> HistoryState<T> {
+    /// 压入撤销栈，超出 `max_depth` 时丢弃最早的记录
+    fn push_undo(&mut self, value: T) {
+        self.undo.push_back(value);
+        while self.undo.len() > self.max_depth {
+            self.undo.pop_front();
+        }
+    }
+}
+
+/// Store 的撤销/重做历史
+///
+/// 每当 Store 的任一字段发生变化，变化前的快照被压入撤销栈 (最多保留 `max_depth` 条)，
+/// 重做栈随之清空。`undo`/`redo` 在一个 `batch` 中恢复所有字段。
+///
+/// ```rust,ignore
+/// let doc = DocumentStore::new(Document::default());
+/// let history = StoreHistory::new(doc, 100).coalesce(Duration::from_millis(500));
+///
+/// button("Undo").on(event::click, move |_| history.undo());
+/// ```
+pub struct StoreHistory<S: StoreSnapshot> {
+    store: S,
+    state: Rc<RefCell<HistoryState<S::Value>>>,
+    /// 历史栈变化时递增，使 `can_undo`/`can_redo` 可被追踪
+    version: RwSignal<u64>,
+}
+
+impl<S: StoreSnapshot> Clone for StoreHistory<S> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store,
+            state: self.state.clone(),
+            version: self.version,
+        }
+    }
+}
+
+impl<S> StoreHistory<S>
+where
+    S: StoreSnapshot,
+    S::Value: Clone + PartialEq,
+{
+    pub fn new(store: S, max_depth: usize) -> Self {
+        Self::with_clock(store, max_depth, Rc::new(js_sys::Date::now))
+    }
+
+    fn with_clock(store: S, max_depth: usize, clock: Rc<dyn Fn() -> f64>) -> Self {
+        let state = Rc::new(RefCell::new(HistoryState {
+            current: store.snapshot(),
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            last_edit: None,
+            coalesce_ms: 0.0,
+            max_depth,
+        }));
+        let version = RwSignal::new(0);
+
+        Effect::new({
+            let state = state.clone();
+            move |_| {
+                let value = store.snapshot();
+                let mut state = state.borrow_mut();
+                // 撤销/重做恢复的快照已是 current，不再记录
+                if value == state.current {
+                    return;
+                }
+
+                // 仅在开启合并时读取时钟
+                let now = (state.coalesce_ms > 0.0).then(|| clock());
+                let coalesced = match (now, state.last_edit) {
+                    (Some(now), Some(last)) => now - last < state.coalesce_ms,
+                    _ => false,
+                };
+                if !coalesced {
+                    let previous = std::mem::replace(&mut state.current, value);
+                    state.push_undo(previous);
+                } else {
+                    state.current = value;
+                }
+                state.redo.clear();
+                state.last_edit = now;
+                drop(state);
+                version.update(|v| *v += 1);
+            }
+        });
+
+        Self {
+            store,
+            state,
+            version,
+        }
+    }
+
+    /// 将间隔小于 `window` 的连续编辑合并为一条历史记录
+    pub fn coalesce(self, window: Duration) -> Self {
+        self.state.borrow_mut().coalesce_ms = window.as_secs_f64() * 1000.0;
+        self
+    }
+
+    /// 恢复到上一条历史记录，无记录时不做任何事
+    pub fn undo(&self) {
+        let mut state = self.state.borrow_mut();
+        let Some(previous) = state.undo.pop_back() else {
+            return;
+        };
+        let current = std::mem::replace(&mut state.current, previous.clone());
+        state.redo.push(current);
+        self.restore(state, previous);
+    }
+
+    /// 重新应用最近一次撤销的记录，无记录时不做任何事
+    pub fn redo(&self) {
+        let mut state = self.state.borrow_mut();
+        let Some(next) = state.redo.pop() else {
+            return;
+        };
+        let current = std::mem::replace(&mut state.current, next.clone());
+        state.push_undo(current);
+        self.restore(state, next);
+    }
+
+    fn restore(&self, mut state: std::cell::RefMut<'_, HistoryState<S::Value>>, value: S::Value) {
+        state.last_edit = None;
+        // 恢复会同步触发记录 Effect，需先释放借用
+        drop(state);
+        self.store.restore(value);
+        self.version.update(|v| *v += 1);
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.version.track();
+        !self.state.borrow().undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.version.track();
+        !self.state.borrow().redo.is_empty()
+    }
+
+    /// 清空撤销与重做栈
+    pub fn clear(&self) {
+        let mut state = self.state.borrow_mut();
+        state.undo.clear();
+        state.redo.clear();
+        state.last_edit = None;
+        drop(state);
+        self.version.update(|v| *v += 1);
+    }
+
+    pub fn store(&self) -> S {
+        self.store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::cell::Cell;
+
+    #[derive(Clone, Debug, PartialEq, Store)]
+    struct Doc {
+        title: String,
+        words: u32,
+    }
+
+    fn doc(title: &str, words: u32) -> Doc {
+        Doc {
+            title: title.into(),
+            words,
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_restore_snapshots() {
+        create_scope(|| {
+            let store = DocStore::new(doc("draft", 0));
+            let history = StoreHistory::new(store, 10);
+            assert!(!history.can_undo());
+
+            store.title.set("intro".into());
+            store.words.set(10);
+            store.set(doc("final", 25));
+
+            history.undo();
+            history.undo();
+            assert_eq!(store.get(), doc("intro", 0));
+            assert!(history.can_redo());
+
+            history.redo();
+            assert_eq!(store.get(), doc("intro", 10));
+            history.redo();
+            assert_eq!(store.get(), doc("final", 25));
+            assert!(!history.can_redo());
+
+            // 撤销后的新编辑会丢弃重做栈
+            history.undo();
+            store.words.set(11);
+            assert!(!history.can_redo());
+            history.undo();
+            assert_eq!(store.get(), doc("intro", 10));
+        });
+    }
+
+    #[test]
+    fn max_depth_and_coalescing() {
+        create_scope(|| {
+            let store = DocStore::new(doc("a", 0));
+            let now = Rc::new(Cell::new(0.0));
+            let history = StoreHistory::with_clock(store, 2, {
+                let now = now.clone();
+                Rc::new(move || now.get())
+            })
+            .coalesce(Duration::from_millis(500));
+
+            // 500ms 内的连续输入合并为一条记录
+            for (at, words) in [(0.0, 1), (100.0, 2), (300.0, 3)] {
+                now.set(at);
+                store.words.set(words);
+            }
+            now.set(1000.0);
+            store.words.set(4);
+            now.set(2000.0);
+            store.words.set(5);
+
+            // 最多保留两条记录：最早的 (words = 0) 被丢弃
+            history.undo();
+            assert_eq!(store.words.get(), 4);
+            history.undo();
+            assert_eq!(store.words.get(), 3);
+            history.undo();
+            assert_eq!(store.words.get(), 3);
+            assert!(!history.can_undo());
+
+            // 重做同样受 `max_depth` 限制
+            history.redo();
+            history.redo();
+            assert_eq!(store.words.get(), 5);
+            assert_eq!(history.state.borrow().undo.len(), 2);
+        });
+    }
+}
@@ -0,0 +1,203 @@
+use crate::store::KeyedStore;
+use silex_core::reactivity::{Memo, ReadSignal, RwSignal};
+use silex_core::traits::{RxRead, RxWrite};
+
+/// 面向表格的列表 Store：在 [`KeyedStore`] 之上提供排序与过滤的派生视图
+///
+/// 派生视图是以行信号为元素的 `Memo`，依赖原始行及视图中读取的其它信号；
+/// 结果不变时 (如追加了不满足过滤条件的行) 不会通知视图的订阅者。
+///
+/// ```rust,ignore
+/// let users = ListStore::new(load_users());
+/// let query = RwSignal::new(String::new());
+/// let visible = users.filtered(move |u: &User| u.name.contains(&*query.get()));
+/// let by_age = users.sorted_by(|u: &User| u.age);
+///
+/// For::new(visible, |row| *row, |row| tr(move || row.get().name))
+/// ```
+pub struct ListStore<T> {
+    rows: KeyedStore<T>,
+}
+
+impl<T> Clone for ListStore<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListStore<T> {}
+
+impl<T: Clone + 'static> ListStore<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            rows: KeyedStore::new(items),
+        }
+    }
+
+    /// 底层的 [`KeyedStore`]
+    pub fn keyed(&self) -> KeyedStore<T> {
+        self.rows
+    }
+
+    /// 所有行信号 (原始顺序)，仅在增删行时通知
+    pub fn rows(&self) -> ReadSignal<Vec<RwSignal<T>>> {
+        self.rows.rows()
+    }
+
+    /// 第 `index` 行的信号，越界时返回 `None`
+    pub fn get(&self, index: usize) -> Option<RwSignal<T>> {
+        self.rows.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// 在末尾追加一行，返回新行的信号
+    pub fn push(&self, value: T) -> RwSignal<T> {
+        self.rows.push(value)
+    }
+
+    /// 移除第 `index` 行并返回其值，越界时返回 `None`
+    pub fn remove(&self, index: usize) -> Option<T> {
+        self.rows.remove(index)
+    }
+
+    /// 原地修改第 `index` 行，只通知该行及依赖它的派生视图；越界时返回 `false`
+    pub fn update(&self, index: usize, f: impl FnOnce(&mut T)) -> bool {
+        match self.rows.get(index) {
+            Some(row) => {
+                row.update(f);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 将所有行替换为 `items`，见 [`KeyedStore::set`]
+    pub fn set(&self, items: Vec<T>) {
+        self.rows.set(items);
+    }
+
+    /// 当前所有行的值
+    pub fn snapshot(&self) -> Vec<T> {
+        self.rows.snapshot()
+    }
+
+    /// 按 `key_fn` 升序排列的行 (稳定排序)
+    pub fn sorted_by<K, F>(&self, key_fn: F) -> Memo<Vec<RwSignal<T>>>
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'static,
+    {
+        let rows = self.rows();
+        Memo::new(move |_| {
+            rows.with(|rows| {
+                let mut keyed: Vec<_> = rows
+                    .iter()
+                    .map(|row| (row.with(|value| key_fn(value)), *row))
+                    .collect();
+                keyed.sort_by(|a, b| a.0.cmp(&b.0));
+                keyed.into_iter().map(|(_, row)| row).collect()
+            })
+        })
+    }
+
+    /// 满足 `predicate` 的行 (保持原始顺序)
+    ///
+    /// `predicate` 中读取的信号同样被追踪，这些信号变化时重新过滤。
+    pub fn filtered<P>(&self, predicate: P) -> Memo<Vec<RwSignal<T>>>
+    where
+        P: Fn(&T) -> bool + 'static,
+    {
+        let rows = self.rows();
+        Memo::new(move |_| {
+            rows.with(|rows| {
+                rows.iter()
+                    .filter(|row| row.with(|value| predicate(value)))
+                    .copied()
+                    .collect()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Item {
+        name: &'static str,
+        price: u32,
+    }
+
+    fn item(name: &'static str, price: u32) -> Item {
+        Item { name, price }
+    }
+
+    fn names(view: Memo<Vec<RwSignal<Item>>>) -> Vec<&'static str> {
+        view.with(|rows| rows.iter().map(|row| row.with(|item| item.name)).collect())
+    }
+
+    #[test]
+    fn filtered_view_only_updates_for_matching_items() {
+        create_scope(|| {
+            let list = ListStore::new(vec![item("pen", 3), item("book", 12)]);
+            let min_price = RwSignal::new(10);
+            let expensive = list.filtered(move |item: &Item| item.price >= min_price.get());
+
+            let runs = Rc::new(Cell::new(0));
+            Effect::new({
+                let runs = runs.clone();
+                move |_| {
+                    expensive.track();
+                    runs.set(runs.get() + 1);
+                }
+            });
+            assert_eq!(names(expensive), ["book"]);
+            assert_eq!(runs.get(), 1);
+
+            // 不满足条件的新行不通知过滤视图
+            list.push(item("eraser", 1));
+            assert_eq!(runs.get(), 1);
+
+            list.push(item("lamp", 40));
+            assert_eq!(runs.get(), 2);
+            assert_eq!(names(expensive), ["book", "lamp"]);
+
+            // 过滤条件依赖的信号变化时重新过滤
+            min_price.set(3);
+            assert_eq!(runs.get(), 3);
+            assert_eq!(names(expensive), ["pen", "book", "lamp"]);
+
+            // 修改行使其不再满足条件
+            list.update(0, |item| item.price = 2);
+            assert_eq!(names(expensive), ["book", "lamp"]);
+        });
+    }
+
+    #[test]
+    fn sorted_view_follows_row_updates() {
+        create_scope(|| {
+            let list = ListStore::new(vec![item("c", 30), item("a", 10), item("b", 20)]);
+            let by_price = list.sorted_by(|item: &Item| item.price);
+            assert_eq!(names(by_price), ["a", "b", "c"]);
+
+            list.update(1, |item| item.price = 99);
+            assert_eq!(names(by_price), ["b", "c", "a"]);
+
+            assert_eq!(list.remove(0), Some(item("c", 30)));
+            assert_eq!(names(by_price), ["b", "a"]);
+            // 原始顺序不受影响
+            assert_eq!(list.snapshot(), [item("a", 99), item("b", 20)]);
+        });
+    }
+}
@@ -0,0 +1,62 @@
+//! 挂载测试共用的视图替身：只记录挂载了什么，不访问 DOM
+
+use silex_dom::attribute::PendingAttribute;
+use silex_dom::prelude::{ApplyAttributes, Mount, MountRef};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Node;
+
+/// 按挂载顺序记录的标签
+pub(crate) type MountLog = Rc<RefCell<Vec<String>>>;
+
+/// 测试视图：挂载时以 `record` 求值并写入日志，从不读写父节点
+#[derive(Clone)]
+pub(crate) struct Probe {
+    log: MountLog,
+    record: Rc<dyn Fn() -> String>,
+}
+
+impl Probe {
+    /// 挂载时记录固定的标签
+    pub(crate) fn marker(log: &MountLog, label: impl Into<String>) -> Self {
+        let label = label.into();
+        Self::new(log, move || label.clone())
+    }
+
+    /// 挂载时记录 `record` 的结果，用于观察挂载时所处的上下文
+    pub(crate) fn new(log: &MountLog, record: impl Fn() -> String + 'static) -> Self {
+        Self {
+            log: log.clone(),
+            record: Rc::new(record),
+        }
+    }
+}
+
+impl ApplyAttributes for Probe {}
+
+impl Mount for Probe {
+    fn mount(self, parent: &Node, attrs: Vec<PendingAttribute>) {
+        self.mount_ref(parent, attrs);
+    }
+}
+
+impl MountRef for Probe {
+    fn mount_ref(&self, _parent: &Node, _attrs: Vec<PendingAttribute>) {
+        self.log.borrow_mut().push((self.record)());
+    }
+}
+
+/// 挂载 `view`。被测视图只把父节点交给 [`Probe`]，因此这里不需要真实的节点
+pub(crate) fn mount(view: impl Mount) {
+    view.mount(&unused_parent(), Vec::new());
+}
+
+/// 以引用挂载 `view`，规则同 [`mount`]
+pub(crate) fn mount_ref(view: &impl MountRef) {
+    view.mount_ref(&unused_parent(), Vec::new());
+}
+
+fn unused_parent() -> Node {
+    JsValue::NULL.unchecked_into()
+}
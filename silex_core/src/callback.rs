@@ -1,5 +1,7 @@
 use std::any::Any;
+use std::future::Future;
 use std::marker::PhantomData;
+use std::pin::Pin;
 
 pub use silex_reactivity::NodeId;
 
@@ -19,47 +21,54 @@ pub use silex_reactivity::NodeId;
 /// cb2.call(100);
 /// ```
 #[derive(Debug)]
-pub struct Callback<T = ()> {
+pub struct Callback<T = (), Out = ()> {
     id: NodeId,
-    marker: PhantomData<T>,
+    marker: PhantomData<fn(T) -> Out>,
 }
 
-impl<T> Clone for Callback<T> {
+impl<T, Out> Clone for Callback<T, Out> {
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<T> Copy for Callback<T> {}
+impl<T, Out> Copy for Callback<T, Out> {}
 
-impl<T: 'static> Callback<T> {
+impl<T: 'static, Out: 'static> Callback<T, Out> {
     /// Create a new callback from a closure.
     pub fn new<F>(f: F) -> Self
     where
-        F: Fn(T) + 'static,
+        F: Fn(T) -> Out + 'static,
     {
-        let id = silex_reactivity::register_callback(move |any: Box<dyn Any>| {
-            if let Ok(arg) = any.downcast::<T>() {
-                f(*arg);
-            } else {
-                #[cfg(debug_assertions)]
-                {
-                    let type_name = std::any::type_name::<T>();
-                    crate::log::console_error(
-                        format!("Callback: type mismatch, expected {}", type_name).as_str(),
-                    );
+        let id =
+            silex_reactivity::register_callback_with_result(move |any: Box<dyn Any>| {
+                match any.downcast::<T>() {
+                    Ok(arg) => Box::new(f(*arg)) as Box<dyn Any>,
+                    Err(_) => {
+                        #[cfg(debug_assertions)]
+                        {
+                            let type_name = std::any::type_name::<T>();
+                            crate::log::console_error(
+                                format!("Callback: type mismatch, expected {}", type_name).as_str(),
+                            );
+                        }
+                        Box::new(())
+                    }
                 }
-            }
-        });
+            });
         Self {
             id,
             marker: PhantomData,
         }
     }
 
-    /// Call the callback with the given argument.
-    pub fn call(&self, arg: T) {
-        silex_reactivity::invoke_callback(self.id, Box::new(arg));
+    /// Call the callback and return its result.
+    ///
+    /// Returns `None` if the callback has been disposed.
+    pub fn try_call(&self, arg: T) -> Option<Out> {
+        silex_reactivity::try_invoke_callback(self.id, Box::new(arg))
+            .and_then(|out| out.downcast::<Out>().ok())
+            .map(|out| *out)
     }
 
     /// Returns the underlying `NodeId` for this callback.
@@ -68,18 +77,106 @@ impl<T: 'static> Callback<T> {
     }
 }
 
+impl<T: 'static, Out: Default + 'static> Callback<T, Out> {
+    /// Call the callback with the given argument.
+    ///
+    /// Returns `Out::default()` if the callback has been disposed.
+    pub fn call(&self, arg: T) -> Out {
+        self.try_call(arg).unwrap_or_default()
+    }
+}
+
 // Allow passing a closure directly where a Callback is expected (if Into is used)
-impl<T: 'static, F> From<F> for Callback<T>
+impl<T: 'static, Out: 'static, F> From<F> for Callback<T, Out>
 where
-    F: Fn(T) + 'static,
+    F: Fn(T) -> Out + 'static,
 {
     fn from(f: F) -> Self {
         Self::new(f)
     }
 }
 
-impl<T: 'static> Default for Callback<T> {
+impl<T: 'static, Out: Default + 'static> Default for Callback<T, Out> {
     fn default() -> Self {
-        Self::new(|_| {})
+        Self::new(|_| Out::default())
+    }
+}
+
+type CallbackFuture<Out> = Pin<Box<dyn Future<Output = Out>>>;
+
+/// A `Copy`-able asynchronous callback whose `call` returns a future.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let save = AsyncCallback::new(|draft: Draft| async move { api::save(draft).await });
+///
+/// spawn_local(async move {
+///     if let Some(result) = save.call(draft).await {
+///         // ...
+///     }
+/// });
+/// ```
+pub struct AsyncCallback<T = (), Out = ()> {
+    inner: Callback<T, CallbackFuture<Out>>,
+}
+
+impl<T, Out> Clone for AsyncCallback<T, Out> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Out> Copy for AsyncCallback<T, Out> {}
+
+impl<T, Out> std::fmt::Debug for AsyncCallback<T, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncCallback")
+            .field("id", &self.inner.id)
+            .finish()
     }
 }
+
+impl<T: 'static, Out: 'static> AsyncCallback<T, Out> {
+    /// Create a new async callback from a closure returning a future.
+    pub fn new<F, Fut>(f: F) -> Self
+    where
+        F: Fn(T) -> Fut + 'static,
+        Fut: Future<Output = Out> + 'static,
+    {
+        Self {
+            inner: Callback::new(move |arg| Box::pin(f(arg)) as CallbackFuture<Out>),
+        }
+    }
+
+    /// Call the callback and await its result.
+    ///
+    /// The future resolves to `None` if the callback has been disposed.
+    pub fn call(&self, arg: T) -> impl Future<Output = Option<Out>> + use<T, Out> {
+        let fut = self.inner.try_call(arg);
+        async move {
+            match fut {
+                Some(fut) => Some(fut.await),
+                None => None,
+            }
+        }
+    }
+
+    /// Returns the underlying `NodeId` for this callback.
+    pub fn id(&self) -> NodeId {
+        self.inner.id()
+    }
+}
+
+impl<T: 'static, Out: 'static, F, Fut> From<F> for AsyncCallback<T, Out>
+where
+    F: Fn(T) -> Fut + 'static,
+    Fut: Future<Output = Out> + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,45 @@
+use super::*;
+use silex_reactivity::{create_scope, dispose};
+use std::task::{Context, Poll, Waker};
+
+#[test]
+fn test_typed_callback_returns_value() {
+    let square = Callback::new(|x: i32| x * x);
+    assert_eq!(square.call(7), 49);
+    assert_eq!(square.try_call(3), Some(9));
+
+    // 既有的无返回值用法保持不变
+    let hits = std::rc::Rc::new(std::cell::Cell::new(0));
+    let cb: Callback<()> = Callback::new({
+        let hits = hits.clone();
+        move |_| hits.set(hits.get() + 1)
+    });
+    cb.call(());
+    assert_eq!(hits.get(), 1);
+}
+
+#[test]
+fn test_disposed_callback_returns_none() {
+    let mut cb = None;
+    let scope = create_scope(|| cb = Some(Callback::new(|x: u32| x + 1)));
+    let cb = cb.unwrap();
+    assert_eq!(cb.call(1), 2);
+
+    dispose(scope);
+    assert_eq!(cb.try_call(1), None);
+    assert_eq!(cb.call(1), 0);
+}
+
+#[test]
+fn test_async_callback_awaited() {
+    let double = AsyncCallback::new(|x: u32| async move { x * 2 });
+    let mut fut = Box::pin(double.call(21));
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(Some(42)));
+
+    let mut cb = None;
+    let scope = create_scope(|| cb = Some(AsyncCallback::new(|_: ()| async {})));
+    dispose(scope);
+    let mut fut = Box::pin(cb.unwrap().call(()));
+    assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(None));
+}
@@ -8,7 +8,7 @@ pub mod node_ref;
 pub mod reactivity;
 pub mod traits;
 
-pub use callback::Callback;
+pub use callback::{AsyncCallback, Callback};
 pub use error::{SilexError, SilexResult};
 pub use node_ref::NodeRef;
 
@@ -211,14 +211,14 @@ macro_rules! rx {
 
 pub mod prelude {
     pub use crate::Rx;
-    pub use crate::callback::Callback;
+    pub use crate::callback::{AsyncCallback, Callback};
     pub use crate::log::*;
     pub use crate::logic::*;
     pub use crate::node_ref::NodeRef;
     pub use crate::reactivity::*;
     pub use crate::traits::*;
     pub use crate::{SilexError, SilexResult};
-    pub use crate::{batch_read, batch_read_untracked, rx};
+    pub use crate::{batch_read, batch_read_untracked, rx, set_all};
 }
 
 /// Multi-signal batch read macro for zero-copy access to multiple signals.
@@ -279,3 +279,24 @@ macro_rules! batch_read_untracked {
         $crate::batch_read_untracked!([$($s),+] => [$($p: $t),+] => $body)
     };
 }
+
+/// 在同一个 `batch` 中设置多个信号，依赖这些信号的 Effect 只在全部设置完成后运行一次。
+///
+/// 等价于 `batch(|| { a.set(..); b.set(..); })`，省去闭包样板代码。
+///
+/// # Example
+/// ```rust,ignore
+/// let name = RwSignal::new(String::new());
+/// let age = RwSignal::new(0);
+///
+/// set_all!(name => "Alice".to_string(), age => 42);
+/// ```
+#[macro_export]
+macro_rules! set_all {
+    ($($s:expr => $v:expr),+ $(,)?) => {{
+        use $crate::traits::RxWrite;
+        $crate::reactivity::batch(|| {
+            $(($s).set($v);)+
+        })
+    }};
+}
@@ -2,6 +2,9 @@ mod arithmetic;
 mod compare;
 mod transform;
 
+#[cfg(test)]
+mod tests;
+
 pub use arithmetic::*;
 pub use compare::*;
 pub use transform::*;
@@ -0,0 +1,53 @@
+use super::*;
+use crate::reactivity::{Effect, RwSignal};
+use crate::traits::{RxGet, RxWrite};
+use silex_reactivity::create_scope;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn test_zip_tracks_both_inputs() {
+    create_scope(|| {
+        let a = RwSignal::new(1);
+        let b = RwSignal::new("x".to_string());
+        let zipped = zip(a, b);
+
+        assert_eq!(zipped.get(), (1, "x".to_string()));
+
+        a.set(2);
+        assert_eq!(zipped.get(), (2, "x".to_string()));
+
+        b.set("y".to_string());
+        assert_eq!(zipped.get(), (2, "y".to_string()));
+    });
+}
+
+#[test]
+fn test_zip_skips_equal_values() {
+    create_scope(|| {
+        let a = RwSignal::new(1);
+        let b = RwSignal::new(true);
+        let zipped = zip(a, b);
+
+        let runs = Rc::new(Cell::new(0));
+        Effect::new({
+            let runs = runs.clone();
+            move |_| {
+                let (_count, _flag) = zipped.get();
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 1);
+
+        // 值未改变：输入信号仍会通知，但元组相等，下游不会重新执行
+        a.set(1);
+        b.set(true);
+        assert_eq!(runs.get(), 1);
+
+        a.set(5);
+        assert_eq!(runs.get(), 2);
+
+        b.set(false);
+        assert_eq!(runs.get(), 3);
+    });
+}
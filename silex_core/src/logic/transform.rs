@@ -77,3 +77,17 @@ where
         Memo::new(move |_| this.with(Clone::clone))
     }
 }
+
+/// 将两个信号组合为一个元组记忆化信号。
+///
+/// 结果同时追踪 `a` 与 `b`，任一输入变化时重新计算；
+/// 新元组与旧值相等 (`PartialEq`) 时不会通知下游。
+pub fn zip<A, B>(a: A, b: B) -> Memo<(A::Value, B::Value)>
+where
+    A: RxRead + Clone + 'static,
+    B: RxRead + Clone + 'static,
+    A::Value: Clone + PartialEq + Sized + 'static,
+    B::Value: Clone + PartialEq + Sized + 'static,
+{
+    Memo::new(move |_| (a.with(Clone::clone), b.with(Clone::clone)))
+}
@@ -2,6 +2,9 @@ use std::marker::PhantomData;
 
 pub use silex_reactivity::NodeId;
 
+#[cfg(test)]
+mod tests;
+
 /// `NodeRef` 用于获取对底层 DOM 节点的直接引用。
 ///
 /// 此类型使用 `NodeId` 句柄引用存储在响应式运行时中的元素，
@@ -75,6 +78,21 @@ impl<T: Clone + 'static> NodeRef<T> {
         silex_reactivity::set_node_ref(self.id, node);
     }
 
+    /// 注册在节点加载时执行一次的回调，可多次调用注册多个回调。
+    ///
+    /// 适合在元素挂载时测量尺寸、初始化第三方组件等。如果节点已经加载，回调立即执行。
+    pub fn on_load(&self, f: impl FnOnce(&T) + 'static) {
+        if let Some(node) = self.get() {
+            f(&node);
+            return;
+        }
+        silex_reactivity::on_node_ref_load(self.id, move |node| {
+            if let Some(node) = node.downcast_ref::<T>() {
+                f(node);
+            }
+        });
+    }
+
     /// 返回此 `NodeRef` 的底层 `NodeId`。
     pub fn id(&self) -> NodeId {
         self.id
@@ -0,0 +1,23 @@
+use super::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_on_load_runs_with_element() {
+    let node = NodeRef::<String>::new();
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    for tag in ["measure", "widget"] {
+        let seen = seen.clone();
+        node.on_load(move |el| seen.borrow_mut().push(format!("{tag}:{el}")));
+    }
+    assert!(seen.borrow().is_empty());
+
+    node.load("div".to_string());
+    assert_eq!(*seen.borrow(), vec!["measure:div", "widget:div"]);
+
+    // 已加载时注册的回调立即执行
+    let seen_late = seen.clone();
+    node.on_load(move |el| seen_late.borrow_mut().push(format!("late:{el}")));
+    assert_eq!(seen.borrow().last().unwrap(), "late:div");
+    assert_eq!(seen.borrow().len(), 3);
+}
@@ -1,40 +1,119 @@
 pub use silex_reactivity::NodeId;
+pub use silex_reactivity::{Scheduler, flush};
 pub use silex_reactivity::{
-    batch, create_scope, dispose, is_signal_valid, on_cleanup, provide_context, store_value,
-    use_context,
+    batch, create_scope, current_owner, dispose, is_signal_valid, on_cleanup, provide_context,
+    store_value, use_context, with_owner,
 };
+#[cfg(feature = "serde")]
+pub use silex_reactivity::{hydrate_scope, serialize_scope};
 
 mod effect;
 mod memo;
+mod mount;
 mod mutation;
 mod resource;
 mod signal;
 mod slice;
 mod stored_value;
+mod stream;
+mod trigger;
 
+pub mod debug;
 pub mod dispatch;
 
 pub use effect::*;
 pub use memo::*;
+pub use mount::*;
 pub use mutation::*;
 pub use resource::*;
 pub use signal::*;
 pub use slice::*;
 pub use stored_value::*;
+pub use stream::*;
+pub use trigger::*;
+
+// --- Scheduler ---
+
+/// 设置信号写入后运行 Effect 的时机。
+///
+/// [`Scheduler::Microtask`] 将同一 tick 内的多次写入合并，在 `queueMicrotask` 中统一运行一次；
+/// `batch` 结束与 [`flush`] 仍同步排空。
+pub fn set_scheduler(scheduler: Scheduler) {
+    silex_reactivity::set_microtask_queue(queue_microtask);
+    silex_reactivity::set_scheduler(scheduler);
+}
+
+fn queue_microtask(task: Box<dyn FnOnce()>) {
+    use wasm_bindgen::JsCast;
+    match web_sys::window() {
+        Some(window) => window
+            .queue_microtask(&wasm_bindgen::closure::Closure::once_into_js(task).unchecked_into()),
+        None => task(),
+    }
+}
 
 // --- Context ---
 
+/// 读取上下文，未提供时返回 [`SilexError::Reactivity`](crate::SilexError::Reactivity)
+///
+/// 上下文以类型为键存储 (见 [`use_context`])，错误只可能表示祖先作用域中未提供该类型。
+#[track_caller]
+pub fn try_expect_context<T: Clone + 'static>() -> crate::SilexResult<T> {
+    use_context::<T>()
+        .ok_or_else(|| crate::SilexError::Reactivity(missing_context_message::<T>(None)))
+}
+
+/// 读取上下文，未提供时 panic
+///
+/// debug 构建下 panic 信息包含调用位置及当前所有者节点的定义位置。
+#[track_caller]
 pub fn expect_context<T: Clone + 'static>() -> T {
+    expect_context_or_panic(None)
+}
+
+/// 与 [`expect_context`] 相同，但以 `msg` 说明缺失的原因，例如
+/// `expect_context_msg::<UserStore>("UserStore must be provided by App")`
+#[track_caller]
+pub fn expect_context_msg<T: Clone + 'static>(msg: &str) -> T {
+    expect_context_or_panic(Some(msg))
+}
+
+#[track_caller]
+fn expect_context_or_panic<T: Clone + 'static>(hint: Option<&str>) -> T {
     match use_context::<T>() {
         Some(v) => v,
         None => {
-            let type_name = std::any::type_name::<T>();
-            let msg = format!(
-                "Expected context `{}` but none found. Did you forget to wrap your component in a Provider?",
-                type_name
-            );
+            let msg = missing_context_message::<T>(hint);
             crate::log::console_error(&msg);
             panic!("{}", msg);
         }
     }
 }
+
+#[track_caller]
+fn missing_context_message<T: 'static>(hint: Option<&str>) -> String {
+    let type_name = std::any::type_name::<T>();
+    #[allow(unused_mut)]
+    let mut msg = match hint {
+        Some(hint) => format!("{hint} (context `{type_name}` not found)"),
+        None => format!(
+            "Expected context `{type_name}` but none found. Did you forget to wrap your component in a Provider?"
+        ),
+    };
+    #[cfg(debug_assertions)]
+    {
+        msg.push_str(&format!(
+            "\n  requested at {}",
+            std::panic::Location::caller()
+        ));
+        if let Some(defined_at) =
+            silex_reactivity::current_owner().and_then(silex_reactivity::get_node_defined_at)
+        {
+            msg.push_str(&format!(", inside the node defined at {defined_at}"));
+        }
+    }
+    msg
+}
+
+#[cfg(test)]
+mod tests;
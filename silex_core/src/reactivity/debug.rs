@@ -0,0 +1,26 @@
+//! 响应式运行时的调试诊断，仅在 debug 构建中收集数据，release 构建中为空操作。
+
+use silex_reactivity::NodeId;
+
+#[cfg(test)]
+mod tests;
+
+/// 各 Effect 的累计耗时 (ms) 与运行次数，按累计耗时降序排列。
+///
+/// 用于定位占用主线程的 Effect，可配合 `silex_reactivity::get_debug_label` 查看节点标签。
+/// 计时需要先调用 [`use_performance_clock`] (`mount_to_body` 会自动调用)，否则只统计运行次数。
+pub fn effect_timings() -> Vec<(NodeId, f64, u32)> {
+    silex_reactivity::effect_timings()
+}
+
+/// 使用 `performance.now()` 为 Effect 计时
+pub fn use_performance_clock() {
+    silex_reactivity::set_effect_clock(performance_now);
+}
+
+fn performance_now() -> f64 {
+    web_sys::window()
+        .and_then(|w| w.performance())
+        .map(|p| p.now())
+        .unwrap_or_default()
+}
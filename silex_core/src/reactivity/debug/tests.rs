@@ -0,0 +1,56 @@
+use super::*;
+use crate::reactivity::{Effect, RwSignal};
+use crate::traits::{RxGet, RxWrite};
+use silex_reactivity::create_scope;
+use std::cell::Cell;
+
+thread_local! {
+    static NOW: Cell<f64> = const { Cell::new(0.0) };
+}
+
+fn mock_now() -> f64 {
+    // 每次读取前进 2ms，使每次运行计时为 2ms
+    NOW.with(|now| {
+        now.set(now.get() + 2.0);
+        now.get()
+    })
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn test_effect_timings_count_runs() {
+    silex_reactivity::set_effect_clock(mock_now);
+    create_scope(|| {
+        let count = RwSignal::new(0);
+        let effect = Effect::new(move |_| {
+            count.get();
+        });
+        for i in 1..=4 {
+            count.set(i);
+        }
+
+        let timings = effect_timings();
+        let (_, total, runs) = timings
+            .iter()
+            .find(|(id, _, _)| *id == effect.id)
+            .copied()
+            .expect("effect timing recorded");
+        assert_eq!(runs, 5);
+        assert_eq!(total, 10.0);
+    });
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn test_effect_timings_removed_on_dispose() {
+    let scope = create_scope(|| {
+        Effect::new(|_| {});
+    });
+    let [(effect, _, runs)] = effect_timings()[..] else {
+        panic!("one effect timing recorded");
+    };
+    assert_eq!(runs, 1);
+
+    silex_reactivity::dispose(scope);
+    assert!(effect_timings().iter().all(|(id, _, _)| *id != effect));
+}
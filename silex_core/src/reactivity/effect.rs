@@ -3,6 +3,9 @@ use std::rc::Rc;
 
 use silex_reactivity::NodeId;
 
+#[cfg(test)]
+mod tests;
+
 // --- Effect ---
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -55,3 +58,52 @@ impl Effect {
         })
     }
 }
+
+/// 仅在 `key_fn` 计算出的 key 变化时执行 `body`
+///
+/// 只追踪 `key_fn` 中的读取：依赖变化但 key 与上次相等时跳过 `body`。
+/// `body` 在非追踪上下文中执行，创建时以初始 key 执行一次。
+///
+/// ```rust,ignore
+/// // 仅在用户切换时重新加载，忽略用户名等其他字段的修改
+/// effect_on_key(move || user.with(|u| u.id), move |id| load_profile(*id));
+/// ```
+pub fn effect_on_key<K, KF, B>(key_fn: KF, body: B) -> Effect
+where
+    K: PartialEq + 'static,
+    KF: Fn() -> K + 'static,
+    B: Fn(&K) + 'static,
+{
+    Effect::new(move |prev: Option<K>| {
+        let key = key_fn();
+        if prev.as_ref() != Some(&key) {
+            silex_reactivity::untrack(|| body(&key));
+        }
+        key
+    })
+}
+
+/// 惰性侦听：追踪 `deps`，但跳过创建时的首次执行，仅在依赖变化后调用 `f(新值, 旧值)`
+///
+/// 类似 Vue `watch` 的 `immediate: false`。创建时仍会执行 `deps` 以建立订阅；
+/// `f` 在非追踪上下文中执行。
+///
+/// ```rust,ignore
+/// watch_lazy(move || query.get(), move |query, prev| log!("{prev:?} -> {query}"));
+/// ```
+pub fn watch_lazy<D, DF, F>(deps: DF, f: F) -> Effect
+where
+    D: Clone + 'static,
+    DF: Fn() -> D + 'static,
+    F: Fn(D, Option<D>) + 'static,
+{
+    Effect::new(move |prev: Option<D>| {
+        let value = deps();
+        // prev 为 None 即首次执行：只建立订阅
+        if prev.is_some() {
+            let current = value.clone();
+            silex_reactivity::untrack(|| f(current, prev));
+        }
+        value
+    })
+}
@@ -0,0 +1,60 @@
+use super::*;
+use crate::reactivity::RwSignal;
+use crate::traits::{RxGet, RxRead, RxWrite};
+use silex_reactivity::create_scope;
+
+#[derive(Clone)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[test]
+fn test_effect_on_key_skips_when_key_is_unchanged() {
+    create_scope(|| {
+        let user = RwSignal::new(User {
+            id: 1,
+            name: "Ann".into(),
+        });
+        let other = RwSignal::new(0);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        effect_on_key(move || user.with(|u| u.id), {
+            let seen = seen.clone();
+            move |id: &u32| {
+                // body 中的读取不被追踪
+                let _ = other.get();
+                seen.borrow_mut().push(*id);
+            }
+        });
+        assert_eq!(*seen.borrow(), vec![1]);
+
+        // 依赖变化，但 key 不变
+        user.update(|u| u.name = "Bea".into());
+        assert_eq!(*seen.borrow(), vec![1]);
+
+        other.set(1);
+        assert_eq!(*seen.borrow(), vec![1]);
+
+        user.update(|u| u.id = 2);
+        assert_eq!(*seen.borrow(), vec![1, 2]);
+    });
+}
+
+#[test]
+fn test_watch_lazy_skips_setup_and_passes_previous_value() {
+    create_scope(|| {
+        let count = RwSignal::new(1);
+        let calls = Rc::new(RefCell::new(Vec::new()));
+
+        watch_lazy(move || count.get(), {
+            let calls = calls.clone();
+            move |value, prev| calls.borrow_mut().push((value, prev))
+        });
+        assert!(calls.borrow().is_empty());
+
+        count.set(2);
+        count.set(5);
+        assert_eq!(*calls.borrow(), vec![(2, Some(1)), (5, Some(2))]);
+    });
+}
@@ -0,0 +1,95 @@
+use crate::reactivity::{RwSignal, Signal, StoredValue, provide_context, use_context};
+use crate::traits::{RxGet, RxWrite};
+
+#[cfg(test)]
+mod tests;
+
+type MountCallbacks = Vec<Box<dyn FnOnce()>>;
+
+// --- MountGate 挂载闸门 ---
+
+/// 挂载闸门：记录某个根（或动态区域）是否已完成初次挂载。
+///
+/// `Effect` 在渲染期间立即执行，此时节点尚未插入文档；
+/// 通过 [`on_mount`] 注册的回调则会被暂存，直到所属根调用 [`MountGate::mark_mounted`] 后才执行。
+#[derive(Clone, Copy)]
+pub struct MountGate {
+    mounted: RwSignal<bool>,
+    pending: StoredValue<MountCallbacks>,
+}
+
+impl MountGate {
+    pub fn new() -> Self {
+        Self {
+            mounted: RwSignal::new(false),
+            pending: StoredValue::new(Vec::new()),
+        }
+    }
+
+    /// 创建一个新的闸门并将其提供给当前作用域的所有子节点。
+    pub fn provide() -> Self {
+        let gate = Self::new();
+        provide_context(gate);
+        gate
+    }
+
+    pub fn is_mounted(&self) -> Signal<bool> {
+        self.mounted.into()
+    }
+
+    /// 标记挂载完成，并依次执行所有暂存的 `on_mount` 回调。重复调用无副作用。
+    pub fn mark_mounted(&self) {
+        if self.mounted.get_untracked() {
+            return;
+        }
+        self.mounted.set(true);
+
+        let callbacks = self.pending.try_update_untracked(std::mem::take);
+        for f in callbacks.into_iter().flatten() {
+            silex_reactivity::untrack(f);
+        }
+    }
+
+    /// 在该闸门挂载完成后执行 `f`；若已挂载则立即执行。
+    pub fn on_mount(&self, f: impl FnOnce() + 'static) {
+        if self.mounted.get_untracked() {
+            silex_reactivity::untrack(f);
+        } else {
+            self.pending.update_untracked(|list| list.push(Box::new(f)));
+        }
+    }
+}
+
+impl Default for MountGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 以单个批处理执行顶层挂载：构建视图期间由信号写入触发的 Effect 会被暂缓，
+/// 整棵树构建完成后统一刷新一次。
+///
+/// 新建的 Effect 仍会立即完成首次运行；嵌套调用会并入最外层的批处理。
+pub fn mount_batch<R>(f: impl FnOnce() -> R) -> R {
+    silex_reactivity::batch(f)
+}
+
+/// 当前根是否已完成挂载。
+///
+/// 若不存在挂载闸门（例如服务端渲染或未经 `mount_to_body` 挂载的视图），始终返回 `false`。
+pub fn is_mounted() -> Signal<bool> {
+    match use_context::<MountGate>() {
+        Some(gate) => gate.is_mounted(),
+        None => Signal::from(false),
+    }
+}
+
+/// 在所属根完成初次挂载（节点已插入文档）后执行 `f`。
+///
+/// 与 `Effect` 不同，`f` 不会在构建视图期间运行，也不会追踪任何依赖。
+/// 若根已挂载，则立即执行；若不存在挂载闸门，`f` 永远不会执行。
+pub fn on_mount(f: impl FnOnce() + 'static) {
+    if let Some(gate) = use_context::<MountGate>() {
+        gate.on_mount(f);
+    }
+}
@@ -0,0 +1,104 @@
+use super::*;
+use crate::reactivity::Effect;
+use silex_reactivity::create_scope;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_on_mount_runs_after_attach() {
+    let log = Rc::new(RefCell::new(Vec::<&'static str>::new()));
+    let attached = Rc::new(RefCell::new(false));
+
+    create_scope({
+        let log = log.clone();
+        let attached = attached.clone();
+        move || {
+            let gate = MountGate::provide();
+
+            // 构建子树：Effect 立即运行，on_mount 被暂存
+            create_scope({
+                let log = log.clone();
+                let attached = attached.clone();
+                move || {
+                    let log_effect = log.clone();
+                    Effect::new(move |_| log_effect.borrow_mut().push("effect"));
+
+                    on_mount(move || {
+                        assert!(*attached.borrow(), "on_mount ran before attach");
+                        log.borrow_mut().push("mount");
+                    });
+                }
+            });
+
+            assert_eq!(*log.borrow(), vec!["effect"]);
+            assert!(!is_mounted().get_untracked());
+
+            // 模拟插入文档
+            *attached.borrow_mut() = true;
+            gate.mark_mounted();
+
+            assert!(is_mounted().get_untracked());
+        }
+    });
+
+    assert_eq!(*log.borrow(), vec!["effect", "mount"]);
+}
+
+#[test]
+fn test_on_mount_after_mounted_runs_immediately() {
+    create_scope(|| {
+        let gate = MountGate::provide();
+        gate.mark_mounted();
+        gate.mark_mounted();
+
+        let count = Rc::new(RefCell::new(0));
+        let c = count.clone();
+        on_mount(move || *c.borrow_mut() += 1);
+        assert_eq!(*count.borrow(), 1);
+    });
+}
+
+#[test]
+fn test_on_mount_without_gate_never_runs() {
+    create_scope(|| {
+        let ran = Rc::new(RefCell::new(false));
+        let r = ran.clone();
+        on_mount(move || *r.borrow_mut() = true);
+
+        assert!(!*ran.borrow());
+        assert!(!is_mounted().get_untracked());
+    });
+}
+
+/// 模拟一个组件：挂载时登记自身，并递归挂载子组件
+fn mount_component(depth: usize, registered: crate::reactivity::RwSignal<usize>) {
+    create_scope(move || {
+        Effect::new(move |_| registered.update(|n| *n += 1));
+        if depth > 0 {
+            mount_component(depth - 1, registered);
+            mount_component(depth - 1, registered);
+        }
+    });
+}
+
+#[test]
+fn test_mount_batch_flushes_once() {
+    create_scope(|| {
+        let registered = crate::reactivity::RwSignal::new(0usize);
+        let flushes = Rc::new(RefCell::new(0));
+        let f = flushes.clone();
+        Effect::new(move |_| {
+            registered.get();
+            *f.borrow_mut() += 1;
+        });
+        *flushes.borrow_mut() = 0;
+
+        mount_batch(|| mount_component(3, registered));
+        assert_eq!(registered.get_untracked(), 15);
+        assert_eq!(*flushes.borrow(), 1, "nested mounts flush once");
+
+        // 不使用挂载批处理时，每个组件都会触发一次刷新
+        mount_component(1, registered);
+        assert_eq!(*flushes.borrow(), 4);
+    });
+}
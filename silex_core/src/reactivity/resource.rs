@@ -1,12 +1,17 @@
 use std::cell::Cell;
 use std::future::Future;
 use std::panic::Location;
+use std::pin::Pin;
 use std::rc::Rc;
+use std::time::Duration;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
 
 use silex_reactivity::{on_cleanup, use_context};
 
 use crate::SilexError;
-use crate::reactivity::Memo;
+use crate::reactivity::{Memo, StoredValue};
 use crate::traits::*;
 use crate::traits::{RxCloneData, RxData, RxError};
 use crate::{Rx, RxValueKind};
@@ -48,12 +53,21 @@ impl<T, E> ResourceState<T, E> {
     pub fn is_loading(&self) -> bool {
         matches!(self, Self::Loading | Self::Reloading(_))
     }
+
+    pub fn as_error(&self) -> Option<&E> {
+        match self {
+            Self::Error(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 pub struct Resource<T, E = SilexError> {
     pub state: ReadSignal<ResourceState<T, E>>,
     set_state: WriteSignal<ResourceState<T, E>>,
     trigger: WriteSignal<usize>,
+    /// 重新获取时是否保留已有数据 (`Reloading`)，否则回到 `Loading`
+    keep_previous: StoredValue<bool>,
 }
 
 impl<T, E> Clone for Resource<T, E> {
@@ -85,8 +99,65 @@ where
     }
 }
 
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// 停止轮询的清理函数
+type StopPolling = Box<dyn FnOnce()>;
+
 impl<T: RxCloneData, E: RxError> Resource<T, E> {
     pub fn new<S, Fetcher, R>(source: R, fetcher: Fetcher) -> Self
+    where
+        R: RxGet<Value = S> + 'static,
+        S: PartialEq + RxCloneData,
+        Fetcher: ResourceFetcher<S, Data = T, Error = E> + RxData,
+    {
+        Self::with_spawner(source, fetcher, wasm_bindgen_futures::spawn_local)
+    }
+
+    /// 与 [`Resource::new`] 相同，但每隔 `interval` 自动重新获取。
+    ///
+    /// - 页面隐藏 (`document.hidden`) 期间暂停轮询，重新可见或窗口获得焦点时立即补一次获取。
+    /// - 上一次获取仍在进行时跳过本次轮询，避免请求堆积。
+    /// - 所属作用域销毁时清除定时器与监听器。
+    pub fn polled<S, Fetcher, R>(source: R, fetcher: Fetcher, interval: Duration) -> Self
+    where
+        R: RxGet<Value = S> + 'static,
+        S: PartialEq + RxCloneData,
+        Fetcher: ResourceFetcher<S, Data = T, Error = E> + RxData,
+    {
+        let resource = Self::new(source, fetcher);
+        resource.poll_with(move |tick| browser_ticker(tick, interval));
+        resource
+    }
+
+    /// 由 `start` 驱动轮询：`start` 接收每次轮询要调用的 tick，返回停止函数
+    fn poll_with(&self, start: impl FnOnce(Rc<dyn Fn()>) -> StopPolling) {
+        let resource = *self;
+        let alive = Rc::new(Cell::new(true));
+        let tick: Rc<dyn Fn()> = Rc::new({
+            let alive = alive.clone();
+            move || {
+                let in_flight = resource
+                    .state
+                    .try_with_untracked(|s| s.is_loading())
+                    .unwrap_or(true);
+                if alive.get() && !in_flight {
+                    resource.refetch();
+                }
+            }
+        });
+        let stop = start(tick);
+        on_cleanup(move || {
+            alive.set(false);
+            stop();
+        });
+    }
+
+    fn with_spawner<S, Fetcher, R>(
+        source: R,
+        fetcher: Fetcher,
+        spawn: impl Fn(LocalFuture) + 'static,
+    ) -> Self
     where
         R: RxGet<Value = S> + 'static,
         S: PartialEq + RxCloneData,
@@ -101,6 +172,7 @@ impl<T: RxCloneData, E: RxError> Resource<T, E> {
         on_cleanup(move || alive_clone.set(false));
 
         let request_id = Rc::new(Cell::new(0usize));
+        let keep_previous = StoredValue::new(true);
 
         Effect::new(move |_| {
             let source_val = source.get();
@@ -112,10 +184,11 @@ impl<T: RxCloneData, E: RxError> Resource<T, E> {
             }
 
             // State transition logic:
+            let keep = keep_previous.get_untracked();
             set_state.update(|s| {
                 *s = match &*s {
                     // If we already have data (Ready or Reloading), switch to Reloading to preserve data
-                    ResourceState::Ready(data) | ResourceState::Reloading(data) => {
+                    ResourceState::Ready(data) | ResourceState::Reloading(data) if keep => {
                         ResourceState::Reloading(data.clone())
                     }
                     // Otherwise (Idle, Loading, Error), switch to Loading
@@ -131,7 +204,7 @@ impl<T: RxCloneData, E: RxError> Resource<T, E> {
             let alive = alive.clone();
             let request_id = request_id.clone();
 
-            wasm_bindgen_futures::spawn_local(async move {
+            spawn(Box::pin(async move {
                 let res = fut.await;
 
                 if alive.get() && request_id.get() == current_id {
@@ -146,16 +219,36 @@ impl<T: RxCloneData, E: RxError> Resource<T, E> {
                 if let Some(ctx) = &suspense_ctx {
                     ctx.decrement();
                 }
-            });
+            }));
         });
 
         Resource {
             state,
             set_state,
             trigger: set_trigger,
+            keep_previous,
         }
     }
 
+    /// 设置重新获取期间是否保留上一次的数据 (默认 `true`)
+    ///
+    /// 保留时状态为 `Reloading(data)`，[`value`](Self::value) 仍返回旧数据；
+    /// 否则状态回到 `Loading`。
+    pub fn keep_previous(self, keep: bool) -> Self {
+        self.keep_previous.set_untracked(keep);
+        self
+    }
+
+    /// 当前状态 (追踪)，用于分别渲染加载、错误与数据视图
+    pub fn state(&self) -> ResourceState<T, E> {
+        self.state.get()
+    }
+
+    /// 最近一次获取失败的错误 (追踪)，状态不是 `Error` 时返回 `None`
+    pub fn error(&self) -> Option<E> {
+        self.state.with(|s| s.as_error().cloned())
+    }
+
     pub fn refetch(&self) {
         self.trigger.update(|n| *n = n.wrapping_add(1));
     }
@@ -214,6 +307,60 @@ impl<T: RxCloneData, E: RxError> Resource<T, E> {
     }
 }
 
+/// 基于 `setInterval` 的轮询：页面隐藏时跳过，重新可见或窗口获得焦点时立即触发
+fn browser_ticker(tick: Rc<dyn Fn()>, interval: Duration) -> StopPolling {
+    let Some(window) = web_sys::window() else {
+        return Box::new(|| {});
+    };
+    let document = window.document();
+    let visible = move || !document.as_ref().is_some_and(|d| d.hidden());
+
+    let on_tick = Closure::<dyn Fn()>::new({
+        let tick = tick.clone();
+        let visible = visible.clone();
+        move || {
+            if visible() {
+                tick();
+            }
+        }
+    });
+    let handle = window
+        .set_interval_with_callback_and_timeout_and_arguments_0(
+            on_tick.as_ref().unchecked_ref(),
+            interval.as_millis().min(i32::MAX as u128) as i32,
+        )
+        .ok();
+
+    let on_resume = Closure::<dyn Fn()>::new(move || {
+        if visible() {
+            tick();
+        }
+    });
+    let document = window.document();
+    if let Some(document) = &document {
+        let _ = document.add_event_listener_with_callback(
+            "visibilitychange",
+            on_resume.as_ref().unchecked_ref(),
+        );
+    }
+    let _ = window.add_event_listener_with_callback("focus", on_resume.as_ref().unchecked_ref());
+
+    Box::new(move || {
+        if let Some(handle) = handle {
+            window.clear_interval_with_handle(handle);
+        }
+        if let Some(document) = &document {
+            let _ = document.remove_event_listener_with_callback(
+                "visibilitychange",
+                on_resume.as_ref().unchecked_ref(),
+            );
+        }
+        let _ =
+            window.remove_event_listener_with_callback("focus", on_resume.as_ref().unchecked_ref());
+        drop(on_tick);
+    })
+}
+
 impl<T: RxCloneData, E: RxError> RxValue for Resource<T, E> {
     type Value = Option<T>;
 }
@@ -357,3 +504,6 @@ impl SuspenseContext {
 pub fn use_suspense_context() -> Option<SuspenseContext> {
     use_context::<SuspenseContext>()
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,120 @@
+use super::*;
+use crate::reactivity::RwSignal;
+use silex_reactivity::{create_scope, dispose};
+use std::cell::RefCell;
+use std::task::{Context, Waker};
+
+type Tasks = Rc<RefCell<Vec<LocalFuture>>>;
+
+fn run_tasks(tasks: &Tasks) {
+    let mut cx = Context::from_waker(Waker::noop());
+    tasks
+        .borrow_mut()
+        .retain_mut(|fut| fut.as_mut().poll(&mut cx).is_pending());
+}
+
+#[test]
+fn test_polled_resource_refetches_until_scope_disposes() {
+    let calls = Rc::new(Cell::new(0));
+    let tasks: Tasks = Rc::default();
+    let ticker = Rc::new(RefCell::new(None::<Rc<dyn Fn()>>));
+    let stopped = Rc::new(Cell::new(false));
+    let tick = || (ticker.borrow().clone().unwrap())();
+
+    let scope = create_scope(|| {
+        let resource = Resource::<u32, SilexError>::with_spawner(
+            RwSignal::new(0u32),
+            {
+                let calls = calls.clone();
+                move |_: u32| {
+                    calls.set(calls.get() + 1);
+                    let n = calls.get();
+                    async move { Ok(n) }
+                }
+            },
+            {
+                let tasks = tasks.clone();
+                move |fut| tasks.borrow_mut().push(fut)
+            },
+        );
+        resource.poll_with({
+            let ticker = ticker.clone();
+            let stopped = stopped.clone();
+            move |tick| {
+                *ticker.borrow_mut() = Some(tick);
+                Box::new(move || stopped.set(true))
+            }
+        });
+    });
+    assert_eq!(calls.get(), 1);
+
+    // 首次获取尚未完成，跳过本次轮询
+    tick();
+    assert_eq!(calls.get(), 1);
+
+    run_tasks(&tasks);
+    tick();
+    run_tasks(&tasks);
+    tick();
+    run_tasks(&tasks);
+    assert_eq!(calls.get(), 3);
+
+    dispose(scope);
+    assert!(stopped.get(), "interval cleared on cleanup");
+    tick();
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn test_error_state_and_refetch_recovers() {
+    let broken = Rc::new(Cell::new(true));
+    let tasks: Tasks = Rc::default();
+
+    create_scope(|| {
+        let resource = Resource::<u32, String>::with_spawner(
+            RwSignal::new(1u32),
+            {
+                let broken = broken.clone();
+                move |n: u32| {
+                    let result = if broken.get() {
+                        Err("offline".to_string())
+                    } else {
+                        Ok(n * 10)
+                    };
+                    async move { result }
+                }
+            },
+            {
+                let tasks = tasks.clone();
+                move |fut| tasks.borrow_mut().push(fut)
+            },
+        );
+        assert_eq!(resource.state(), ResourceState::Loading);
+
+        run_tasks(&tasks);
+        assert_eq!(
+            resource.state(),
+            ResourceState::Error("offline".to_string())
+        );
+        assert_eq!(resource.error().as_deref(), Some("offline"));
+
+        broken.set(false);
+        resource.refetch();
+        assert_eq!(resource.state(), ResourceState::Loading);
+        run_tasks(&tasks);
+        assert_eq!(resource.state(), ResourceState::Ready(10));
+        assert_eq!(resource.error(), None);
+
+        // 默认保留上一次的数据，关闭后重新获取回到 Loading
+        resource.refetch();
+        assert_eq!(resource.state(), ResourceState::Reloading(10));
+        assert_eq!(resource.value(), Some(10));
+        run_tasks(&tasks);
+
+        let resource = resource.keep_previous(false);
+        resource.refetch();
+        assert_eq!(resource.state(), ResourceState::Loading);
+        run_tasks(&tasks);
+        assert_eq!(resource.state(), ResourceState::Ready(10));
+    });
+}
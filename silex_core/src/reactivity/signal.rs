@@ -47,6 +47,28 @@ impl<T: 'static> Signal<T> {
     }
 }
 
+/// 以引用读取信号 `id` 的值并追踪依赖，`f` 直接拿到运行时中存储的 `&T`，没有克隆或中间分配。
+///
+/// 这是性能敏感代码 (如大型 `Vec` 信号) 的底层出口：`f` 执行期间持有运行时存储的借用，
+/// 不能在其中写入信号或创建响应式节点。信号已销毁或存储的值不是 `T` 时返回
+/// [`SilexError::Reactivity`](crate::SilexError::Reactivity)，而非静默的 `None`。
+pub fn with_signal_ref<T: 'static, R>(
+    id: NodeId,
+    f: impl FnOnce(&T) -> R,
+) -> crate::SilexResult<R> {
+    silex_reactivity::try_with_signal(id, f).ok_or_else(|| {
+        let reason = if silex_reactivity::is_signal_valid(id) {
+            format!(
+                "does not hold a value of type `{}`",
+                std::any::type_name::<T>()
+            )
+        } else {
+            "has been disposed".to_string()
+        };
+        crate::SilexError::Reactivity(format!("signal {:?} {}", id, reason))
+    })
+}
+
 impl<T: RxData> std::fmt::Debug for Signal<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -370,6 +392,58 @@ impl<T: RxData> From<RwSignal<T>> for Signal<T> {
     }
 }
 
+/// 元组信号拆分出的某一分量的读写两半
+pub type SignalHalves<T> = (ReadSignal<T>, WriteSignal<T>);
+
+/// 将元组信号拆分为两个独立的读写分量，适用于成对的表单字段等场景。
+///
+/// 两个分量与元组双向同步：写入任一分量会更新元组并通知其订阅者；
+/// 元组被整体写入时，只有值发生变化的分量会通知。
+/// 读取某个分量只追踪该分量，另一分量的变化不会引起重新执行。
+///
+/// ```rust,ignore
+/// let range = RwSignal::new((0, 100));
+/// let ((min, set_min), (max, set_max)) = split_signal(range);
+/// set_max.set(50); // range.get() == (0, 50)
+/// ```
+pub fn split_signal<A, B>(rw: RwSignal<(A, B)>) -> (SignalHalves<A>, SignalHalves<B>)
+where
+    A: Clone + PartialEq + 'static,
+    B: Clone + PartialEq + 'static,
+{
+    let (a, b) = rw.get_untracked();
+    let a = RwSignal::new(a);
+    let b = RwSignal::new(b);
+
+    // 先克隆再写入：写入会同步触发另一方向的同步，不能持有读守卫
+    // 元组 -> 分量
+    crate::reactivity::Effect::new(move |_| {
+        let (next_a, next_b) = rw.get();
+        a.maybe_update(|a| replace_if_changed(a, next_a));
+        b.maybe_update(|b| replace_if_changed(b, next_b));
+    });
+    // 分量 -> 元组：值相同时不写回，避免循环通知
+    crate::reactivity::Effect::new(move |_| {
+        let next = a.get();
+        rw.maybe_update(|(a, _)| replace_if_changed(a, next));
+    });
+    crate::reactivity::Effect::new(move |_| {
+        let next = b.get();
+        rw.maybe_update(|(_, b)| replace_if_changed(b, next));
+    });
+
+    (a.split(), b.split())
+}
+
+fn replace_if_changed<T: PartialEq>(slot: &mut T, next: T) -> bool {
+    if *slot != next {
+        *slot = next;
+        true
+    } else {
+        false
+    }
+}
+
 // 手动实现了 RxInternal，移除自动委托以避免冲突
 crate::impl_rx_delegate!(ReadSignal, SignalID, false);
 crate::impl_rx_delegate!(RwSignal, read, false);
@@ -2,8 +2,9 @@ use crate::prelude::Signal;
 use crate::reactivity::SignalSlice;
 use crate::traits::*;
 use silex_reactivity::{
-    NodeId, get_debug_label, get_node_defined_at, is_signal_valid, notify_signal, set_debug_label,
-    track_signal, try_update_signal_silent, untrack as untrack_scoped,
+    NodeId, enable_signal_history, get_debug_label, get_node_defined_at, is_signal_valid,
+    notify_signal, set_debug_label, track_signal, try_get_signal_previous,
+    try_update_signal_silent, untrack as untrack_scoped,
 };
 use std::marker::PhantomData;
 use std::panic::Location;
@@ -31,6 +32,24 @@ impl<T> ReadSignal<T> {
     }
 }
 
+impl<T: Clone + 'static> ReadSignal<T> {
+    /// 开启历史记录，使 [`previous`](Self::previous) 可用
+    ///
+    /// 开启后每次修改前都会克隆一次旧值，因此默认关闭。
+    pub fn with_history(self) -> Self {
+        enable_signal_history::<T>(self.id);
+        self
+    }
+
+    /// 最近一次修改前的值，会追踪该信号
+    ///
+    /// 未开启 [`with_history`](Self::with_history) 或尚未修改过时返回 `None`。
+    pub fn previous(&self) -> Option<T> {
+        track_signal(self.id);
+        try_get_signal_previous(self.id)
+    }
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! impl_signal_core_traits {
@@ -162,6 +181,27 @@ impl<T: RxData> RwSignal<T> {
         RwSignal { read, write }
     }
 
+    /// 创建可被 [`serialize_scope`](crate::reactivity::serialize_scope) 收集、
+    /// 由 [`hydrate_scope`](crate::reactivity::hydrate_scope) 恢复的信号
+    #[cfg(feature = "serde")]
+    #[track_caller]
+    pub fn new_serializable(value: T) -> Self
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let id = silex_reactivity::signal_serializable(value);
+        RwSignal {
+            read: ReadSignal {
+                id,
+                marker: PhantomData,
+            },
+            write: WriteSignal {
+                id,
+                marker: PhantomData,
+            },
+        }
+    }
+
     pub fn read_signal(&self) -> ReadSignal<T> {
         self.read
     }
@@ -192,6 +232,19 @@ impl<T: RxData> RwSignal<T> {
     }
 }
 
+impl<T: Clone + 'static> RwSignal<T> {
+    /// 开启历史记录，见 [`ReadSignal::with_history`]
+    pub fn with_history(self) -> Self {
+        self.read.with_history();
+        self
+    }
+
+    /// 最近一次修改前的值，见 [`ReadSignal::previous`]
+    pub fn previous(&self) -> Option<T> {
+        self.read.previous()
+    }
+}
+
 impl<T: 'static> RxWrite for RwSignal<T> {
     #[inline(always)]
     fn rx_try_update_untracked<URet>(
@@ -207,6 +260,55 @@ impl<T: 'static> RxWrite for RwSignal<T> {
     }
 }
 
+// --- WeakSignal ---
+
+/// 不保证信号存活的句柄，适合由定时器、事件监听器等长生命周期回调持有
+///
+/// 通过 [`upgrade`](Self::upgrade) 在信号仍存活时取回 [`RwSignal`]，信号所属作用域销毁后返回 `None`。
+/// `NodeId` 带有代数，信号槽位被新信号复用后旧句柄同样失效。
+pub struct WeakSignal<T> {
+    id: NodeId,
+    marker: PhantomData<T>,
+}
+
+impl_signal_core_traits!(WeakSignal);
+
+impl<T> WeakSignal<T> {
+    /// 信号仍存活时返回读写句柄
+    pub fn upgrade(&self) -> Option<RwSignal<T>> {
+        is_signal_valid(self.id).then_some(RwSignal {
+            read: ReadSignal {
+                id: self.id,
+                marker: PhantomData,
+            },
+            write: WriteSignal {
+                id: self.id,
+                marker: PhantomData,
+            },
+        })
+    }
+
+    pub fn is_alive(&self) -> bool {
+        is_signal_valid(self.id)
+    }
+}
+
+impl<T> RwSignal<T> {
+    /// 创建不保证信号存活的 [`WeakSignal`]
+    pub fn downgrade(&self) -> WeakSignal<T> {
+        WeakSignal {
+            id: self.write.id,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> From<RwSignal<T>> for WeakSignal<T> {
+    fn from(signal: RwSignal<T>) -> Self {
+        signal.downgrade()
+    }
+}
+
 // --- Global Functions ---
 
 pub fn untrack<T>(f: impl FnOnce() -> T) -> T {
@@ -1,6 +1,6 @@
 use super::*;
 use crate::reactivity::RwSignal;
-use crate::traits::{RxGet, RxWrite};
+use crate::traits::{RxGet, RxRead, RxWrite};
 use silex_reactivity::create_scope;
 use std::rc::Rc;
 
@@ -174,3 +174,171 @@ fn test_rw_signal_new() {
         assert_eq!(w, write);
     });
 }
+
+#[test]
+fn test_peek_does_not_subscribe() {
+    create_scope(|| {
+        let (tracked, set_tracked) = Signal::pair(0);
+        let (peeked, set_peeked) = Signal::pair(String::from("a"));
+        let runs = Rc::new(std::cell::RefCell::new(Vec::new()));
+
+        crate::reactivity::Effect::new({
+            let runs = runs.clone();
+            move |_| {
+                let value = tracked.get();
+                let len = peeked.peek_with(String::len);
+                runs.borrow_mut().push((value, peeked.peek(), len));
+            }
+        });
+
+        set_peeked.set("bcd".to_string());
+        assert_eq!(runs.borrow().len(), 1, "peeked signal is not a dependency");
+
+        set_tracked.set(1);
+        assert_eq!(
+            *runs.borrow(),
+            vec![(0, "a".to_string(), 1), (1, "bcd".to_string(), 3)]
+        );
+    });
+}
+
+#[test]
+fn test_split_signal_halves_sync_with_tuple() {
+    create_scope(|| {
+        let pair = RwSignal::new((1, String::from("a")));
+        let ((num, set_num), (text, set_text)) = split_signal(pair);
+
+        let observed = Rc::new(std::cell::RefCell::new(Vec::new()));
+        crate::reactivity::Effect::new({
+            let observed = observed.clone();
+            move |_| observed.borrow_mut().push(pair.get())
+        });
+        let num_runs = Rc::new(std::cell::Cell::new(0));
+        crate::reactivity::Effect::new({
+            let num_runs = num_runs.clone();
+            move |_| {
+                num.track();
+                num_runs.set(num_runs.get() + 1);
+            }
+        });
+
+        set_text.set("b".to_string());
+        assert_eq!(pair.get_untracked(), (1, "b".to_string()));
+        assert_eq!(
+            *observed.borrow(),
+            vec![(1, "a".to_string()), (1, "b".to_string())]
+        );
+        assert_eq!(num_runs.get(), 1, "the other half is not notified");
+
+        set_num.set(2);
+        pair.set((3, "b".to_string()));
+        assert_eq!(
+            (num.get_untracked(), text.get_untracked()),
+            (3, "b".to_string())
+        );
+        assert_eq!(num_runs.get(), 3);
+    });
+}
+
+#[test]
+fn test_set_all_runs_effects_once() {
+    use crate::reactivity::Effect;
+    use std::cell::Cell;
+
+    create_scope(|| {
+        let first = RwSignal::new(String::new());
+        let last = RwSignal::new(String::new());
+        let runs = Rc::new(Cell::new(0));
+        Effect::new({
+            let runs = runs.clone();
+            move |_| {
+                let _ = (first.get(), last.get());
+                runs.set(runs.get() + 1);
+            }
+        });
+
+        crate::set_all!(first => "Ada".to_string(), last => "Lovelace".to_string());
+        assert_eq!(runs.get(), 2);
+        assert_eq!(first.get_untracked(), "Ada");
+        assert_eq!(last.get_untracked(), "Lovelace");
+    });
+}
+
+#[test]
+fn test_signal_previous_value() {
+    create_scope(|| {
+        let plain = RwSignal::new(0);
+        plain.set(1);
+        assert_eq!(plain.previous(), None, "history is opt-in");
+
+        let count = RwSignal::new(1).with_history();
+        assert_eq!(count.previous(), None);
+
+        count.set(2);
+        count.set(3);
+        assert_eq!(count.previous(), Some(2));
+        assert_eq!(count.get_untracked(), 3);
+
+        count.update(|v| *v += 1);
+        assert_eq!(count.previous(), Some(3));
+    });
+}
+
+#[test]
+fn test_weak_signal_upgrade_after_dispose() {
+    let mut weak = None;
+    let scope = create_scope(|| {
+        let rw = RwSignal::new(1);
+        let handle = rw.downgrade();
+        handle.upgrade().unwrap().set(2);
+        assert_eq!(rw.get(), 2);
+        weak = Some((handle, rw.write.id));
+    });
+    let (weak, old_id) = weak.unwrap();
+    assert!(weak.is_alive());
+
+    silex_reactivity::dispose(scope);
+    assert!(weak.upgrade().is_none());
+
+    // 槽位被新信号复用后，旧句柄的代数不再匹配
+    create_scope(|| {
+        let reused = RwSignal::new(3);
+        assert_eq!(reused.write.id.index, old_id.index);
+        assert_ne!(reused.write.id.generation, old_id.generation);
+        assert!(weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn test_with_signal_ref_borrows_without_cloning() {
+    // 不实现 Clone：只能通过引用读取
+    struct Bytes(Vec<u8>);
+
+    create_scope(|| {
+        let data = vec![7u8; 1024];
+        let ptr = data.as_ptr();
+        let id = silex_reactivity::signal(Bytes(data));
+
+        let seen = with_signal_ref(id, |bytes: &Bytes| (bytes.0.as_ptr(), bytes.0.len())).unwrap();
+        assert_eq!(seen, (ptr, 1024), "reads the stored buffer in place");
+
+        // 追踪依赖
+        let lens = Rc::new(std::cell::RefCell::new(Vec::new()));
+        crate::reactivity::Effect::new({
+            let lens = lens.clone();
+            move |_| {
+                let len = with_signal_ref(id, |bytes: &Bytes| bytes.0.len()).unwrap();
+                lens.borrow_mut().push(len);
+            }
+        });
+        silex_reactivity::update_signal(id, |bytes: &mut Bytes| bytes.0.push(1));
+        assert_eq!(*lens.borrow(), [1024, 1025]);
+
+        let err = with_signal_ref(id, |_: &String| ()).unwrap_err();
+        assert!(err.to_string().contains("alloc::string::String"), "{err}");
+
+        silex_reactivity::dispose(id);
+        let err = with_signal_ref(id, |_: &Bytes| ()).unwrap_err();
+        assert!(err.to_string().contains("disposed"), "{err}");
+    });
+}
@@ -0,0 +1,109 @@
+use crate::reactivity::{ReadSignal, Signal, WriteSignal};
+use crate::traits::RxWrite;
+use futures_core::Stream;
+use silex_reactivity::on_cleanup;
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+#[cfg(test)]
+mod tests;
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// 消费任务的中止状态，作用域销毁时置位并唤醒任务
+#[derive(Default)]
+struct AbortHandle {
+    aborted: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl AbortHandle {
+    fn abort(&self) {
+        self.aborted.set(true);
+        if let Some(waker) = self.waker.borrow_mut().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// 逐项消费流并写入信号，被中止或流结束时完成
+struct ConsumeStream<S: Stream> {
+    stream: Pin<Box<S>>,
+    write: WriteSignal<S::Item>,
+    abort: Rc<AbortHandle>,
+}
+
+// 流已被装箱固定，WriteSignal 只是句柄，整体可安全移动
+impl<S: Stream> Unpin for ConsumeStream<S> {}
+
+impl<S> Future for ConsumeStream<S>
+where
+    S: Stream,
+    S::Item: 'static,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        loop {
+            if this.abort.aborted.get() {
+                return Poll::Ready(());
+            }
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(value)) => {
+                    // 信号已失效时停止消费
+                    if this.write.try_set(value).is_some() {
+                        return Poll::Ready(());
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(()),
+                Poll::Pending => {
+                    *this.abort.waker.borrow_mut() = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+/// 从异步流派生信号：后台任务逐项消费 `stream` 并设置信号，适用于 WebSocket、EventSource 等推送源。
+///
+/// 所在作用域销毁时任务被中止并释放流，即使流永远不会结束。
+///
+/// ```rust,ignore
+/// let price = signal_from_stream(0.0, ticker_stream());
+/// div(move || format!("{:.2}", price.get()))
+/// ```
+pub fn signal_from_stream<T, S>(initial: T, stream: S) -> ReadSignal<T>
+where
+    T: 'static,
+    S: Stream<Item = T> + 'static,
+{
+    signal_from_stream_with(initial, stream, wasm_bindgen_futures::spawn_local)
+}
+
+fn signal_from_stream_with<T, S>(
+    initial: T,
+    stream: S,
+    spawn: impl FnOnce(LocalFuture),
+) -> ReadSignal<T>
+where
+    T: 'static,
+    S: Stream<Item = T> + 'static,
+{
+    let (read, write) = Signal::pair(initial);
+    let abort = Rc::new(AbortHandle::default());
+    on_cleanup({
+        let abort = abort.clone();
+        move || abort.abort()
+    });
+    spawn(Box::pin(ConsumeStream {
+        stream: Box::pin(stream),
+        write,
+        abort,
+    }));
+    read
+}
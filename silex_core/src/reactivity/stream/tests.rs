@@ -0,0 +1,78 @@
+use super::*;
+use crate::traits::RxGet;
+use silex_reactivity::{create_scope, dispose};
+use std::collections::VecDeque;
+
+/// 基于共享队列的模拟通道，`closed` 之前队列为空时保持 Pending
+#[derive(Clone, Default)]
+struct Channel {
+    queue: Rc<RefCell<VecDeque<u32>>>,
+    closed: Rc<Cell<bool>>,
+    /// 流被释放时置位
+    dropped: Rc<Cell<bool>>,
+}
+
+struct Receiver(Channel);
+
+impl Stream for Receiver {
+    type Item = u32;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        match self.0.queue.borrow_mut().pop_front() {
+            Some(v) => Poll::Ready(Some(v)),
+            None if self.0.closed.get() => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for Receiver {
+    fn drop(&mut self) {
+        self.0.dropped.set(true);
+    }
+}
+
+#[test]
+fn test_stream_items_update_signal_until_dispose() {
+    let channel = Channel::default();
+    let task = Rc::new(RefCell::new(None::<LocalFuture>));
+    let poll = || {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut task = task.borrow_mut();
+        if task
+            .as_mut()
+            .is_some_and(|fut| fut.as_mut().poll(&mut cx).is_ready())
+        {
+            *task = None;
+        }
+    };
+
+    let signal = Rc::new(Cell::new(None));
+    let scope = create_scope({
+        let channel = channel.clone();
+        let task = task.clone();
+        let signal = signal.clone();
+        move || {
+            let read = signal_from_stream_with(0, Receiver(channel), |fut| {
+                *task.borrow_mut() = Some(fut);
+            });
+            signal.set(Some(read));
+        }
+    });
+    let read = signal.get().unwrap();
+    assert_eq!(read.get_untracked(), 0);
+
+    channel.queue.borrow_mut().extend([1, 2]);
+    poll();
+    assert_eq!(read.get_untracked(), 2);
+    channel.queue.borrow_mut().push_back(3);
+    poll();
+    assert_eq!(read.get_untracked(), 3);
+
+    // 流未结束，销毁后任务完成并释放流
+    dispose(scope);
+    channel.queue.borrow_mut().push_back(4);
+    poll();
+    assert!(task.borrow().is_none(), "task finished after dispose");
+    assert!(channel.dropped.get());
+}
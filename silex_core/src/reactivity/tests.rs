@@ -0,0 +1,33 @@
+use super::*;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[derive(Clone, Debug, PartialEq)]
+struct UserStore(u32);
+
+fn panic_message(f: impl FnOnce()) -> String {
+    let payload = catch_unwind(AssertUnwindSafe(f)).expect_err("expected a panic");
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_expect_context_msg_reports_custom_message() {
+    create_scope(|| {
+        let msg = panic_message(|| {
+            expect_context_msg::<UserStore>("UserStore must be provided by App");
+        });
+        assert!(msg.starts_with("UserStore must be provided by App"));
+        assert!(msg.contains(std::any::type_name::<UserStore>()));
+        #[cfg(debug_assertions)]
+        assert!(msg.contains("requested at"), "{msg}");
+
+        // 未提供时 try 版本返回错误而非 panic
+        assert!(try_expect_context::<UserStore>().is_err());
+
+        provide_context(UserStore(7));
+        assert_eq!(expect_context_msg::<UserStore>("unused"), UserStore(7));
+        assert_eq!(try_expect_context::<UserStore>().unwrap(), UserStore(7));
+    });
+}
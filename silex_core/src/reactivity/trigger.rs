@@ -0,0 +1,62 @@
+use silex_reactivity::{NodeId, is_signal_valid, notify_signal, track_signal};
+
+#[cfg(test)]
+mod tests;
+
+// --- Trigger 触发器 ---
+
+/// 仅承载依赖关系的响应式节点（值为 `()` 的信号）。
+///
+/// 用于把不受信号管理的外部可变状态（例如 WASM 侧缓存）接入响应式系统：
+/// 读取方调用 [`Trigger::track`] 建立依赖，外部状态变化后调用 [`Trigger::notify`]，
+/// 所有依赖它的 `Memo` / `Effect` 都会被标记为脏并重新执行。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Trigger {
+    id: NodeId,
+}
+
+impl Trigger {
+    pub fn new() -> Self {
+        Self {
+            id: silex_reactivity::signal(()),
+        }
+    }
+
+    /// 在当前追踪上下文中订阅此触发器。
+    pub fn track(&self) {
+        track_signal(self.id);
+    }
+
+    /// 通知所有订阅者重新执行。触发器被销毁后调用无副作用。
+    pub fn notify(&self) {
+        if is_signal_valid(self.id) {
+            notify_signal(self.id);
+        }
+    }
+
+    pub fn is_disposed(&self) -> bool {
+        !is_signal_valid(self.id)
+    }
+}
+
+impl Default for Trigger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 创建一个触发器，返回 `(track, notify)` 闭包对。
+///
+/// ```rust,ignore
+/// let (track, notify) = create_trigger();
+/// let len = Memo::new(move |_| {
+///     track();
+///     CACHE.with(|c| c.borrow().len())
+/// });
+/// CACHE.with(|c| c.borrow_mut().push(1));
+/// notify(); // len 重新计算
+/// ```
+pub fn create_trigger() -> (impl Fn() + Copy + 'static, impl Fn() + Copy + 'static) {
+    let trigger = Trigger::new();
+    (move || trigger.track(), move || trigger.notify())
+}
@@ -0,0 +1,52 @@
+use super::*;
+use crate::reactivity::{Effect, Memo};
+use crate::traits::RxGet;
+use silex_reactivity::create_scope;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+#[test]
+fn test_notify_reruns_effect() {
+    create_scope(|| {
+        let (track, notify) = create_trigger();
+        let runs = Rc::new(Cell::new(0));
+
+        Effect::new({
+            let runs = runs.clone();
+            move |_| {
+                track();
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 1);
+
+        notify();
+        assert_eq!(runs.get(), 2);
+        notify();
+        assert_eq!(runs.get(), 3);
+    });
+}
+
+#[test]
+fn test_trigger_invalidates_memo_over_external_state() {
+    create_scope(|| {
+        let cache = Rc::new(RefCell::new(vec![1, 2]));
+        let trigger = Trigger::new();
+
+        let sum = Memo::new({
+            let cache = cache.clone();
+            move |_| {
+                trigger.track();
+                cache.borrow().iter().sum::<i32>()
+            }
+        });
+        assert_eq!(sum.get(), 3);
+
+        // 外部状态变化但未通知：Memo 保持旧值
+        cache.borrow_mut().push(3);
+        assert_eq!(sum.get(), 3);
+
+        trigger.notify();
+        assert_eq!(sum.get(), 6);
+    });
+}
@@ -129,6 +129,14 @@ pub trait RxRead: RxInternal {
         self.rx_try_with_untracked(fun)
     }
 
+    /// 窥视：不订阅地通过闭包访问底层值，等同于 [`with_untracked`](RxRead::with_untracked)。
+    ///
+    /// 在调用处明确表示此次读取不会建立依赖，无需包裹 `untrack(...)`。
+    #[track_caller]
+    fn peek_with<U>(&self, fun: impl FnOnce(&Self::Value) -> U) -> U {
+        self.with_untracked(fun)
+    }
+
     /// 尝试获取值的副本。该方法不强制要求 `Clone` 约束（自适应回退）。
     /// - 如果信号已销毁 / 未实现 Clone：返回 `None`。
     #[track_caller]
@@ -177,6 +185,12 @@ where
 
     /// 响应式地订阅信号，克隆并返回值。
     fn get(&self) -> Self::Value;
+
+    /// 窥视：不订阅地克隆并返回值，等同于 [`get_untracked`](RxGet::get_untracked)。
+    #[track_caller]
+    fn peek(&self) -> Self::Value {
+        self.get_untracked()
+    }
 }
 
 impl<T: ?Sized + RxRead> RxGet for T
@@ -1,5 +1,6 @@
 use crate::types::{ValidFor, props};
-use silex_core::traits::{IntoRx, RxGet, RxValue};
+use silex_core::reactivity::Signal;
+use silex_core::traits::{IntoRx, IntoSignal, RxGet, RxValue};
 use silex_dom::attribute::{ApplyTarget, ApplyToDom, IntoStorable};
 use std::fmt::{Display, Write};
 use std::hash::{Hash, Hasher};
@@ -21,6 +22,7 @@ pub struct Style {
     pub(crate) static_rules: Vec<StaticRule>,
     pub(crate) dynamic_rules: Vec<DynamicRule>,
     pub(crate) nested_rules: Vec<NestedRule>,
+    pub(crate) conditional_rules: Vec<(Signal<bool>, Style)>,
 }
 
 impl Default for Style {
@@ -35,6 +37,7 @@ impl Style {
             static_rules: Vec::new(),
             dynamic_rules: Vec::new(),
             nested_rules: Vec::new(),
+            conditional_rules: Vec::new(),
         }
     }
 
@@ -59,6 +62,18 @@ impl Style {
         self
     }
 
+    /// 当 `condition` 为真时叠加 `other` 中的声明，例如 `.merge_when(active, sty().color(hex("#f00")))`
+    /// 条件样式编译为独立的作用域类，由 Effect 按条件切换，而非拼接内联样式。
+    /// 与基础样式重叠的属性以条件样式为准；多个条件样式之间按调用顺序后者优先。
+    pub fn merge_when<C>(mut self, condition: C, other: Style) -> Self
+    where
+        C: IntoSignal<Value = bool> + 'static,
+    {
+        self.conditional_rules
+            .push((condition.into_signal(), other));
+        self
+    }
+
     pub fn on_hover<F>(self, f: F) -> Self
     where
         F: FnOnce(Style) -> Style,
@@ -136,6 +151,98 @@ impl Style {
         self.padding_top(value.clone()).padding_bottom(value)
     }
 
+    /// 展开为 `margin-left`/`margin-right` (`x`) 与 `margin-top`/`margin-bottom` (`y`)
+    pub fn margin_xy<X, Y>(self, x: X, y: Y) -> Self
+    where
+        X: IntoRx + RxValue + Clone + 'static,
+        X::Value: ValidFor<props::MarginLeft>
+            + ValidFor<props::MarginRight>
+            + Display
+            + Clone
+            + Sized
+            + 'static,
+        X::RxType: RxGet<Value = X::Value> + Clone + 'static,
+        Y: IntoRx + RxValue + Clone + 'static,
+        Y::Value: ValidFor<props::MarginTop>
+            + ValidFor<props::MarginBottom>
+            + Display
+            + Clone
+            + Sized
+            + 'static,
+        Y::RxType: RxGet<Value = Y::Value> + Clone + 'static,
+    {
+        self.margin_x(x).margin_y(y)
+    }
+
+    /// 展开为 `padding-left`/`padding-right` (`x`) 与 `padding-top`/`padding-bottom` (`y`)
+    pub fn padding_xy<X, Y>(self, x: X, y: Y) -> Self
+    where
+        X: IntoRx + RxValue + Clone + 'static,
+        X::Value: ValidFor<props::PaddingLeft>
+            + ValidFor<props::PaddingRight>
+            + Display
+            + Clone
+            + Sized
+            + 'static,
+        X::RxType: RxGet<Value = X::Value> + Clone + 'static,
+        Y: IntoRx + RxValue + Clone + 'static,
+        Y::Value: ValidFor<props::PaddingTop>
+            + ValidFor<props::PaddingBottom>
+            + Display
+            + Clone
+            + Sized
+            + 'static,
+        Y::RxType: RxGet<Value = Y::Value> + Clone + 'static,
+    {
+        self.padding_x(x).padding_y(y)
+    }
+
+    /// 展开为 `top`/`right`/`bottom`/`left`，四边取同一个值
+    pub fn inset_all<V>(self, value: V) -> Self
+    where
+        V: IntoRx + RxValue + Clone + 'static,
+        V::Value: ValidFor<props::Top>
+            + ValidFor<props::Right>
+            + ValidFor<props::Bottom>
+            + ValidFor<props::Left>
+            + Display
+            + Clone
+            + Sized
+            + 'static,
+        V::RxType: RxGet<Value = V::Value> + Clone + 'static,
+    {
+        self.top(value.clone())
+            .right(value.clone())
+            .bottom(value.clone())
+            .left(value)
+    }
+
+    /// 展开为 `width` 与 `height`
+    pub fn size<W, H>(self, width: W, height: H) -> Self
+    where
+        W: IntoRx + RxValue + 'static,
+        W::Value: ValidFor<props::Width> + Display + Clone + Sized + 'static,
+        W::RxType: RxGet<Value = W::Value> + Clone + 'static,
+        H: IntoRx + RxValue + 'static,
+        H::Value: ValidFor<props::Height> + Display + Clone + Sized + 'static,
+        H::RxType: RxGet<Value = H::Value> + Clone + 'static,
+    {
+        self.width(width).height(height)
+    }
+
+    /// 展开为 `row-gap` 与 `column-gap`
+    pub fn gap_xy<X, Y>(self, column: X, row: Y) -> Self
+    where
+        X: IntoRx + RxValue + 'static,
+        X::Value: ValidFor<props::ColumnGap> + Display + Clone + Sized + 'static,
+        X::RxType: RxGet<Value = X::Value> + Clone + 'static,
+        Y: IntoRx + RxValue + 'static,
+        Y::Value: ValidFor<props::RowGap> + Display + Clone + Sized + 'static,
+        Y::RxType: RxGet<Value = Y::Value> + Clone + 'static,
+    {
+        self.row_gap(row).column_gap(column)
+    }
+
     pub fn pseudo<F>(self, selector: &'static str, f: F) -> Self
     where
         F: FnOnce(Style) -> Style,
@@ -191,8 +298,30 @@ impl ApplyToDom for Style {
     }
 }
 
+/// 编译后的 `Style`：基础类名、完整 CSS 以及条件类的切换信息。
+pub struct RenderedStyle {
+    pub class_name: String,
+    pub css: String,
+    dyn_bindings: Vec<(String, DynamicValue)>,
+    conditional_classes: Vec<(String, Signal<bool>)>,
+}
+
+impl RenderedStyle {
+    /// 当前应作用于元素的类名（基础类 + 条件成立的条件类）。在 Effect 中调用时会追踪条件。
+    pub fn classes(&self) -> Vec<String> {
+        let mut classes = vec![self.class_name.clone()];
+        for (class, condition) in &self.conditional_classes {
+            if condition.get() {
+                classes.push(class.clone());
+            }
+        }
+        classes
+    }
+}
+
 impl Style {
-    pub fn apply_to_element(&self, el: &web_sys::Element) -> String {
+    /// 生成稳定类名与 CSS 文本，不触及 DOM。
+    pub fn render(&self) -> RenderedStyle {
         // 1. 生成稳定哈希（忽略动态值，递归所有嵌套规则）
         let mut hasher = silex_hash::css::CssHasher::new();
         hash_recursive(self, &mut hasher);
@@ -208,12 +337,33 @@ impl Style {
 
         generate_css_recursive(self, &base_sel, hash_str, &mut css_str, &mut dyn_bindings);
 
-        // 3. 注入样式并添加类名
-        crate::inject_style(&class_base, &css_str);
-        let _ = el.class_list().add_1(&class_base);
+        // 3. 条件样式：`.base.cond` 的特异性高于基础类，且按声明顺序写在同一段 CSS 中，
+        //    因此重叠属性的覆盖顺序是确定的（后者优先）
+        let mut conditional_classes = Vec::new();
+        for (i, (condition, sub)) in self.conditional_rules.iter().enumerate() {
+            let class = format!("{}-w{}", class_base, i);
+            let selector = format!("{}.{}", base_sel, class);
+            generate_css_recursive(sub, &selector, hash_str, &mut css_str, &mut dyn_bindings);
+            conditional_classes.push((class, *condition));
+        }
+
+        RenderedStyle {
+            class_name: class_base,
+            css: css_str,
+            dyn_bindings,
+            conditional_classes,
+        }
+    }
+
+    pub fn apply_to_element(&self, el: &web_sys::Element) -> String {
+        let rendered = self.render();
+
+        // 注入样式并添加类名
+        crate::inject_style(&rendered.class_name, &rendered.css);
+        let _ = el.class_list().add_1(&rendered.class_name);
 
-        // 4. 建立极轻量更新 Effect (只有 style.setProperty)
-        for (var_name, getter) in dyn_bindings {
+        // 建立极轻量更新 Effect (只有 style.setProperty)
+        for (var_name, getter) in rendered.dyn_bindings {
             let el_clone = el.clone();
             silex_core::reactivity::Effect::new(move |prev: Option<String>| {
                 let current = getter();
@@ -228,7 +378,19 @@ impl Style {
                 current
             });
         }
-        class_base
+
+        // 条件类切换
+        for (class, condition) in rendered.conditional_classes {
+            let el_clone = el.clone();
+            silex_core::reactivity::Effect::new(move |prev: Option<bool>| {
+                let active = condition.get();
+                if prev != Some(active) {
+                    let _ = el_clone.class_list().toggle_with_force(&class, active);
+                }
+                active
+            });
+        }
+        rendered.class_name
     }
 }
 
@@ -242,6 +404,10 @@ fn hash_recursive(style: &Style, hasher: &mut silex_hash::css::CssHasher) {
         silex_hash::css::Normalized(prop).hash(hasher);
         "dyn-val".hash(hasher); // 动态值占位
     }
+    for (_, sub) in &style.conditional_rules {
+        "when".hash(hasher);
+        hash_recursive(sub, hasher);
+    }
     for rule in &style.nested_rules {
         match rule {
             NestedRule::Media(query, sub) => {
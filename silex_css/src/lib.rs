@@ -6,11 +6,17 @@ pub mod types;
 
 pub mod prelude {
     pub use crate::builder::{Style, sty};
-    pub use crate::runtime::{DynamicCss, DynamicStyleManager, inject_style};
+    pub use crate::runtime::{
+        DynamicCss, DynamicStyleManager, inject_style, inject_style_prefixed,
+    };
     pub use crate::theme::{
-        ThemePatchToCss, ThemeVariables, set_global_theme, theme_patch, theme_variables, use_theme,
+        CssVarBinding, ThemeHandle, ThemePatchToCss, ThemeVariables, root_vars_css,
+        set_global_theme, set_global_themes, theme_patch, theme_variables, use_theme,
+        use_theme_handle,
     };
     pub use crate::types::*;
 }
 
-pub use runtime::{DynamicCss, DynamicStyleManager, inject_style, make_dynamic_val_for};
+pub use runtime::{
+    DynamicCss, DynamicStyleManager, inject_style, inject_style_prefixed, make_dynamic_val_for,
+};
@@ -1,8 +1,9 @@
 /// 自动生成的 CSS 属性注册表
 #[macro_export]
 macro_rules! for_all_properties {
-    ($callback:ident) => {
+    ($callback:path $(, $arg:tt)*) => {
         $callback! {
+            $($arg,)*
             (webkit_line_clamp, "-webkit-line-clamp", WebkitLineClamp, Shorthand),
             (webkit_text_fill_color, "-webkit-text-fill-color", WebkitTextFillColor, Color),
             (webkit_text_stroke, "-webkit-text-stroke", WebkitTextStroke, Shorthand),
@@ -14,37 +14,149 @@ pub type CssVariableGetter = Rx<String>;
 const CACHE_LIMIT: usize = 128;
 
 thread_local! {
-    static DYNAMIC_STYLE_REGISTRY: RefCell<HashMap<String, Weak<DynamicStyleState>>> = RefCell::new(HashMap::new());
-    static RETIRED_STYLES: RefCell<VecDeque<Rc<DynamicStyleState>>> = const { RefCell::new(VecDeque::new()) };
+    static DYNAMIC_STYLE_REGISTRY: RefCell<SharedStyleRegistry<CssStyleSheet>> = RefCell::new(SharedStyleRegistry::new());
 }
 
-/// Manages an injected stylesheet uniquely for a component instance.
-pub(crate) struct DynamicStyleState {
-    pub id: String,
-    pub sheet: CssStyleSheet,
+/// Backend of a shared style block.
+/// Abstracted so that the sharing/ref-counting logic does not depend on a live document.
+pub(crate) trait StyleSheetBackend {
+    /// Creates and attaches a new style block with the given content.
+    fn create(content: &str) -> Self;
+    /// Replaces the whole content of the style block.
+    fn replace(&self, content: &str);
+    /// Detaches the style block from the document.
+    fn detach(&self);
 }
 
-impl Drop for DynamicStyleState {
-    fn drop(&mut self) {
-        // 1. Remove from document stylesheets
+impl StyleSheetBackend for CssStyleSheet {
+    fn create(content: &str) -> Self {
+        let sheet = CssStyleSheet::new().expect("Failed to create CssStyleSheet");
+        let _ = sheet.replace_sync(content);
+        DOCUMENT_REGISTRY.with(|dr| dr.borrow_mut().add_sheet(sheet.clone()));
+        sheet
+    }
+
+    fn replace(&self, content: &str) {
+        let _ = self.replace_sync(content);
+    }
+
+    fn detach(&self) {
         DOCUMENT_REGISTRY.with(|dr| {
             if let Ok(mut dr) = dr.try_borrow_mut() {
-                dr.remove_sheet(&self.sheet);
+                dr.remove_sheet(self);
             }
         });
-        // 2. Remove from registry map
-        DYNAMIC_STYLE_REGISTRY.with(|reg| {
-            if let Ok(mut reg) = reg.try_borrow_mut() {
-                reg.remove(&self.id);
-            }
+    }
+}
+
+/// A style block shared by every manager that resolved to the same id.
+/// The block is detached when the last reference (including the retired cache) is dropped.
+pub(crate) struct SharedStyle<S: StyleSheetBackend> {
+    id: String,
+    content: RefCell<String>,
+    sheet: S,
+}
+
+impl<S: StyleSheetBackend> SharedStyle<S> {
+    /// Writes `content` only if it differs from what is already injected.
+    fn set_content(&self, content: &str) {
+        let mut current = self.content.borrow_mut();
+        if *current != content {
+            self.sheet.replace(content);
+            *current = content.to_string();
+        }
+    }
+}
+
+impl<S: StyleSheetBackend> Drop for SharedStyle<S> {
+    fn drop(&mut self) {
+        self.sheet.detach();
+    }
+}
+
+/// Registry deduplicating style blocks by id, with an LRU cache of recently released blocks.
+pub(crate) struct SharedStyleRegistry<S: StyleSheetBackend> {
+    entries: HashMap<String, Weak<SharedStyle<S>>>,
+    retired: VecDeque<Rc<SharedStyle<S>>>,
+}
+
+impl<S: StyleSheetBackend> Default for SharedStyleRegistry<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: StyleSheetBackend> SharedStyleRegistry<S> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            retired: VecDeque::new(),
+        }
+    }
+
+    /// Returns the style block for `id`, reusing a live or retired one if it exists.
+    /// An existing block is only rewritten when its content actually changed.
+    pub(crate) fn acquire(&mut self, id: &str, content: &str) -> Rc<SharedStyle<S>> {
+        if let Some(pos) = self.retired.iter().position(|s| s.id == id)
+            && let Some(state) = self.retired.remove(pos)
+        {
+            state.set_content(content);
+            return state;
+        }
+
+        if let Some(state) = self.entries.get(id).and_then(Weak::upgrade) {
+            state.set_content(content);
+            return state;
+        }
+
+        let state = Rc::new(SharedStyle {
+            id: id.to_string(),
+            content: RefCell::new(content.to_string()),
+            sheet: S::create(content),
         });
+        self.entries.insert(id.to_string(), Rc::downgrade(&state));
+        state
+    }
+
+    /// Releases one reference. The last reference is moved into the retired cache
+    /// instead of being detached immediately, so that toggling back is free.
+    pub(crate) fn release(&mut self, state: Rc<SharedStyle<S>>) {
+        if Rc::strong_count(&state) > 1 {
+            return;
+        }
+        self.retired.push_back(state);
+        if self.retired.len() > CACHE_LIMIT
+            && let Some(oldest) = self.retired.pop_front()
+        {
+            self.entries.remove(&oldest.id);
+        }
+    }
+
+    /// Number of active references to `id` (excluding the retired cache).
+    #[cfg(test)]
+    fn ref_count(&self, id: &str) -> usize {
+        let Some(state) = self.entries.get(id).and_then(Weak::upgrade) else {
+            return 0;
+        };
+        let retired = self.retired.iter().filter(|s| s.id == id).count();
+        // Subtract the temporary `state` we just upgraded
+        Rc::strong_count(&state) - 1 - retired
+    }
+
+    /// Number of style blocks currently attached (active or retired).
+    #[cfg(test)]
+    fn live_count(&self) -> usize {
+        self.entries
+            .values()
+            .filter(|w| w.strong_count() > 0)
+            .count()
     }
 }
 
 /// Manages an injected <style> block uniquely for a component instance.
 /// It cleans up the tag when dropped, preventing CSSOM leaks.
 pub struct DynamicStyleManager {
-    state: Option<Rc<DynamicStyleState>>,
+    state: Option<Rc<SharedStyle<CssStyleSheet>>>,
 }
 
 impl Default for DynamicStyleManager {
@@ -64,20 +176,14 @@ impl DynamicStyleManager {
         mgr
     }
 
-    /// Moves the current style state to the retired cache if it's the last active reference.
+    /// Returns the current style state to the registry (retiring it if it was the last reference).
     fn take_and_retire(&mut self) {
         if let Some(state) = self.state.take() {
-            // If strong_count is 1, it means this manager was the only one holding the style.
-            if Rc::strong_count(&state) == 1 {
-                RETIRED_STYLES.with(|retired| {
-                    let mut r = retired.borrow_mut();
-                    r.push_back(state);
-                    if r.len() > CACHE_LIMIT {
-                        // This will drop the oldest retired state, potentially triggering DynamicStyleState::drop
-                        r.pop_front();
-                    }
-                });
-            }
+            DYNAMIC_STYLE_REGISTRY.with(|registry| {
+                if let Ok(mut registry) = registry.try_borrow_mut() {
+                    registry.release(state);
+                }
+            });
         }
     }
 
@@ -85,36 +191,12 @@ impl DynamicStyleManager {
         if let Some(state) = &self.state
             && state.id == id
         {
-            let _ = state.sheet.replace_sync(content);
+            state.set_content(content);
             return;
         }
 
-        let new_state = DYNAMIC_STYLE_REGISTRY.with(|registry| {
-            let mut reg = registry.borrow_mut();
-
-            if let Some(weak) = reg.get(id)
-                && let Some(state) = weak.upgrade()
-            {
-                RETIRED_STYLES.with(|retired| {
-                    let mut r = retired.borrow_mut();
-                    if let Some(pos) = r.iter().position(|s| s.id == id) {
-                        r.remove(pos);
-                    }
-                });
-                let _ = state.sheet.replace_sync(content);
-                return state;
-            }
-            let sheet = CssStyleSheet::new().expect("Failed to create CssStyleSheet");
-            let _ = sheet.replace_sync(content);
-            DOCUMENT_REGISTRY.with(|dr| dr.borrow_mut().add_sheet(sheet.clone()));
-
-            let state = Rc::new(DynamicStyleState {
-                id: id.to_string(),
-                sheet,
-            });
-            reg.insert(id.to_string(), Rc::downgrade(&state));
-            state
-        });
+        let new_state =
+            DYNAMIC_STYLE_REGISTRY.with(|registry| registry.borrow_mut().acquire(id, content));
 
         self.take_and_retire();
         self.state = Some(new_state);
@@ -263,3 +345,71 @@ where
     let signal = source.into_rx();
     Rx::derive(Box::new(move || format!("{}", signal.get())))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static CREATED: Cell<usize> = const { Cell::new(0) };
+        static REPLACED: Cell<usize> = const { Cell::new(0) };
+        static ATTACHED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct MockSheet;
+
+    impl StyleSheetBackend for MockSheet {
+        fn create(_content: &str) -> Self {
+            CREATED.with(|c| c.set(c.get() + 1));
+            ATTACHED.with(|c| c.set(c.get() + 1));
+            MockSheet
+        }
+
+        fn replace(&self, _content: &str) {
+            REPLACED.with(|c| c.set(c.get() + 1));
+        }
+
+        fn detach(&self) {
+            ATTACHED.with(|c| c.set(c.get() - 1));
+        }
+    }
+
+    #[test]
+    fn identical_dynamic_rules_share_one_style_node() {
+        let mut registry = SharedStyleRegistry::<MockSheet>::new();
+        let css = ".slx-dyn-1 { color: red; }";
+
+        let a = registry.acquire("slx-dyn-1", css);
+        let b = registry.acquire("slx-dyn-1", css);
+
+        assert_eq!(CREATED.with(Cell::get), 1);
+        assert_eq!(
+            REPLACED.with(Cell::get),
+            0,
+            "identical content must not be re-injected"
+        );
+        assert_eq!(ATTACHED.with(Cell::get), 1);
+        assert_eq!(registry.live_count(), 1);
+        assert_eq!(registry.ref_count("slx-dyn-1"), 2);
+
+        registry.release(a);
+        assert_eq!(registry.ref_count("slx-dyn-1"), 1);
+        assert_eq!(ATTACHED.with(Cell::get), 1);
+
+        // The last release retires the node; re-acquiring revives it without recreating.
+        registry.release(b);
+        assert_eq!(registry.ref_count("slx-dyn-1"), 0);
+        let c = registry.acquire("slx-dyn-1", css);
+        assert_eq!(CREATED.with(Cell::get), 1);
+        assert_eq!(registry.ref_count("slx-dyn-1"), 1);
+
+        registry.release(c);
+        drop(registry);
+        assert_eq!(
+            ATTACHED.with(Cell::get),
+            0,
+            "style node must be detached once released"
+        );
+    }
+}
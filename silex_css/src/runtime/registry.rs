@@ -34,6 +34,10 @@ impl StaticStyleRegistry {
             return;
         }
         self.injected_ids.insert(id.to_string());
+        // Outside the browser only the id is recorded, like the `:root` variables in `theme.rs`.
+        if !cfg!(target_arch = "wasm32") {
+            return;
+        }
 
         if let Some(sheet) = &self.shared_sheet {
             // Incremental injection: insert each rule one by one to avoid full re-parsing
@@ -115,6 +119,137 @@ pub fn inject_style(id: &str, content: &str) {
     StaticStyleRegistry::with(|r| r.inject(id, content));
 }
 
+/// Injects a CSS string into the document after adding vendor prefixes
+/// for the curated properties in [`PREFIXED_PROPERTIES`].
+pub fn inject_style_prefixed(id: &str, content: &str) {
+    let content = prefix_declarations(content);
+    StaticStyleRegistry::with(|r| r.inject(id, &content));
+}
+
+/// Properties that still need a `-webkit-` prefixed duplicate in some browsers (mostly Safari).
+/// This is intentionally a small curated list, not a full autoprefixer.
+pub const PREFIXED_PROPERTIES: &[&str] = &[
+    "appearance",
+    "backdrop-filter",
+    "box-decoration-break",
+    "hyphens",
+    "mask",
+    "mask-image",
+    "mask-position",
+    "mask-repeat",
+    "mask-size",
+    "text-size-adjust",
+    "user-select",
+];
+
+/// Adds a `-webkit-` prefixed copy before every declaration of a property listed in
+/// [`PREFIXED_PROPERTIES`], unless the block already declares the prefixed form.
+pub(crate) fn prefix_declarations(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    // Property names declared by each open block, collected up front so that a prefixed
+    // form written after the unprefixed one is also respected.
+    let mut blocks: Vec<Vec<&str>> = Vec::new();
+    let mut start = 0;
+
+    for (i, byte) in structural_bytes(css) {
+        if byte == b'{' {
+            blocks.push(declared_names(&css[i + 1..]));
+            out.push_str(&css[start..=i]);
+            start = i + 1;
+            continue;
+        }
+        let Some(declared) = blocks.last() else {
+            continue;
+        };
+
+        let decl = &css[start..i];
+        if let Some(name) = declaration_name(decl)
+            && PREFIXED_PROPERTIES.contains(&name)
+            && !declared
+                .iter()
+                .any(|d| d.strip_prefix("-webkit-") == Some(name))
+        {
+            let indent = &decl[..decl.len() - decl.trim_start().len()];
+            out.push_str(indent);
+            out.push_str("-webkit-");
+            out.push_str(decl.trim_start());
+            out.push(';');
+        }
+        out.push_str(&css[start..=i]);
+        if byte == b'}' {
+            blocks.pop();
+        }
+        start = i + 1;
+    }
+    out.push_str(&css[start..]);
+    out
+}
+
+/// Yields the positions of `{`, `;` and `}` that are outside of quotes and parentheses
+/// (e.g. the `;` in `url(data:image/svg+xml;utf8,...)` is skipped).
+fn structural_bytes(css: &str) -> impl Iterator<Item = (usize, u8)> + '_ {
+    let bytes = css.as_bytes();
+    let mut in_quote = None;
+    let mut parens = 0usize;
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let pos = i;
+            let byte = bytes[i];
+            i += 1;
+            match byte {
+                b'\\' => i += 1,
+                b'"' | b'\'' => {
+                    if in_quote == Some(byte) {
+                        in_quote = None;
+                    } else if in_quote.is_none() {
+                        in_quote = Some(byte);
+                    }
+                }
+                _ if in_quote.is_some() => {}
+                b'(' => parens += 1,
+                b')' => parens = parens.saturating_sub(1),
+                b'{' | b';' | b'}' if parens == 0 => return Some((pos, byte)),
+                _ => {}
+            }
+        }
+        None
+    })
+}
+
+/// Names of the declarations directly inside a block, given the text after its `{`.
+/// Declarations of nested rules are not included.
+fn declared_names(block: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, byte) in structural_bytes(block) {
+        match (byte, depth) {
+            (b'{', _) => depth += 1,
+            (b'}', 0) => {
+                names.extend(declaration_name(&block[start..i]));
+                break;
+            }
+            (b'}', _) => {
+                depth -= 1;
+                start = i + 1;
+            }
+            (b';', 0) => {
+                names.extend(declaration_name(&block[start..i]));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
+fn declaration_name(decl: &str) -> Option<&str> {
+    decl.split_once(':').map(|(name, _)| name.trim())
+}
+
 /// Registry to manage the list of adopted stylesheets in the document.
 /// This is the single source of truth for document.adoptedStyleSheets.
 pub(crate) struct DocumentStyleRegistry {
@@ -210,3 +345,52 @@ impl DocumentStyleRegistry {
         self.last_sync_ids = current_ids;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_select_gains_webkit_prefix() {
+        let css = ".slx-a {\n  user-select: none;\n  color: red;\n}\n";
+        let prefixed = prefix_declarations(css);
+        assert_eq!(
+            prefixed,
+            ".slx-a {\n  -webkit-user-select: none;\n  user-select: none;\n  color: red;\n}\n"
+        );
+    }
+
+    #[test]
+    fn prefixing_handles_minified_and_nested_rules() {
+        let css = "@media (max-width:600px){.a:hover{backdrop-filter:blur(4px)}}.b{content:\"user-select:none;\"}";
+        assert_eq!(
+            prefix_declarations(css),
+            "@media (max-width:600px){.a:hover{-webkit-backdrop-filter:blur(4px);backdrop-filter:blur(4px)}}.b{content:\"user-select:none;\"}"
+        );
+    }
+
+    #[test]
+    fn existing_prefix_is_not_duplicated() {
+        let css = ".a{-webkit-user-select:text;user-select:text}";
+        assert_eq!(prefix_declarations(css), css);
+    }
+
+    #[test]
+    fn semicolons_inside_url_do_not_end_the_declaration() {
+        let css = ".a{mask-image:url(data:image/svg+xml;utf8,<svg></svg>);color:red}";
+        assert_eq!(
+            prefix_declarations(css),
+            ".a{-webkit-mask-image:url(data:image/svg+xml;utf8,<svg></svg>);mask-image:url(data:image/svg+xml;utf8,<svg></svg>);color:red}"
+        );
+    }
+
+    #[test]
+    fn prefixed_form_declared_later_is_not_duplicated() {
+        let css =
+            ".a{mask:none;-webkit-mask:none}.b{user-select:none;&:hover{-webkit-user-select:text}}";
+        assert_eq!(
+            prefix_declarations(css),
+            ".a{mask:none;-webkit-mask:none}.b{-webkit-user-select:none;user-select:none;&:hover{-webkit-user-select:text}}"
+        );
+    }
+}
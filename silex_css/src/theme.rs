@@ -1,6 +1,87 @@
+use crate::types::{CssVar, CssVarValue};
+use silex_core::error::handle_error;
 use silex_core::prelude::*;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::Display;
 
+thread_local! {
+    static ROOT_STYLE: RefCell<RootStyle> = RefCell::new(RootStyle::default());
+}
+
+/// Everything written to `:root`: the global theme and standalone typed variables.
+///
+/// The declarations live in the inline style of the document element, so they keep
+/// the specificity of inline styles and override any `:root` rule from stylesheets.
+#[derive(Default)]
+struct RootStyle {
+    theme: Vec<(String, String)>,
+    vars: Vec<(String, String)>,
+    /// Declarations written by the last flush, skipped when nothing changed and
+    /// told apart from inline declarations set by others.
+    applied: Vec<(String, String)>,
+}
+
+impl RootStyle {
+    fn flush(&mut self) {
+        let next: Vec<(String, String)> =
+            self.theme.iter().chain(self.vars.iter()).cloned().collect();
+        if next == self.applied {
+            return;
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        write_root_properties(&self.applied, &next);
+        self.applied = next;
+    }
+
+    fn css(&self) -> String {
+        let mut css = String::from(":root{");
+        for (name, value) in &self.applied {
+            css.push_str(name);
+            css.push(':');
+            css.push_str(value);
+            css.push(';');
+        }
+        css.push('}');
+        css
+    }
+}
+
+/// Rewrites the document element's inline style with a single `cssText` assignment,
+/// so all variables switch together. Declarations set by others are carried over.
+#[cfg(target_arch = "wasm32")]
+fn write_root_properties(prev: &[(String, String)], next: &[(String, String)]) {
+    use ::wasm_bindgen::JsCast;
+    let Some(style) = ::silex_dom::document()
+        .document_element()
+        .and_then(|root| root.dyn_into::<::web_sys::HtmlElement>().ok())
+        .map(|root| root.style())
+    else {
+        return;
+    };
+    let ours = |name: &str| prev.iter().chain(next).any(|(n, _)| n == name);
+    let mut css = String::new();
+    for i in 0..style.length() {
+        let name = style.item(i);
+        if ours(&name) {
+            continue;
+        }
+        let value = style.get_property_value(&name).unwrap_or_default();
+        let priority = style.get_property_priority(&name);
+        css.push_str(&format!("{name}:{value}"));
+        if !priority.is_empty() {
+            css.push_str(&format!(" !{priority}"));
+        }
+        css.push(';');
+    }
+    for (name, value) in next {
+        css.push_str(&format!("{name}:{value};"));
+    }
+    style.set_css_text(&css);
+}
+
 /// A trait that every Silex Theme must implement.
 /// This allows the `styled!` macro to perform compile-time type checks.
 /// Usually implemented via the `theme!` macro.
@@ -23,8 +104,13 @@ where
     T: ThemeType + ThemeToCss + RxCloneData + 'static,
 {
     let signal = theme.into_signal();
-    // Provide the theme signal in the current reactive scope
-    ::silex_core::prelude::provide_context(signal);
+    // Provide the theme in the current reactive scope, keeping the registry
+    // if the signal comes from the enclosing theme handle.
+    let handle = match use_context::<ThemeHandle<T>>() {
+        Some(handle) if handle.theme == signal => handle,
+        _ => ThemeHandle::from_signal(signal),
+    };
+    provide_context(handle);
     ThemeVariables(signal)
 }
 
@@ -77,46 +163,222 @@ where
     }
 }
 
+/// A reactive handle to the current theme.
+///
+/// Reads like a `Signal<T>` (it can be passed to `theme_variables`, `.map(...)` etc.),
+/// and additionally exposes the current theme name and named switching when the
+/// handle was created from a set of registered themes.
+pub struct ThemeHandle<T: 'static> {
+    theme: Signal<T>,
+    name: Signal<String>,
+    registry: Option<ThemeRegistry<T>>,
+}
+
+struct ThemeRegistry<T: 'static> {
+    themes: StoredValue<Vec<(String, T)>>,
+    set_theme: WriteSignal<T>,
+    set_name: WriteSignal<String>,
+}
+
+impl<T> Clone for ThemeRegistry<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for ThemeRegistry<T> {}
+
+impl<T> Clone for ThemeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for ThemeHandle<T> {}
+
+silex_core::impl_rx_delegate!(ThemeHandle, theme, false);
+
+impl<T: RxCloneData> ThemeHandle<T> {
+    /// Creates a handle over a set of named themes, starting with `initial`
+    /// (or the first theme if `initial` is not registered).
+    ///
+    /// # Panics
+    /// Panics if `themes` is empty.
+    pub fn new<N: Into<String>>(themes: impl IntoIterator<Item = (N, T)>, initial: &str) -> Self {
+        let themes: Vec<(String, T)> = themes.into_iter().map(|(n, t)| (n.into(), t)).collect();
+        let (name, theme) = themes
+            .iter()
+            .find(|(n, _)| n == initial)
+            .or_else(|| themes.first())
+            .cloned()
+            .expect("ThemeHandle::new requires at least one theme");
+
+        let (theme, set_theme) = Signal::pair(theme);
+        let (name, set_name) = Signal::pair(name);
+        Self {
+            theme: theme.into(),
+            name: name.into(),
+            registry: Some(ThemeRegistry {
+                themes: StoredValue::new(themes),
+                set_theme,
+                set_name,
+            }),
+        }
+    }
+
+    /// Wraps an existing theme signal. Such a handle has no named themes,
+    /// so `set_theme`/`toggle_theme` are no-ops.
+    pub fn from_signal(theme: Signal<T>) -> Self {
+        Self {
+            theme,
+            name: Signal::from(String::new()),
+            registry: None,
+        }
+    }
+
+    /// The current theme values.
+    pub fn theme(&self) -> Signal<T> {
+        self.theme
+    }
+
+    /// The name of the current theme (empty for handles created via `from_signal`).
+    pub fn name(&self) -> Signal<String> {
+        self.name
+    }
+
+    /// Names of all registered themes, in registration order.
+    pub fn names(&self) -> Vec<String> {
+        self.registry
+            .map(|r| {
+                r.themes
+                    .with_untracked(|t| t.iter().map(|(n, _)| n.clone()).collect())
+            })
+            .unwrap_or_default()
+    }
+
+    /// Switches to the theme registered as `name`.
+    /// Does nothing if it is already active; observers are notified once per switch.
+    pub fn set_theme(&self, name: &str) {
+        let Some(registry) = self.registry else {
+            silex_core::warn!(
+                "set_theme(\"{}\") called on a theme handle without named themes",
+                name
+            );
+            return;
+        };
+        if self.name.with_untracked(|current| current == name) {
+            return;
+        }
+        let Some(theme) = registry
+            .themes
+            .with_untracked(|t| t.iter().find(|(n, _)| n == name).map(|(_, t)| t.clone()))
+        else {
+            silex_core::warn!("Unknown theme \"{}\"", name);
+            return;
+        };
+
+        batch(|| {
+            registry.set_name.set(name.to_string());
+            registry.set_theme.set(theme);
+        });
+    }
+
+    /// Switches to the next registered theme (wrapping around),
+    /// e.g. light -> dark -> light.
+    pub fn toggle_theme(&self) {
+        let names = self.names();
+        if names.len() < 2 {
+            return;
+        }
+        let current = self.name.get_untracked();
+        let next = names
+            .iter()
+            .position(|n| *n == current)
+            .map(|i| (i + 1) % names.len())
+            .unwrap_or(0);
+        self.set_theme(&names[next]);
+    }
+}
+
 /// Hook to get the current theme signal from context.
 pub fn use_theme<T: 'static>() -> Signal<T> {
-    ::silex_core::prelude::use_context::<Signal<T>>().expect("No ThemeProvider found in hierarchy")
+    use_theme_handle::<T>().theme
+}
+
+/// Hook to get the current theme handle from context, for named switching.
+pub fn use_theme_handle<T: 'static>() -> ThemeHandle<T> {
+    ::silex_core::prelude::use_context::<ThemeHandle<T>>()
+        .expect("No ThemeProvider found in hierarchy")
+}
+
+/// Collects the `(name, value)` pairs of every theme variable.
+fn root_theme_vars<T: ThemeToCss>(theme: &T) -> Vec<(String, String)> {
+    T::get_variable_names()
+        .iter()
+        .map(|name| name.to_string())
+        .zip(theme.get_variable_values())
+        .collect()
+}
+
+/// Replaces the theme declarations on `:root`, writing only the properties that changed.
+fn apply_vars_to_root(theme: Vec<(String, String)>) {
+    ROOT_STYLE.with(|root| {
+        let mut root = root.borrow_mut();
+        root.theme = theme;
+        root.flush();
+    });
+}
+
+/// Sets a single custom property on `:root`, next to the theme variables.
+fn apply_var_to_root(name: &str, value: String) {
+    ROOT_STYLE.with(|root| {
+        let mut root = root.borrow_mut();
+        match root.vars.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value,
+            None => root.vars.push((name.to_string(), value)),
+        }
+        root.flush();
+    });
+}
+
+/// The declarations currently applied to `:root` by the global theme and typed root variables,
+/// formatted as a `:root{...}` rule.
+pub fn root_vars_css() -> String {
+    ROOT_STYLE.with(|root| root.borrow().css())
+}
+
+fn bind_theme_to_root<T>(handle: ThemeHandle<T>)
+where
+    T: ThemeType + ThemeToCss + RxCloneData + 'static,
+{
+    provide_context(handle);
+    Effect::new(move |_| {
+        apply_vars_to_root(handle.theme.with(root_theme_vars));
+    });
 }
 
 /// Sets a global theme that applies to the entire document (:root).
+///
+/// Variables are written to the inline style of the document element, so they take
+/// precedence over `:root` rules declared in stylesheets.
 pub fn set_global_theme<T>(theme: impl IntoSignal<Value = T> + 'static)
 where
     T: ThemeType + ThemeToCss + RxCloneData + 'static,
 {
-    let signal = theme.into_signal();
-    // Register the theme in the global context as well
-    ::silex_core::prelude::provide_context(signal);
-
-    // Apply reactive updates to :root
-    ::silex_core::prelude::Effect::new(move |prev_values: Option<Vec<String>>| {
-        use ::wasm_bindgen::JsCast;
-        let doc = ::silex_dom::document();
-        if let Some(root) = doc.document_element()
-            && let Some(style) = root.dyn_ref::<::web_sys::HtmlElement>().map(|e| e.style())
-        {
-            let theme_val = signal.get();
-            let names = T::get_variable_names();
-            let values = theme_val.get_variable_values();
-
-            if let Some(old_vals) = &prev_values {
-                for (i, (name, value)) in names.iter().zip(values.iter()).enumerate() {
-                    if Some(value) != old_vals.get(i) {
-                        let _ = style.set_property(name, value);
-                    }
-                }
-            } else {
-                for (name, value) in names.iter().zip(values.iter()) {
-                    let _ = style.set_property(name, value);
-                }
-            }
-            return values;
-        }
-        Vec::new()
-    });
+    bind_theme_to_root(ThemeHandle::from_signal(theme.into_signal()));
+}
+
+/// Registers a set of named themes, applies `initial` to the entire document (:root)
+/// and returns the handle used to switch between them.
+pub fn set_global_themes<T, N>(
+    themes: impl IntoIterator<Item = (N, T)>,
+    initial: &str,
+) -> ThemeHandle<T>
+where
+    T: ThemeType + ThemeToCss + RxCloneData + 'static,
+    N: Into<String>,
+{
+    let handle = ThemeHandle::new(themes, initial);
+    bind_theme_to_root(handle);
+    handle
 }
 
 /// A trait for theme patches that only override a subset of variables.
@@ -198,3 +460,221 @@ where
         self
     }
 }
+
+// --- Typed CSS variables ---
+
+/// A registered typed variable, shared by every `CssVar::new/local` call with the same name.
+struct CssVarEntry {
+    signal: Box<dyn Any>,
+    /// Detached scope owning the signal and the `:root` effect.
+    scope: NodeId,
+    /// Number of live registrations; the entry is dropped when the last owner is disposed.
+    refs: usize,
+    on_root: bool,
+}
+
+thread_local! {
+    static CSS_VAR_SIGNALS: RefCell<HashMap<String, CssVarEntry>> = RefCell::new(HashMap::new());
+}
+
+/// Removes a single custom property from the shared `:root` rule.
+fn remove_var_from_root(name: &str) {
+    ROOT_STYLE.with(|root| {
+        let mut root = root.borrow_mut();
+        root.vars.retain(|(n, _)| n != name);
+        root.flush();
+    });
+}
+
+/// Drops one registration of `name`; the last one disposes the signal and its `:root` declaration.
+fn release_css_var(name: &str) {
+    let removed = CSS_VAR_SIGNALS.with(|vars| {
+        let mut vars = vars.borrow_mut();
+        let entry = vars.get_mut(name)?;
+        entry.refs -= 1;
+        if entry.refs > 0 {
+            return None;
+        }
+        vars.remove(name)
+    });
+    if let Some(entry) = removed {
+        dispose(entry.scope);
+        if entry.on_root {
+            remove_var_from_root(name);
+        }
+    }
+}
+
+impl<T: Display + Clone + 'static> CssVar<T> {
+    /// Creates a reactive custom property written to `:root` (alongside the global theme).
+    ///
+    /// The returned value is still a `CssVar<T>`, so referencing it in `sty()`/`css!`
+    /// keeps the `ValidFor` checks of `T` (e.g. a `CssVar<Px>` is rejected by `color`).
+    /// Reports a type mismatch through `handle_error`, see [`CssVar::try_new`].
+    pub fn new(name: &str, initial: T) -> Self {
+        Self::try_new(name, initial).unwrap_or_else(|err| Self::unbound(name, err))
+    }
+
+    /// Creates a reactive custom property that is not written to `:root`;
+    /// apply it to an element with `.apply(var.bind())`.
+    pub fn local(name: &str, initial: T) -> Self {
+        Self::try_local(name, initial).unwrap_or_else(|err| Self::unbound(name, err))
+    }
+
+    /// Like [`CssVar::new`], but fails if `name` is already registered with another type.
+    ///
+    /// The registration belongs to the current owner: disposing it removes the
+    /// declaration from `:root` once no other owner uses the same variable.
+    pub fn try_new(name: &str, initial: T) -> SilexResult<Self> {
+        let var = Self::try_local(name, initial)?;
+        let pending = CSS_VAR_SIGNALS.with(|vars| {
+            let mut vars = vars.borrow_mut();
+            let entry = vars.get_mut(name)?;
+            let signal = entry.signal.downcast_ref::<RwSignal<T>>().copied()?;
+            (!std::mem::replace(&mut entry.on_root, true)).then_some((entry.scope, signal))
+        });
+        if let Some((scope, signal)) = pending {
+            let name = name.to_string();
+            with_owner(Some(scope), || {
+                Effect::new(move |_| {
+                    if let Some(value) = signal.try_with(|v| v.to_string()) {
+                        apply_var_to_root(&name, value);
+                    }
+                });
+            });
+        }
+        Ok(var)
+    }
+
+    /// Like [`CssVar::local`], but fails if `name` is already registered with another type.
+    ///
+    /// Creating a variable whose name is already registered returns a handle to the
+    /// existing signal (keeping its current value) instead of replacing it.
+    pub fn try_local(name: &str, initial: T) -> SilexResult<Self> {
+        let reused = CSS_VAR_SIGNALS.with(|vars| {
+            let mut vars = vars.borrow_mut();
+            let Some(entry) = vars.get_mut(name) else {
+                return Ok(false);
+            };
+            if !entry.signal.is::<RwSignal<T>>() {
+                return Err(SilexError::Reactivity(format!(
+                    "CssVar {} is already registered with a different value type",
+                    name
+                )));
+            }
+            entry.refs += 1;
+            Ok(true)
+        })?;
+        if !reused {
+            let mut signal = None;
+            let scope = with_owner(None, || {
+                create_scope(|| signal = Some(RwSignal::new(initial)))
+            });
+            let entry = CssVarEntry {
+                signal: Box::new(signal.expect("scope runs synchronously")),
+                scope,
+                refs: 1,
+                on_root: false,
+            };
+            CSS_VAR_SIGNALS.with(|vars| vars.borrow_mut().insert(name.to_string(), entry));
+        }
+        let owned = name.to_string();
+        on_cleanup(move || release_css_var(&owned));
+        Ok(Self::reference(name))
+    }
+
+    fn reference(name: &str) -> Self {
+        CssVar(
+            CssVarValue::Dynamic(format!("var({})", name)),
+            std::marker::PhantomData,
+        )
+    }
+
+    /// A plain reference used when registration failed: it still renders `var(name)`, but has no value.
+    fn unbound(name: &str, err: SilexError) -> Self {
+        handle_error(err);
+        Self::reference(name)
+    }
+
+    fn signal(&self) -> Option<RwSignal<T>> {
+        let name = self.name();
+        CSS_VAR_SIGNALS.with(|vars| {
+            vars.borrow()
+                .get(name)
+                .and_then(|entry| entry.signal.downcast_ref::<RwSignal<T>>())
+                .copied()
+        })
+    }
+
+    /// Updates the variable; every element or `:root` rule using it follows.
+    pub fn set(&self, value: T) {
+        let written = self
+            .signal()
+            .is_some_and(|signal| signal.try_set(value).is_none());
+        if !written {
+            silex_core::warn!(
+                "CssVar {} was not created with CssVar::new/local and cannot be set",
+                self.name()
+            );
+        }
+    }
+
+    /// The current (tracked) value, or `None` for plain references created via `css_var`/`theme!`.
+    pub fn get(&self) -> Option<T> {
+        self.signal().and_then(|signal| signal.try_get())
+    }
+
+    /// Applies the variable to a single element's inline style.
+    pub fn bind(&self) -> CssVarBinding<T> {
+        CssVarBinding(self.clone())
+    }
+}
+
+impl<T> CssVar<T> {
+    /// The custom property name, e.g. `--gap` for `var(--gap)`.
+    pub fn name(&self) -> &str {
+        let raw = match &self.0 {
+            CssVarValue::Static(s) => s,
+            CssVarValue::Dynamic(s) => s.as_str(),
+        };
+        raw.strip_prefix("var(")
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(raw)
+    }
+}
+
+/// A structure that can be applied to a DOM element to set a typed CSS variable on it.
+pub struct CssVarBinding<T>(CssVar<T>);
+
+impl<T> ::silex_dom::attribute::ApplyToDom for CssVarBinding<T>
+where
+    T: Display + Clone + 'static,
+{
+    fn apply(&self, el: &::web_sys::Element, _target: ::silex_dom::attribute::ApplyTarget) {
+        let var = self.0.clone();
+        let el = el.clone();
+        ::silex_core::prelude::Effect::new(move |_| {
+            use ::wasm_bindgen::JsCast;
+            let Some(value) = var.get() else {
+                return;
+            };
+            if let Some(style) = el
+                .dyn_ref::<::web_sys::HtmlElement>()
+                .map(|e| e.style())
+                .or_else(|| el.dyn_ref::<::web_sys::SvgElement>().map(|e| e.style()))
+            {
+                let _ = style.set_property(var.name(), &value.to_string());
+            }
+        });
+    }
+}
+
+impl<T> silex_dom::attribute::IntoStorable for CssVarBinding<T>
+where
+    T: Display + Clone + 'static,
+{
+    type Stored = Self;
+    fn into_storable(self) -> Self::Stored {
+        self
+    }
+}
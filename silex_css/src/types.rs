@@ -162,6 +162,28 @@ macro_rules! impl_valid_for_dimension {
     };
 }
 
+/// 为自定义长度单位逐个实现尺寸类属性的 `ValidFor`，供 `#[derive(CssValue)]` 展开使用
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_valid_for_length {
+    ([$t:ty], $( ($snake:ident, $kebab:expr, $pascal:ident, $group:ident) ),*) => {
+        $( $crate::impl_valid_for_length!(@group $t, $pascal, $group); )*
+    };
+    (@group $t:ty, $pascal:ident, Dimension) => {
+        impl $crate::types::ValidFor<$crate::types::props::$pascal> for $t {}
+    };
+    (@group $t:ty, $pascal:ident, Custom) => {
+        impl $crate::types::ValidFor<$crate::types::props::$pascal> for $t {}
+    };
+    (@group $t:ty, $pascal:ident, Shorthand) => {
+        impl $crate::types::ValidFor<$crate::types::props::$pascal> for $t {}
+    };
+    (@group $t:ty, $pascal:ident, $group:ident) => {};
+    ($t:ty) => {
+        $crate::for_all_properties!($crate::impl_valid_for_length, [$t]);
+    };
+}
+
 macro_rules! impl_css_ops {
     ($t:ty, $trait:ident, $mark:ident) => {
         impl<R: $trait> std::ops::Add<R> for $t {
@@ -0,0 +1,52 @@
+use silex_core::reactivity::Signal;
+use silex_core::traits::{IntoRx, IntoSignal, RxGet, RxValue};
+use silex_core::{Rx, RxValueKind};
+use silex_css::make_dynamic_val_for;
+use silex_css::prelude::*;
+use silex_css::types::{CssLength, props};
+use std::fmt;
+
+/// 与 `#[derive(CssValue)]` 展开相同的自定义长度单位
+#[derive(Clone, Copy)]
+struct Ch(f32);
+
+impl fmt::Display for Ch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}ch", self.0)
+    }
+}
+
+impl CssLength for Ch {}
+
+impl RxValue for Ch {
+    type Value = Self;
+}
+
+impl IntoRx for Ch {
+    type RxType = Rx<Self, RxValueKind>;
+    fn into_rx(self) -> Self::RxType {
+        Rx::new_constant(self)
+    }
+    fn is_constant(&self) -> bool {
+        true
+    }
+}
+
+impl IntoSignal for Ch {
+    fn into_signal(self) -> Signal<Self> {
+        Signal::from(self)
+    }
+}
+
+silex_css::impl_valid_for_length!(Ch);
+
+#[test]
+fn custom_length_unit_is_valid_for_dimension_props() {
+    let rendered = sty().width(Ch(1.5)).margin(Ch(2.0)).render();
+    assert!(rendered.css.contains("width: 1.5ch;"), "{}", rendered.css);
+    assert!(rendered.css.contains("margin: 2ch;"), "{}", rendered.css);
+
+    // `css!` 插值使用的转换
+    let value = make_dynamic_val_for::<props::Width, _>(Ch(2.0));
+    assert_eq!(value.get(), "2ch");
+}
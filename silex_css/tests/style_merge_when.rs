@@ -0,0 +1,50 @@
+use silex_core::prelude::*;
+use silex_css::prelude::*;
+
+#[test]
+fn merge_when_toggles_conditional_class() {
+    create_scope(|| {
+        let active = RwSignal::new(false);
+        let style = sty()
+            .color(hex("#000"))
+            .padding(px(4))
+            .merge_when(active, sty().color(hex("#f00")));
+        let rendered = style.render();
+        let base = rendered.class_name.clone();
+        let merged = format!("{}-w0", base);
+
+        // Both rule sets live in the same block, the conditional one after the base
+        let base_rule = rendered.css.find(&format!(".{} {{", base)).unwrap();
+        let merged_rule = rendered
+            .css
+            .find(&format!(".{}.{} {{\n  color: #f00;", base, merged))
+            .unwrap();
+        assert!(base_rule < merged_rule);
+
+        let classes = Memo::new(move |_| rendered.classes());
+        assert_eq!(classes.get(), vec![base.clone()]);
+
+        active.set(true);
+        assert_eq!(classes.get(), vec![base.clone(), merged.clone()]);
+
+        active.set(false);
+        assert_eq!(classes.get(), vec![base.clone()]);
+    });
+}
+
+#[test]
+fn later_conditional_styles_win() {
+    create_scope(|| {
+        let style = sty()
+            .merge_when(true, sty().color(hex("#111")))
+            .merge_when(true, sty().color(hex("#222")));
+        let rendered = style.render();
+        let first = rendered.css.find("#111").unwrap();
+        let second = rendered.css.find("#222").unwrap();
+        assert!(first < second);
+        assert_eq!(rendered.classes().len(), 3);
+
+        // Conditional styles take part in the class hash
+        assert_ne!(rendered.class_name, sty().render().class_name);
+    });
+}
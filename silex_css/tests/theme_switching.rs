@@ -0,0 +1,119 @@
+use silex_core::prelude::*;
+use silex_css::prelude::*;
+use silex_css::theme::{ThemeToCss, ThemeType};
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Clone, Debug)]
+struct Palette {
+    surface: &'static str,
+    text: &'static str,
+}
+
+impl ThemeType for Palette {}
+
+impl ThemeToCss for Palette {
+    fn to_css_variables(&self) -> String {
+        format!("--surface: {}; --text: {};", self.surface, self.text)
+    }
+    fn get_variable_values(&self) -> Vec<String> {
+        vec![self.surface.to_string(), self.text.to_string()]
+    }
+    fn get_variable_names() -> &'static [&'static str] {
+        &["--surface", "--text"]
+    }
+}
+
+impl fmt::Display for Palette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_css_variables())
+    }
+}
+
+fn themes() -> Vec<(&'static str, Palette)> {
+    vec![
+        (
+            "light",
+            Palette {
+                surface: "#ffffff",
+                text: "#111827",
+            },
+        ),
+        (
+            "dark",
+            Palette {
+                surface: "#111827",
+                text: "#f9fafb",
+            },
+        ),
+    ]
+}
+
+#[test]
+fn theme_variables_observers_update_once_per_switch() {
+    create_scope(|| {
+        let handle = ThemeHandle::new(themes(), "light");
+        let vars = theme_variables(handle);
+
+        let runs = Rc::new(Cell::new(0));
+        let surface = Rc::new(Cell::new(""));
+        Effect::new({
+            let runs = runs.clone();
+            let surface = surface.clone();
+            move |_| {
+                surface.set(vars.0.with(|t| t.surface));
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 1);
+
+        handle.set_theme("dark");
+        assert_eq!(runs.get(), 2);
+        assert_eq!(surface.get(), "#111827");
+        assert_eq!(handle.name().get_untracked(), "dark");
+
+        // Re-selecting the active theme is not a switch
+        handle.set_theme("dark");
+        assert_eq!(runs.get(), 2);
+
+        handle.toggle_theme();
+        assert_eq!(runs.get(), 3);
+        assert_eq!(surface.get(), "#ffffff");
+        assert_eq!(handle.name().get_untracked(), "light");
+
+        // Unknown names leave the theme untouched
+        handle.set_theme("sepia");
+        assert_eq!(runs.get(), 3);
+    });
+}
+
+#[test]
+fn use_theme_handle_returns_registered_handle() {
+    create_scope(|| {
+        // `set_global_themes` provides the handle at the root
+        let handle = ThemeHandle::new(themes(), "dark");
+        provide_context(handle);
+
+        // A nested `theme_variables` over the same theme keeps the registry
+        let _ = theme_variables(use_theme::<Palette>());
+
+        let theme = use_theme_handle::<Palette>();
+        assert_eq!(theme.name().get_untracked(), "dark");
+        assert_eq!(theme.names(), vec!["light", "dark"]);
+
+        theme.toggle_theme();
+        assert_eq!(handle.name().get_untracked(), "light");
+    });
+}
+
+#[test]
+fn global_themes_write_root_declarations() {
+    create_scope(|| {
+        let handle = set_global_themes(themes(), "light");
+        assert_eq!(root_vars_css(), ":root{--surface:#ffffff;--text:#111827;}");
+
+        handle.set_theme("dark");
+        assert_eq!(root_vars_css(), ":root{--surface:#111827;--text:#f9fafb;}");
+    });
+}
@@ -0,0 +1,79 @@
+use silex_core::prelude::*;
+use silex_css::prelude::*;
+
+#[test]
+fn setting_a_typed_var_updates_root_value() {
+    create_scope(|| {
+        let gap = CssVar::<Px>::new("--gap", px(8));
+        assert_eq!(gap.to_string(), "var(--gap)");
+        assert_eq!(gap.name(), "--gap");
+        assert_eq!(gap.get(), Some(px(8)));
+        assert!(root_vars_css().contains("--gap:8px;"));
+
+        gap.set(px(12));
+        assert_eq!(gap.get(), Some(px(12)));
+        assert!(root_vars_css().contains("--gap:12px;"));
+        assert!(!root_vars_css().contains("--gap:8px;"));
+
+        // A second variable shares the same `:root` rule
+        let accent = CssVar::<Hex>::new("--accent", hex("#ff0000"));
+        let css = root_vars_css();
+        assert!(css.contains("--gap:12px;"));
+        assert!(css.contains("--accent:#ff0000;"));
+        assert_eq!(accent.get(), Some(hex("#ff0000")));
+    });
+}
+
+#[test]
+fn plain_var_references_have_no_value() {
+    create_scope(|| {
+        let plain: CssVar<Px> = CssVar(
+            CssVarValue::Static("var(--unbound)"),
+            std::marker::PhantomData,
+        );
+        assert_eq!(plain.name(), "--unbound");
+        assert_eq!(plain.get(), None);
+    });
+}
+
+#[test]
+fn disposing_the_owner_removes_the_root_declaration() {
+    let scope = create_scope(|| {
+        let gap = CssVar::<Px>::new("--gap", px(8));
+        assert_eq!(gap.get(), Some(px(8)));
+    });
+    assert!(root_vars_css().contains("--gap:8px;"));
+
+    dispose(scope);
+    assert!(!root_vars_css().contains("--gap"));
+}
+
+#[test]
+fn repeated_new_shares_one_signal_until_the_last_owner_is_gone() {
+    let first = create_scope(|| {
+        CssVar::<Px>::new("--gap", px(8)).set(px(10));
+    });
+    let second = create_scope(|| {
+        // Reuses the registered signal instead of resetting it
+        let gap = CssVar::<Px>::new("--gap", px(8));
+        assert_eq!(gap.get(), Some(px(10)));
+        gap.set(px(12));
+    });
+    assert_eq!(root_vars_css().matches("--gap:").count(), 1);
+    assert!(root_vars_css().contains("--gap:12px;"));
+
+    dispose(first);
+    assert!(root_vars_css().contains("--gap:12px;"));
+    dispose(second);
+    assert!(!root_vars_css().contains("--gap"));
+}
+
+#[test]
+fn type_mismatch_keeps_the_existing_variable() {
+    create_scope(|| {
+        let gap = CssVar::<Px>::new("--gap", px(8));
+        assert!(CssVar::<Hex>::try_new("--gap", hex("#ff0000")).is_err());
+        assert_eq!(gap.get(), Some(px(8)));
+        assert!(root_vars_css().contains("--gap:8px;"));
+    });
+}
@@ -49,6 +49,15 @@ pub trait AttributeBuilder: Sized {
         self.build_attribute(ApplyTarget::Prop(name), value)
     }
 
+    /// 以 JS 值设置属性 (property)，用于向自定义元素传入对象、数组等无法写成字符串的数据。
+    fn prop_js(self, name: &str, value: wasm_bindgen::JsValue) -> Self {
+        self.apply(AttrOp::Update {
+            name: name.to_string().into(),
+            target: AttrTarget::Prop,
+            data: AttrData::StaticJs(value),
+        })
+    }
+
     fn on<E, F, M>(self, event: E, callback: F) -> Self
     where
         E: EventDescriptor + 'static,
@@ -84,6 +93,19 @@ pub trait GlobalAttributes: AttributeBuilder {
         self.attr("style", value)
     }
 
+    /// 以样式表 (`HashMap<String, String>` 或 `Vec<(String, String)>`) 设置内联样式。
+    /// 更新时只写入变化的属性、移除被删除的属性，不影响其他途径设置的样式。
+    fn style_map<S, M>(self, map: S) -> Self
+    where
+        S: silex_core::traits::IntoSignal<Value = M> + 'static,
+        M: crate::helpers::StyleMap + silex_core::traits::RxCloneData,
+    {
+        let map = map.into_signal();
+        self.apply(PendingAttribute::new_listener(move |el| {
+            crate::helpers::bind_style_map(el.clone(), map);
+        }))
+    }
+
     fn title(self, value: impl IntoStorable) -> Self {
         self.attr("title", value)
     }
@@ -107,6 +129,44 @@ pub trait GlobalAttributes: AttributeBuilder {
     fn hidden(self, value: impl IntoStorable) -> Self {
         self.attr("hidden", value)
     }
+
+    fn contenteditable(self, value: impl IntoStorable) -> Self {
+        self.attr("contenteditable", value)
+    }
+
+    fn accesskey(self, value: impl IntoStorable) -> Self {
+        self.attr("accesskey", value)
+    }
+
+    fn spellcheck(self, value: impl IntoStorable) -> Self {
+        self.attr("spellcheck", value)
+    }
+
+    fn translate(self, value: impl IntoStorable) -> Self {
+        self.attr("translate", value)
+    }
+
+    fn inputmode(self, value: impl IntoStorable) -> Self {
+        self.attr("inputmode", value)
+    }
+
+    fn enterkeyhint(self, value: impl IntoStorable) -> Self {
+        self.attr("enterkeyhint", value)
+    }
+
+    fn autocapitalize(self, value: impl IntoStorable) -> Self {
+        self.attr("autocapitalize", value)
+    }
+
+    fn inert(self, value: impl IntoStorable) -> Self {
+        self.attr("inert", value)
+    }
+
+    /// 设置 `data-state` 属性 (如 "open"/"closed")，便于在 CSS 中按状态编写过渡样式。
+    /// 传入信号时随信号更新，布尔状态可配合 [`open_state`](crate::helpers::open_state) 使用。
+    fn data_state(self, value: impl IntoStorable) -> Self {
+        self.attr("data-state", value)
+    }
 }
 
 // 自动为所有实现 AttributeBuilder 的类型实现 GlobalAttributes
@@ -146,6 +206,19 @@ pub trait GlobalEventAttributes: AttributeBuilder {
         self.build_attribute(ApplyTarget::Class, value)
     }
 
+    /// 以闭包返回的类名列表设置类名，更新时只增删差异部分。
+    /// 不会移除静态 `class` 或其他途径设置的类名。
+    fn dyn_classes<F>(self, classes: F) -> Self
+    where
+        F: Fn() -> Vec<String> + 'static,
+    {
+        let classes = std::rc::Rc::new(classes);
+        self.apply(PendingAttribute::new_listener(move |el| {
+            let classes = classes.clone();
+            crate::helpers::bind_class_set(el.clone(), move || classes());
+        }))
+    }
+
     fn node_ref<N>(self, node_ref: silex_core::node_ref::NodeRef<N>) -> Self
     where
         N: wasm_bindgen::JsCast + Clone + 'static,
@@ -162,6 +235,35 @@ pub trait GlobalEventAttributes: AttributeBuilder {
         )))
     }
 
+    /// 元素插入文档后执行一次 `f`，适合聚焦输入框、初始化图表等一次性设置。
+    ///
+    /// 回调由所属根的挂载闸门放行 (见 [`silex_core::reactivity::on_mount`])，
+    /// 因此不会在构建视图期间、元素尚未插入文档时运行。
+    fn on_mount<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&web_sys::Element) + 'static,
+    {
+        let f = std::cell::Cell::new(Some(f));
+        self.apply(PendingAttribute::new_listener(move |el| {
+            if let Some(f) = f.take() {
+                run_on_mount(el.clone(), f);
+            }
+        }))
+    }
+
+    /// 元素所属作用域销毁时执行 `f`，等同于在该作用域中注册 `on_cleanup`。
+    fn on_unmount<F>(self, f: F) -> Self
+    where
+        F: FnOnce() + 'static,
+    {
+        let f = std::cell::Cell::new(Some(f));
+        self.apply(PendingAttribute::new_listener(move |_| {
+            if let Some(f) = f.take() {
+                silex_core::reactivity::on_cleanup(f);
+            }
+        }))
+    }
+
     // --- Event API ---
 
     fn on_click<F, M>(self, callback: F) -> Self
@@ -171,6 +273,18 @@ pub trait GlobalEventAttributes: AttributeBuilder {
         self.build_event(crate::event::click, callback)
     }
 
+    /// 以事件委托方式绑定事件：同类事件共享 document 上的一个监听器，
+    /// 适合 `For` 渲染的大量列表行。元素所属作用域销毁时自动注销。
+    fn on_delegated<E, F, M>(self, event: E, callback: F) -> Self
+    where
+        E: EventDescriptor + 'static,
+        F: EventHandler<E::EventType, M> + Clone + 'static,
+    {
+        self.apply(PendingAttribute::new_listener(move |el| {
+            crate::event::delegate::delegate_event(el, event, callback.clone());
+        }))
+    }
+
     fn on_input<F, M>(self, callback: F) -> Self
     where
         F: EventHandler<String, M> + Clone + 'static,
@@ -225,29 +339,50 @@ pub trait GlobalEventAttributes: AttributeBuilder {
             signal.set(value);
         });
 
+        this.apply(PendingAttribute::new_listener(
+            move |el: &web_sys::Element| {
+                bind_control_value(el.clone(), signal);
+            },
+        ))
+    }
+
+    /// 将控件与任意类型的信号双向绑定：输入经 `parse` 写入信号，信号经 `format` 写回控件。
+    ///
+    /// `parse` 返回 `None` 的输入被忽略，信号保留最后一个有效值；
+    /// 控件当前文本已能解析为信号的值时不会被改写 (例如输入 `"01"` 得到 `1`)，避免打断编辑。
+    ///
+    /// ```rust,ignore
+    /// let age = RwSignal::new(18);
+    /// input().bind_value_with(age, |s| s.trim().parse().ok(), |v| v.to_string())
+    /// ```
+    fn bind_value_with<T, P, F>(
+        self,
+        signal: silex_core::reactivity::RwSignal<T>,
+        parse: P,
+        format: F,
+    ) -> Self
+    where
+        T: PartialEq + 'static,
+        P: Fn(&str) -> Option<T> + 'static,
+        F: Fn(&T) -> String + 'static,
+    {
+        let binding = std::rc::Rc::new(ParsedBinding {
+            signal,
+            parse: Box::new(parse),
+            format: Box::new(format),
+        });
+        let this = self.on_input({
+            let binding = binding.clone();
+            move |text: String| binding.input(&text)
+        });
+
         this.apply(PendingAttribute::new_listener(
             move |el: &web_sys::Element| {
                 let dom_element = el.clone();
+                let binding = binding.clone();
                 silex_core::reactivity::Effect::new(move |_| {
-                    use silex_core::traits::RxGet;
-                    use wasm_bindgen::JsCast;
-                    let value = signal.get();
-                    if let Some(input) = dom_element.dyn_ref::<web_sys::HtmlInputElement>() {
-                        if input.value() != value {
-                            input.set_value(&value);
-                        }
-                    } else if let Some(area) = dom_element.dyn_ref::<web_sys::HtmlTextAreaElement>()
-                    {
-                        if area.value() != value {
-                            area.set_value(&value);
-                        }
-                    } else if let Some(select) = dom_element.dyn_ref::<web_sys::HtmlSelectElement>()
-                    {
-                        if select.value() != value {
-                            select.set_value(&value);
-                        }
-                    } else {
-                        let _ = dom_element.set_attribute("value", &value);
+                    if let Some(text) = binding.text_for(&element_value(&dom_element)) {
+                        set_element_value(&dom_element, &text);
                     }
                 });
             },
@@ -296,6 +431,132 @@ pub trait GlobalEventAttributes: AttributeBuilder {
 // 自动实现全局事件属性
 impl<T: AttributeBuilder> GlobalEventAttributes for T {}
 
+/// 读取表单控件的当前值，非表单控件读取 `value` 属性
+fn element_value(el: &web_sys::Element) -> String {
+    use wasm_bindgen::JsCast;
+    if let Some(input) = el.dyn_ref::<web_sys::HtmlInputElement>() {
+        input.value()
+    } else if let Some(area) = el.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+        area.value()
+    } else if let Some(select) = el.dyn_ref::<web_sys::HtmlSelectElement>() {
+        select.value()
+    } else {
+        el.get_attribute("value").unwrap_or_default()
+    }
+}
+
+fn set_element_value(el: &web_sys::Element, value: &str) {
+    use wasm_bindgen::JsCast;
+    if let Some(input) = el.dyn_ref::<web_sys::HtmlInputElement>() {
+        input.set_value(value);
+    } else if let Some(area) = el.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+        area.set_value(value);
+    } else if let Some(select) = el.dyn_ref::<web_sys::HtmlSelectElement>() {
+        select.set_value(value);
+    } else {
+        let _ = el.set_attribute("value", value);
+    }
+}
+
+/// 在当前挂载闸门放行后以元素调用 `f`
+fn run_on_mount<E: 'static>(el: E, f: impl FnOnce(&E) + 'static) {
+    silex_core::reactivity::on_mount(move || f(&el));
+}
+
+/// 受控 `value` 的读写：写回前先读取控件当前值，相同则跳过以保留光标位置
+pub(crate) trait ControlValue {
+    /// 是否为 input / textarea / select：只有它们在写入 `value` 时会重置光标
+    fn is_text_control(&self) -> bool;
+    fn control_value(&self) -> String;
+    fn set_control_value(&self, value: &str);
+    /// 直接设置 JS `value` 属性 (自定义元素、progress、meter、option 等)
+    fn set_value_property(&self, value: &str);
+}
+
+impl ControlValue for web_sys::Element {
+    fn is_text_control(&self) -> bool {
+        use wasm_bindgen::JsCast;
+        self.is_instance_of::<web_sys::HtmlInputElement>()
+            || self.is_instance_of::<web_sys::HtmlTextAreaElement>()
+            || self.is_instance_of::<web_sys::HtmlSelectElement>()
+    }
+
+    fn control_value(&self) -> String {
+        element_value(self)
+    }
+
+    fn set_control_value(&self, value: &str) {
+        set_element_value(self, value);
+    }
+
+    fn set_value_property(&self, value: &str) {
+        let _ = js_sys::Reflect::set(
+            self,
+            &wasm_bindgen::JsValue::from_str("value"),
+            &wasm_bindgen::JsValue::from_str(value),
+        );
+    }
+}
+
+/// 受控输入：仅在值与控件当前值不同时写回。
+/// 输入回流到信号后再写回相同的值会把光标重置到末尾，因此必须跳过。
+pub(crate) fn sync_control_value(el: &impl ControlValue, value: &str) {
+    if el.control_value() != value {
+        el.set_control_value(value);
+    }
+}
+
+/// `.prop("value", ..)`：文本控件走 [`sync_control_value`] 守卫，其他元素原样设置 JS 属性
+pub(crate) fn write_value_property(el: &impl ControlValue, value: &str) {
+    if el.is_text_control() {
+        sync_control_value(el, value);
+    } else {
+        el.set_value_property(value);
+    }
+}
+
+fn bind_control_value<E: ControlValue + 'static>(
+    el: E,
+    signal: silex_core::reactivity::RwSignal<String>,
+) {
+    silex_core::reactivity::Effect::new(move |_| {
+        use silex_core::traits::RxRead;
+        signal.with(|value| sync_control_value(&el, value));
+    });
+}
+
+type ParseFn<T> = Box<dyn Fn(&str) -> Option<T>>;
+type FormatFn<T> = Box<dyn Fn(&T) -> String>;
+
+/// `bind_value_with` 的解析/格式化逻辑，与 DOM 无关
+struct ParsedBinding<T: 'static> {
+    signal: silex_core::reactivity::RwSignal<T>,
+    parse: ParseFn<T>,
+    format: FormatFn<T>,
+}
+
+impl<T: PartialEq + 'static> ParsedBinding<T> {
+    /// 用户输入：仅在能解析时写入信号
+    fn input(&self, text: &str) {
+        use silex_core::traits::RxWrite;
+        if let Some(value) = (self.parse)(text) {
+            self.signal.set(value);
+        }
+    }
+
+    /// 追踪信号，返回需要写回控件的文本；当前文本已对应信号的值时返回 `None`
+    fn text_for(&self, current: &str) -> Option<String> {
+        use silex_core::traits::RxRead;
+        self.signal.with(|value| {
+            if (self.parse)(current).as_ref() == Some(value) {
+                None
+            } else {
+                Some((self.format)(value))
+            }
+        })
+    }
+}
+
 // --- AttributeBuilder Implementations for Erasure Types ---
 
 impl AttributeBuilder for crate::view::AnyView {
@@ -349,3 +610,222 @@ impl AttributeBuilder for crate::view::SharedView {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{RwSignal, create_scope};
+    use silex_core::traits::{RxGet, RxWrite};
+
+    #[derive(Default)]
+    struct MockState {
+        value: String,
+        caret: usize,
+        writes: usize,
+        property_writes: usize,
+        custom_element: bool,
+    }
+
+    /// 模拟浏览器输入框：程序写入 value 会把光标移到末尾
+    #[derive(Clone, Default)]
+    struct MockInput(std::rc::Rc<std::cell::RefCell<MockState>>);
+
+    impl MockInput {
+        /// 不是文本控件的元素 (e.g. 自定义元素)
+        fn custom_element() -> Self {
+            let el = Self::default();
+            el.0.borrow_mut().custom_element = true;
+            el
+        }
+
+        fn type_at(&self, caret: usize, ch: char) {
+            let mut state = self.0.borrow_mut();
+            state.value.insert(caret, ch);
+            state.caret = caret + 1;
+        }
+
+        fn caret(&self) -> usize {
+            self.0.borrow().caret
+        }
+
+        fn writes(&self) -> usize {
+            self.0.borrow().writes
+        }
+
+        fn property_writes(&self) -> usize {
+            self.0.borrow().property_writes
+        }
+    }
+
+    impl ControlValue for MockInput {
+        fn is_text_control(&self) -> bool {
+            !self.0.borrow().custom_element
+        }
+
+        fn control_value(&self) -> String {
+            self.0.borrow().value.clone()
+        }
+
+        fn set_control_value(&self, value: &str) {
+            let mut state = self.0.borrow_mut();
+            state.value = value.to_string();
+            state.caret = value.len();
+            state.writes += 1;
+        }
+
+        fn set_value_property(&self, value: &str) {
+            let mut state = self.0.borrow_mut();
+            state.value = value.to_string();
+            state.property_writes += 1;
+        }
+    }
+
+    #[test]
+    fn value_prop_guard_applies_only_to_text_controls() {
+        let input = MockInput::default();
+        write_value_property(&input, "abc");
+        write_value_property(&input, "abc");
+        assert_eq!((input.writes(), input.property_writes()), (1, 0));
+
+        // 其他元素总是设置 JS 属性，不经过 value 读取与 attribute 回退
+        let element = MockInput::custom_element();
+        write_value_property(&element, "0.5");
+        write_value_property(&element, "0.5");
+        assert_eq!((element.writes(), element.property_writes()), (0, 2));
+    }
+
+    #[test]
+    fn controlled_input_keeps_caret_while_typing() {
+        create_scope(|| {
+            let text = RwSignal::new("helo".to_string());
+            let input = MockInput::default();
+            bind_control_value(input.clone(), text);
+            assert_eq!(input.control_value(), "helo");
+            assert_eq!(input.writes(), 1);
+
+            // 在中间输入字符，input 事件把控件的值写回信号
+            input.type_at(3, 'l');
+            text.set(input.control_value());
+            assert_eq!(input.control_value(), "hello");
+            assert_eq!(input.caret(), 4, "caret stays where the user typed");
+            assert_eq!(input.writes(), 1);
+
+            // 外部修改仍会写入控件
+            text.set("reset".to_string());
+            assert_eq!(input.control_value(), "reset");
+            assert_eq!(input.writes(), 2);
+        });
+    }
+
+    #[test]
+    fn mount_hooks_follow_attach_and_dispose() {
+        use silex_core::reactivity::{MountGate, dispose, on_cleanup};
+        use std::cell::{Cell, RefCell};
+        use std::rc::Rc;
+
+        let attached = Rc::new(Cell::new(false));
+        let log = Rc::new(RefCell::new(Vec::new()));
+        create_scope(|| {
+            let gate = MountGate::provide();
+            let element = create_scope(|| {
+                let mount_log = log.clone();
+                run_on_mount(attached.clone(), move |el: &Rc<Cell<bool>>| {
+                    mount_log
+                        .borrow_mut()
+                        .push(if el.get() { "mount" } else { "early" });
+                });
+                let log = log.clone();
+                on_cleanup(move || log.borrow_mut().push("unmount"));
+            });
+            assert!(log.borrow().is_empty(), "not run during construction");
+
+            // 模拟插入文档
+            attached.set(true);
+            gate.mark_mounted();
+            assert_eq!(*log.borrow(), ["mount"]);
+
+            dispose(element);
+            assert_eq!(*log.borrow(), ["mount", "unmount"]);
+        });
+    }
+
+    #[derive(Default)]
+    struct Attrs(Vec<PendingAttribute>);
+
+    impl AttributeBuilder for Attrs {
+        fn build_attribute<V>(mut self, target: ApplyTarget, value: V) -> Self
+        where
+            V: IntoStorable,
+        {
+            self.0.push(PendingAttribute::build(
+                value.into_storable(),
+                target.into(),
+            ));
+            self
+        }
+
+        fn build_event<E, F, M>(self, _event: E, _callback: F) -> Self
+        where
+            E: EventDescriptor + 'static,
+            F: EventHandler<E::EventType, M> + Clone + 'static,
+        {
+            self
+        }
+    }
+
+    /// 仅检查类型：自定义元素可设置 JS 属性、普通属性与子节点
+    #[allow(dead_code)]
+    fn custom_element_builder_compiles(data: wasm_bindgen::JsValue) {
+        let _ = crate::element::custom_element("x-foo")
+            .prop_js("data", data)
+            .attr("theme", "dark")
+            .child("label");
+    }
+
+    #[test]
+    fn prop_js_sets_a_js_property() {
+        let attrs = Attrs::default().prop_js("data", wasm_bindgen::JsValue::NULL);
+        let [attr] = attrs.0.as_slice() else {
+            panic!("expected a single attribute");
+        };
+        assert!(matches!(
+            &attr.op,
+            AttrOp::Update {
+                name,
+                target: AttrTarget::Prop,
+                data: AttrData::StaticJs(_),
+            } if name == "data"
+        ));
+    }
+
+    #[test]
+    fn parsed_binding_keeps_last_valid_value() {
+        create_scope(|| {
+            let count = RwSignal::new(5);
+            let binding = ParsedBinding {
+                signal: count,
+                parse: Box::new(|s: &str| s.trim().parse::<i32>().ok()),
+                format: Box::new(|v: &i32| v.to_string()),
+            };
+            assert_eq!(binding.text_for("").as_deref(), Some("5"));
+
+            binding.input("42");
+            assert_eq!(count.get(), 42);
+            assert_eq!(binding.text_for("42"), None);
+
+            // 无效输入不改动信号，也不改写正在编辑的文本
+            binding.input("42x");
+            assert_eq!(count.get(), 42);
+            binding.input("-");
+            assert_eq!(count.get(), 42);
+
+            // 能解析为当前值的文本保持原样
+            binding.input("042");
+            assert_eq!(binding.text_for("042"), None);
+
+            // 信号从外部更新时写回格式化后的文本
+            count.set(7);
+            assert_eq!(binding.text_for("042").as_deref(), Some("7"));
+        });
+    }
+}
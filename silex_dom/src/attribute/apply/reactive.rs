@@ -271,13 +271,7 @@ impl ReactiveApply for String {
             OwnedApplyTarget::Prop(name) => AttrOp::Update {
                 name,
                 target: AttrTarget::Prop,
-                data: AttrData::ReactiveJs({
-                    let rx = rx.clone();
-                    silex_core::Rx::derive(Box::new(move || {
-                        use silex_core::traits::RxGet;
-                        JsValue::from_str(&rx.get())
-                    }))
-                }),
+                data: AttrData::ReactiveString(rx),
             },
             OwnedApplyTarget::Class => AttrOp::AddReactiveClasses(rx),
             OwnedApplyTarget::Style => AttrOp::BindReactiveStyleSheet(rx),
@@ -391,3 +385,112 @@ impl ReactiveApply for bool {
         Some(op)
     }
 }
+
+// --- Option: `Some` 写入、`None` 移除属性 ---
+
+/// `Option` 属性绑定的写入端：`Some` 时 `setAttribute`，`None` 时 `removeAttribute`
+pub(crate) trait AttributeSink {
+    fn set_attr(&self, name: &str, value: &str);
+    fn remove_attr(&self, name: &str);
+}
+
+impl AttributeSink for WebElem {
+    fn set_attr(&self, name: &str, value: &str) {
+        let _ = self.set_attribute(name, value);
+    }
+
+    fn remove_attr(&self, name: &str) {
+        let _ = self.remove_attribute(name);
+    }
+}
+
+pub(crate) fn bind_optional_attribute_internal<E: AttributeSink + 'static>(
+    el: E,
+    name: Cow<'static, str>,
+    rx: silex_core::Rx<Option<String>, silex_core::RxValueKind>,
+) {
+    Effect::new(move |_| {
+        use silex_core::traits::RxGet;
+        match rx.get() {
+            Some(value) => el.set_attr(&name, &value),
+            None => el.remove_attr(&name),
+        }
+    });
+}
+
+impl<T: std::fmt::Display + Clone + 'static> ReactiveApply for Option<T> {
+    fn apply_to_dom(
+        rx: silex_core::Rx<Self, silex_core::RxValueKind>,
+        el: WebElem,
+        target: OwnedApplyTarget,
+    ) {
+        use silex_core::traits::RxGet;
+        match target {
+            OwnedApplyTarget::Attr(name) if name != "class" && name != "style" => {
+                let rx = silex_core::Rx::derive(Box::new(move || {
+                    rx.get().map(|value| value.to_string())
+                }));
+                bind_optional_attribute_internal(el, name, rx);
+            }
+            // 类名、样式与属性 (property) 按字符串处理，`None` 视为空字符串
+            target => {
+                let rx = silex_core::Rx::derive(Box::new(move || {
+                    rx.get().map(|value| value.to_string()).unwrap_or_default()
+                }));
+                apply_string_reactive_internal(el, target, rx);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{RwSignal, create_scope};
+    use silex_core::traits::{IntoRx, RxWrite};
+    use std::collections::HashMap;
+
+    #[derive(Clone, Default)]
+    struct MockElement(Rc<RefCell<HashMap<String, String>>>);
+
+    impl AttributeSink for MockElement {
+        fn set_attr(&self, name: &str, value: &str) {
+            self.0
+                .borrow_mut()
+                .insert(name.to_string(), value.to_string());
+        }
+
+        fn remove_attr(&self, name: &str) {
+            self.0.borrow_mut().remove(name);
+        }
+    }
+
+    // `.attr` 直接接受 `Option` 信号
+    #[allow(dead_code)]
+    fn attr_accepts_option_signal(
+        el: crate::element::Element,
+        title: silex_core::reactivity::Signal<Option<String>>,
+    ) -> crate::element::Element {
+        use crate::attribute::AttributeBuilder;
+        el.attr("title", title)
+    }
+
+    #[test]
+    fn optional_attribute_is_set_and_removed() {
+        create_scope(|| {
+            let el = MockElement::default();
+            let title = RwSignal::new(None::<String>);
+            bind_optional_attribute_internal(el.clone(), "title".into(), title.into_rx());
+            assert_eq!(el.0.borrow().get("title"), None);
+
+            title.set(Some("hello".to_string()));
+            assert_eq!(
+                el.0.borrow().get("title").map(String::as_str),
+                Some("hello")
+            );
+
+            title.set(None);
+            assert_eq!(el.0.borrow().get("title"), None);
+        });
+    }
+}
@@ -330,7 +330,10 @@ fn apply_combined_styles_internal(
 // --- Kernel Functions (Non-generic DOM operations) ---
 
 pub(crate) fn set_string_property_internal(el: &Element, name: &str, value: &str, is_prop: bool) {
-    if is_prop {
+    if is_prop && name == "value" {
+        // 受控输入：文本控件与当前值相同时不写回，避免光标跳到末尾
+        crate::attribute::write_value_property(el, value);
+    } else if is_prop {
         let _ = js_sys::Reflect::set(el, &JsValue::from_str(name), &JsValue::from_str(value));
     } else {
         match name {
@@ -17,6 +17,25 @@ pub fn text<V: crate::view::MountRef>(content: V) -> V {
     content
 }
 
+/// 与 [`text`] 相同地以单个文本节点显示信号，更新时改写同一节点的内容。
+/// 仅在格式化后的字符串变化时写入 DOM，重复写入相同值不会触碰节点。
+pub fn text_memo<S>(content: S) -> crate::view::TextMemo<S::Value>
+where
+    S: silex_core::traits::IntoSignal + silex_core::traits::RxData,
+    S::Value: std::fmt::Display + silex_core::traits::RxCloneData,
+{
+    crate::view::TextMemo {
+        source: content.into_signal(),
+    }
+}
+
+/// 创建任意标签名的自定义元素 (Web Components)，如 `custom_element("my-widget")`。
+///
+/// 属性经 `attr` 写入，复杂数据 (对象、数组) 用 `prop_js` 作为 JS 属性传入，子节点用 `child` 追加。
+pub fn custom_element(tag: &str) -> TypedElement<CustomTag> {
+    TypedElement::new(tag)
+}
+
 /// 基础 DOM 元素包装器
 #[derive(Clone, PartialEq)]
 pub struct Element {
@@ -27,11 +46,87 @@ pub fn mount_to_body<V: crate::view::Mount>(view: V) {
     let document = crate::document();
     let body = document.body().expect("No body element");
     let node: web_sys::Node = body.into();
+    // 整个应用的生命周期与页面一致，无需卸载
+    mount_to(view, &node).forget();
+}
+
+/// 将视图挂载到 `parent` 末尾，返回用于卸载的句柄
+///
+/// 适合在非 Silex 页面中嵌入组件或热重载：调用 [`MountHandle::unmount`] 或丢弃句柄时，
+/// 销毁根作用域 (及所有子作用域中的 Effect 与订阅) 并移除挂载范围内的所有节点。
+pub fn mount_to<V: crate::view::Mount>(view: V, parent: &web_sys::Node) -> MountHandle {
+    #[cfg(debug_assertions)]
+    silex_core::reactivity::debug::use_performance_clock();
+
+    // 以一对注释节点标记挂载范围：顶层的 Show/Dynamic/For 之后重渲染插入的节点同样位于其中
+    let document = crate::document();
+    let start: web_sys::Node = document.create_comment("silex-mount-start").into();
+    let end: web_sys::Node = document.create_comment("silex-mount-end").into();
+    if let Err(e) = parent.append_child(&start).map_err(SilexError::from) {
+        silex_core::error::handle_error(e);
+    }
 
     // Create a root reactive scope to ensure context and effects work correctly
-    silex_core::reactivity::create_scope(move || {
-        view.mount(&node, Vec::new());
+    let scope = silex_core::reactivity::create_scope(|| {
+        let gate = silex_core::reactivity::MountGate::provide();
+        // 整棵树的初次渲染只刷新一次 Effect 队列
+        silex_core::reactivity::mount_batch(|| view.mount(parent, Vec::new()));
+        // 子树已插入文档，释放所有 on_mount 回调
+        gate.mark_mounted();
     });
+
+    if let Err(e) = parent.append_child(&end).map_err(SilexError::from) {
+        silex_core::error::handle_error(e);
+    }
+    MountHandle::new(scope, Box::new(move || remove_range(&start, &end)))
+}
+
+/// 移除 `start` 到 `end` (含两端) 之间的所有节点
+fn remove_range(start: &web_sys::Node, end: &web_sys::Node) {
+    let Some(parent) = start.parent_node() else {
+        return;
+    };
+    let mut cursor = Some(start.clone());
+    while let Some(node) = cursor {
+        cursor = node.next_sibling();
+        let _ = parent.remove_child(&node);
+        if &node == end {
+            break;
+        }
+    }
+}
+
+/// [`mount_to`] 返回的卸载句柄，丢弃时自动卸载
+#[must_use = "dropping the handle unmounts the view immediately"]
+pub struct MountHandle {
+    scope: silex_core::reactivity::NodeId,
+    remove_nodes: Option<Box<dyn FnOnce()>>,
+}
+
+impl MountHandle {
+    fn new(scope: silex_core::reactivity::NodeId, remove_nodes: Box<dyn FnOnce()>) -> Self {
+        Self {
+            scope,
+            remove_nodes: Some(remove_nodes),
+        }
+    }
+
+    /// 销毁根作用域并移除挂载的节点
+    pub fn unmount(self) {}
+
+    /// 放弃句柄，视图保持挂载直到页面卸载
+    pub fn forget(mut self) {
+        self.remove_nodes = None;
+    }
+}
+
+impl Drop for MountHandle {
+    fn drop(&mut self) {
+        if let Some(remove_nodes) = self.remove_nodes.take() {
+            silex_core::reactivity::dispose(self.scope);
+            remove_nodes();
+        }
+    }
 }
 
 impl Element {
@@ -175,6 +270,14 @@ impl<T> TypedElement<T> {
     }
 }
 
+impl TypedElement<CustomTag> {
+    /// 追加子节点
+    pub fn child<V: crate::view::MountRef>(self, child: V) -> Self {
+        child.mount_ref(&self.element.dom_element, Vec::new());
+        self
+    }
+}
+
 impl<T> AttributeBuilder for TypedElement<T> {
     fn build_attribute<V>(self, target: ApplyTarget, value: V) -> Self
     where
@@ -276,3 +379,57 @@ where
         drop(closure);
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{Effect, RwSignal, create_scope};
+    use silex_core::traits::{RxGet, RxWrite};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn unmount_disposes_scopes_before_removing_the_range() {
+        let source = RwSignal::new(0);
+        let runs = Rc::new(Cell::new(0));
+        let removed = Rc::new(Cell::new(false));
+
+        let scope = create_scope(|| {
+            // 嵌套的子作用域 (组件) 同样随根作用域销毁
+            create_scope(|| {
+                let runs = runs.clone();
+                Effect::new(move |_| {
+                    source.get();
+                    runs.set(runs.get() + 1);
+                });
+            });
+        });
+        let handle = MountHandle::new(scope, {
+            let removed = removed.clone();
+            Box::new(move || {
+                // 移除节点时作用域已销毁，不会再触发重渲染
+                source.set(3);
+                removed.set(true)
+            })
+        });
+
+        source.set(1);
+        assert_eq!(runs.get(), 2);
+
+        handle.unmount();
+        assert!(removed.get(), "removal callback ran");
+        source.set(2);
+        assert_eq!(runs.get(), 2, "subscribers cleared");
+    }
+
+    #[test]
+    fn forgotten_handle_keeps_the_view() {
+        let removed = Rc::new(Cell::new(false));
+        let handle = MountHandle::new(create_scope(|| {}), {
+            let removed = removed.clone();
+            Box::new(move || removed.set(true))
+        });
+        handle.forget();
+        assert!(!removed.get());
+    }
+}
@@ -37,6 +37,12 @@ pub trait TableHeaderTag: Tag {}
 // 6. SVG Tags Marker (Trait only)
 pub trait SvgTag: Tag {}
 
+/// 自定义元素 (Web Components) 的标记，见 [`custom_element`](crate::element::custom_element)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomTag;
+impl Tag for CustomTag {}
+impl TextTag for CustomTag {}
+
 // --- Macros ---
 
 #[macro_export]
@@ -20,6 +20,7 @@ pub trait EventDescriptor: Copy + Clone + 'static {
     }
 }
 
+pub mod delegate;
 pub mod types;
 pub use types::*;
 
@@ -95,3 +96,77 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attribute::AttributeBuilder;
+
+    /// 事件描述符对应的 `web_sys` 类型
+    fn payload<E: EventDescriptor>(event: E) -> (String, &'static str) {
+        (
+            event.name().into_owned(),
+            std::any::type_name::<E::EventType>(),
+        )
+    }
+
+    /// 仅检查类型：处理函数直接以具体事件类型作为参数
+    #[allow(dead_code)]
+    fn typed_handlers_compile(el: crate::element::Element) -> crate::element::Element {
+        el.on(keydown, |e: web_sys::KeyboardEvent| {
+            let _ = e.key();
+        })
+        .on(pointermove, |e: web_sys::PointerEvent| {
+            let _ = e.pointer_id();
+        })
+        .on(paste, |e: web_sys::ClipboardEvent| {
+            let _ = e.type_();
+        })
+    }
+
+    #[test]
+    fn generated_events_carry_concrete_types() {
+        assert_eq!(
+            payload(keydown),
+            (
+                "keydown".into(),
+                std::any::type_name::<web_sys::KeyboardEvent>()
+            )
+        );
+        assert_eq!(
+            payload(pointermove).1,
+            std::any::type_name::<web_sys::PointerEvent>()
+        );
+        assert_eq!(
+            payload(popstate).1,
+            std::any::type_name::<web_sys::PopStateEvent>()
+        );
+        assert_eq!(payload(change).1, std::any::type_name::<web_sys::Event>());
+        assert_eq!(
+            payload(devicemotion).1,
+            std::any::type_name::<web_sys::DeviceMotionEvent>()
+        );
+        assert_eq!(
+            payload(gamepadconnected).1,
+            std::any::type_name::<web_sys::GamepadEvent>()
+        );
+        assert_eq!(
+            payload(gamepaddisconnected).1,
+            std::any::type_name::<web_sys::GamepadEvent>()
+        );
+        assert_eq!(
+            payload(progress).1,
+            std::any::type_name::<web_sys::ProgressEvent>()
+        );
+        assert_eq!(
+            payload(close).1,
+            std::any::type_name::<web_sys::CloseEvent>()
+        );
+
+        // 处理函数的参数类型由事件描述符决定
+        let _: Box<dyn FnMut(<keydown as EventDescriptor>::EventType)> =
+            EventHandler::<_, WithEventArg>::into_handler(|e: web_sys::KeyboardEvent| {
+                let _ = e.key();
+            });
+    }
+}
@@ -0,0 +1,370 @@
+use super::{EventDescriptor, EventHandler};
+use silex_core::reactivity::on_cleanup;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Element as WebElem;
+
+/// 记录委托 key 的 JS 属性名
+///
+/// 使用 expando 属性而非 HTML 属性：`cloneNode` (模板克隆) 不会复制它，克隆出的元素不会共享原元素的处理函数。
+pub const DELEGATE_PROP: &str = "__silexDelegateKey";
+
+/// 事件委托中的节点：从事件目标逐级向上查找注册了处理函数的元素
+pub trait DelegateTarget: Sized {
+    /// 节点的委托 key (未注册过委托事件时为 `None`)
+    fn delegate_key(&self) -> Option<u64>;
+    /// 父节点
+    fn parent(&self) -> Option<Self>;
+}
+
+impl DelegateTarget for WebElem {
+    fn delegate_key(&self) -> Option<u64> {
+        crate::helpers::get_property(self, DELEGATE_PROP)
+            .ok()?
+            .as_f64()
+            .map(|key| key as u64)
+    }
+
+    fn parent(&self) -> Option<Self> {
+        self.parent_element()
+    }
+}
+
+/// 委托分发的事件：需要能够得知处理函数是否调用了 `stopPropagation`
+pub trait DelegateEvent {
+    fn propagation_stopped(&self) -> bool;
+}
+
+impl DelegateEvent for web_sys::Event {
+    fn propagation_stopped(&self) -> bool {
+        self.cancel_bubble()
+    }
+}
+
+type Handler<Ev> = Rc<RefCell<Box<dyn FnMut(&Ev)>>>;
+/// 注册 token 与处理函数
+type Registration<Ev> = (u64, Handler<Ev>);
+
+/// 委托事件注册表：以 (事件名, 元素 key) 为索引保存处理函数
+///
+/// 同一元素的同一事件可注册多个处理函数，按注册顺序调用；每次注册返回唯一的 token，
+/// 注销时只移除该 token 对应的处理函数。
+pub struct DelegateRegistry<Ev> {
+    handlers: HashMap<(String, u64), Vec<Registration<Ev>>>,
+    next_token: u64,
+}
+
+impl<Ev> Default for DelegateRegistry<Ev> {
+    fn default() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            next_token: 0,
+        }
+    }
+}
+
+impl<Ev: DelegateEvent> DelegateRegistry<Ev> {
+    /// 追加处理函数，返回用于注销的 token
+    pub fn register(&mut self, event: &str, key: u64, handler: Box<dyn FnMut(&Ev)>) -> u64 {
+        self.next_token += 1;
+        let token = self.next_token;
+        self.handlers
+            .entry((event.to_string(), key))
+            .or_default()
+            .push((token, Rc::new(RefCell::new(handler))));
+        token
+    }
+
+    /// 移除 `token` 对应的处理函数，同一元素上的其他处理函数保持不变
+    pub fn unregister(&mut self, event: &str, key: u64, token: u64) {
+        let index = (event.to_string(), key);
+        if let Some(handlers) = self.handlers.get_mut(&index) {
+            handlers.retain(|(t, _)| *t != token);
+            if handlers.is_empty() {
+                self.handlers.remove(&index);
+            }
+        }
+    }
+
+    pub fn is_registered(&self, event: &str, key: u64) -> bool {
+        self.handlers.contains_key(&(event.to_string(), key))
+    }
+
+    /// 从 `target` 向上收集每个元素上需要依次调用的处理函数 (模拟冒泡顺序)
+    fn route<N: DelegateTarget>(&self, event: &str, target: Option<N>) -> Vec<Vec<Handler<Ev>>> {
+        let mut route = Vec::new();
+        let mut node = target;
+        while let Some(current) = node {
+            if let Some(key) = current.delegate_key()
+                && let Some(handlers) = self.handlers.get(&(event.to_string(), key))
+            {
+                route.push(
+                    handlers
+                        .iter()
+                        .map(|(_, handler)| handler.clone())
+                        .collect(),
+                );
+            }
+            node = current.parent();
+        }
+        route
+    }
+}
+
+/// 将事件分发给从目标到根路径上注册的处理函数，遇到 `stopPropagation` 时停止
+pub fn dispatch<N, Ev>(
+    registry: &RefCell<DelegateRegistry<Ev>>,
+    event: &str,
+    target: Option<N>,
+    ev: &Ev,
+) where
+    N: DelegateTarget,
+    Ev: DelegateEvent,
+{
+    // 先收集路径再调用，处理函数中可以安全地注册/注销委托事件
+    let route = registry.borrow().route(event, target);
+    for handlers in route {
+        // 与 DOM 一致：stopPropagation 不影响同一元素上的其他处理函数
+        for handler in handlers {
+            (handler.borrow_mut())(ev);
+        }
+        if ev.propagation_stopped() {
+            break;
+        }
+    }
+}
+
+/// 在当前作用域中注册处理函数，作用域销毁 (e.g. 列表行被移除) 时自动注销
+pub fn register_scoped<Ev: DelegateEvent + 'static>(
+    registry: &Rc<RefCell<DelegateRegistry<Ev>>>,
+    event: &str,
+    key: u64,
+    handler: Box<dyn FnMut(&Ev)>,
+) {
+    let token = registry.borrow_mut().register(event, key, handler);
+    let registry = registry.clone();
+    let event = event.to_string();
+    on_cleanup(move || registry.borrow_mut().unregister(&event, key, token));
+}
+
+thread_local! {
+    static REGISTRY: Rc<RefCell<DelegateRegistry<web_sys::Event>>> = Rc::default();
+    static INSTALLED: RefCell<HashSet<String>> = RefCell::default();
+    static NEXT_KEY: Cell<u64> = const { Cell::new(0) };
+}
+
+/// 获取元素的委托 key，首次注册时分配并写入 [`DELEGATE_PROP`] 属性
+fn element_key(el: &WebElem) -> u64 {
+    if let Some(key) = el.delegate_key() {
+        return key;
+    }
+    let key = NEXT_KEY.with(|next| {
+        next.set(next.get() + 1);
+        next.get()
+    });
+    crate::helpers::set_property(el, DELEGATE_PROP, &Some(JsValue::from_f64(key as f64)));
+    key
+}
+
+/// 每种事件只在 document 上安装一次监听器 (不冒泡的事件在捕获阶段监听)
+fn ensure_listener(event: &str, capture: bool) {
+    if !INSTALLED.with(|installed| installed.borrow_mut().insert(event.to_string())) {
+        return;
+    }
+    let name = event.to_string();
+    let listener = Closure::wrap(Box::new(move |e: web_sys::Event| {
+        let target = e.target().and_then(|t| t.dyn_into::<WebElem>().ok());
+        REGISTRY.with(|registry| dispatch(registry, &name, target, &e));
+    }) as Box<dyn FnMut(web_sys::Event)>);
+
+    let _ = crate::document().add_event_listener_with_callback_and_bool(
+        event,
+        listener.as_ref().unchecked_ref(),
+        capture,
+    );
+    // 监听器与应用同生命周期
+    listener.forget();
+}
+
+/// 以事件委托的方式为元素绑定事件：所有元素共享 document 上的同一个监听器
+///
+/// 处理函数中 `event.current_target()` 为 document，而非注册的元素。
+pub fn delegate_event<E, F, M>(el: &WebElem, event: E, callback: F)
+where
+    E: EventDescriptor,
+    F: EventHandler<E::EventType, M>,
+{
+    let mut handler = callback.into_handler();
+    let name = event.name();
+    ensure_listener(&name, !event.bubbles());
+    REGISTRY.with(|registry| {
+        register_scoped(
+            registry,
+            &name,
+            element_key(el),
+            Box::new(move |e: &web_sys::Event| handler(e.clone().unchecked_into())),
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{create_scope, dispose};
+
+    /// 模拟 DOM 树中的节点
+    #[derive(Clone)]
+    struct Node {
+        key: Option<u64>,
+        parent: Option<Rc<Node>>,
+    }
+
+    impl Node {
+        fn child(self: &Rc<Self>, key: Option<u64>) -> Rc<Node> {
+            Rc::new(Node {
+                key,
+                parent: Some(self.clone()),
+            })
+        }
+    }
+
+    impl DelegateTarget for Rc<Node> {
+        fn delegate_key(&self) -> Option<u64> {
+            self.key
+        }
+
+        fn parent(&self) -> Option<Self> {
+            self.parent.clone()
+        }
+    }
+
+    #[derive(Default)]
+    struct Click {
+        stopped: Cell<bool>,
+    }
+
+    impl DelegateEvent for Click {
+        fn propagation_stopped(&self) -> bool {
+            self.stopped.get()
+        }
+    }
+
+    fn click(registry: &RefCell<DelegateRegistry<Click>>, target: &Rc<Node>) {
+        dispatch(registry, "click", Some(target.clone()), &Click::default());
+    }
+
+    #[test]
+    fn click_reaches_row_handler_until_row_is_removed() {
+        let registry: Rc<RefCell<DelegateRegistry<Click>>> = Rc::default();
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let list = Rc::new(Node {
+            key: Some(100),
+            parent: None,
+        });
+        let rows: Vec<_> = (1..=3).map(|key| list.child(Some(key))).collect();
+        // 点击行内未注册的子元素 (如行内文本)
+        let label = rows[1].child(None);
+
+        let scopes: Vec<_> = (1..=3u64)
+            .map(|key| {
+                let log = log.clone();
+                let registry = registry.clone();
+                create_scope(move || {
+                    register_scoped(
+                        &registry,
+                        "click",
+                        key,
+                        Box::new(move |_| log.borrow_mut().push(key)),
+                    );
+                })
+            })
+            .collect();
+
+        click(&registry, &label);
+        click(&registry, &rows[2]);
+        assert_eq!(*log.borrow(), vec![2, 3]);
+
+        // 移除第二行
+        dispose(scopes[1]);
+        assert!(!registry.borrow().is_registered("click", 2));
+        click(&registry, &label);
+        assert_eq!(*log.borrow(), vec![2, 3]);
+        assert!(registry.borrow().is_registered("click", 1));
+    }
+
+    #[test]
+    fn disposing_an_old_scope_keeps_newer_handlers() {
+        let registry: Rc<RefCell<DelegateRegistry<Click>>> = Rc::default();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let row = Rc::new(Node {
+            key: Some(1),
+            parent: None,
+        });
+
+        let scope = |tag: &'static str| {
+            let log = log.clone();
+            let registry = registry.clone();
+            create_scope(move || {
+                register_scoped(
+                    &registry,
+                    "click",
+                    1,
+                    Box::new(move |_| log.borrow_mut().push(tag)),
+                );
+            })
+        };
+        let old = scope("old");
+        let new = scope("new");
+
+        // 同一元素上的第二次注册不会替换第一次
+        click(&registry, &row);
+        assert_eq!(*log.borrow(), vec!["old", "new"]);
+
+        dispose(old);
+        click(&registry, &row);
+        assert_eq!(*log.borrow(), vec!["old", "new", "new"]);
+
+        dispose(new);
+        assert!(!registry.borrow().is_registered("click", 1));
+    }
+
+    #[test]
+    fn stop_propagation_halts_bubbling() {
+        let registry: RefCell<DelegateRegistry<Click>> = RefCell::default();
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let list = Rc::new(Node {
+            key: Some(1),
+            parent: None,
+        });
+        let row = list.child(Some(2));
+
+        for key in [1, 2] {
+            let log = log.clone();
+            registry.borrow_mut().register(
+                "click",
+                key,
+                Box::new(move |e: &Click| {
+                    log.borrow_mut().push(key);
+                    if key == 2 && log.borrow().len() > 2 {
+                        e.stopped.set(true);
+                    }
+                }),
+            );
+        }
+
+        click(&registry, &row);
+        assert_eq!(
+            *log.borrow(),
+            vec![2, 1],
+            "bubbles from the row to the list"
+        );
+
+        click(&registry, &row);
+        assert_eq!(*log.borrow(), vec![2, 1, 2], "row stopped propagation");
+    }
+}
@@ -1,3 +1,5 @@
+// 自动生成的 DOM 事件类型 (silex_codegen)，请勿手动修改
+
 use super::EventDescriptor;
 use std::borrow::Cow;
 
@@ -18,71 +20,40 @@ macro_rules! generate_events {
     };
 }
 
-// === Mouse Events ===
 generate_events! {
+    abort: web_sys::UiEvent,
+    afterprint: web_sys::Event,
+    animationcancel: web_sys::AnimationEvent,
+    animationend: web_sys::AnimationEvent,
+    animationiteration: web_sys::AnimationEvent,
+    animationstart: web_sys::AnimationEvent,
+    appinstalled: web_sys::Event,
+    auxclick: web_sys::MouseEvent,
+    beforeinput: web_sys::InputEvent,
+    beforeinstallprompt: web_sys::Event,
+    beforematch: web_sys::Event,
+    beforeprint: web_sys::Event,
+    beforetoggle: web_sys::ToggleEvent,
+    beforeunload: web_sys::BeforeUnloadEvent,
+    blur: web_sys::FocusEvent,
+    cancel: web_sys::Event,
+    canplay: web_sys::Event,
+    canplaythrough: web_sys::Event,
+    change: web_sys::Event, // 'change' target is useful, but the event itself is generic
     click: web_sys::MouseEvent,
-    dblclick: web_sys::MouseEvent,
-    mousedown: web_sys::MouseEvent,
-    mouseup: web_sys::MouseEvent,
-    mousemove: web_sys::MouseEvent,
-    mouseover: web_sys::MouseEvent,
-    mouseout: web_sys::MouseEvent,
-    mouseenter: web_sys::MouseEvent,
-    mouseleave: web_sys::MouseEvent,
+    close: web_sys::CloseEvent,
+    command: web_sys::Event,
+    compositionend: web_sys::CompositionEvent,
+    compositionstart: web_sys::CompositionEvent,
+    compositionupdate: web_sys::CompositionEvent,
+    contentvisibilityautostatechange: web_sys::Event,
     contextmenu: web_sys::MouseEvent,
-}
-
-// === Keyboard Events ===
-generate_events! {
-    keydown: web_sys::KeyboardEvent,
-    keypress: web_sys::KeyboardEvent,
-    keyup: web_sys::KeyboardEvent,
-}
-
-// === Form Events ===
-generate_events! {
-    change: web_sys::Event, // 'change' target is useful, but the event itself is generic
-    input: web_sys::InputEvent,
-    submit: web_sys::SubmitEvent,
-    reset: web_sys::Event,
-    invalid: web_sys::Event,
-}
-
-// === Focus Events ===
-generate_events! {
-    focus: web_sys::FocusEvent,
-    blur: web_sys::FocusEvent,
-    focusin: web_sys::FocusEvent,
-    focusout: web_sys::FocusEvent,
-}
-
-// === UI Events ===
-generate_events! {
-    scroll: web_sys::Event,
-    resize: web_sys::UiEvent,
-    load: web_sys::Event,
-    unload: web_sys::Event,
-    abort: web_sys::UiEvent,
-    error: web_sys::ErrorEvent,
-    select: web_sys::Event,
-}
-
-// === Pointer Events ===
-generate_events! {
-    pointerdown: web_sys::PointerEvent,
-    pointermove: web_sys::PointerEvent,
-    pointerup: web_sys::PointerEvent,
-    pointercancel: web_sys::PointerEvent,
-    pointerenter: web_sys::PointerEvent,
-    pointerleave: web_sys::PointerEvent,
-    pointerover: web_sys::PointerEvent,
-    pointerout: web_sys::PointerEvent,
-    gotpointercapture: web_sys::PointerEvent,
-    lostpointercapture: web_sys::PointerEvent,
-}
-
-// === Drag Events ===
-generate_events! {
+    copy: web_sys::ClipboardEvent,
+    cut: web_sys::ClipboardEvent,
+    dblclick: web_sys::MouseEvent,
+    devicemotion: web_sys::DeviceMotionEvent,
+    deviceorientation: web_sys::Event,
+    deviceorientationabsolute: web_sys::Event,
     drag: web_sys::DragEvent,
     dragend: web_sys::DragEvent,
     dragenter: web_sys::DragEvent,
@@ -91,32 +62,95 @@ generate_events! {
     dragover: web_sys::DragEvent,
     dragstart: web_sys::DragEvent,
     drop: web_sys::DragEvent,
-}
-
-// === Touch Events ===
-generate_events! {
-    touchstart: web_sys::TouchEvent,
+    durationchange: web_sys::Event,
+    emptied: web_sys::Event,
+    encrypted: web_sys::Event,
+    ended: web_sys::Event,
+    error: web_sys::ErrorEvent,
+    focus: web_sys::FocusEvent,
+    focusin: web_sys::FocusEvent,
+    focusout: web_sys::FocusEvent,
+    formdata: web_sys::Event,
+    fullscreenchange: web_sys::Event,
+    fullscreenerror: web_sys::Event,
+    gamepadconnected: web_sys::GamepadEvent,
+    gamepaddisconnected: web_sys::GamepadEvent,
+    gotpointercapture: web_sys::PointerEvent,
+    hashchange: web_sys::HashChangeEvent,
+    input: web_sys::InputEvent,
+    invalid: web_sys::Event,
+    keydown: web_sys::KeyboardEvent,
+    keypress: web_sys::KeyboardEvent,
+    keyup: web_sys::KeyboardEvent,
+    languagechange: web_sys::Event,
+    load: web_sys::Event,
+    loadeddata: web_sys::Event,
+    loadedmetadata: web_sys::Event,
+    loadstart: web_sys::Event,
+    lostpointercapture: web_sys::PointerEvent,
+    message: web_sys::MessageEvent,
+    messageerror: web_sys::MessageEvent,
+    mousedown: web_sys::MouseEvent,
+    mouseenter: web_sys::MouseEvent,
+    mouseleave: web_sys::MouseEvent,
+    mousemove: web_sys::MouseEvent,
+    mouseout: web_sys::MouseEvent,
+    mouseover: web_sys::MouseEvent,
+    mouseup: web_sys::MouseEvent,
+    offline: web_sys::Event,
+    online: web_sys::Event,
+    pagehide: web_sys::PageTransitionEvent,
+    pagereveal: web_sys::Event,
+    pageshow: web_sys::PageTransitionEvent,
+    pageswap: web_sys::Event,
+    paste: web_sys::ClipboardEvent,
+    pause: web_sys::Event,
+    play: web_sys::Event,
+    playing: web_sys::Event,
+    pointercancel: web_sys::PointerEvent,
+    pointerdown: web_sys::PointerEvent,
+    pointerenter: web_sys::PointerEvent,
+    pointerleave: web_sys::PointerEvent,
+    pointerlockchange: web_sys::Event,
+    pointerlockerror: web_sys::Event,
+    pointermove: web_sys::PointerEvent,
+    pointerout: web_sys::PointerEvent,
+    pointerover: web_sys::PointerEvent,
+    pointerrawupdate: web_sys::PointerEvent,
+    pointerup: web_sys::PointerEvent,
+    popstate: web_sys::PopStateEvent,
+    progress: web_sys::ProgressEvent,
+    ratechange: web_sys::Event,
+    readystatechange: web_sys::Event,
+    rejectionhandled: web_sys::PromiseRejectionEvent,
+    reset: web_sys::Event,
+    resize: web_sys::UiEvent,
+    scroll: web_sys::Event,
+    scrollend: web_sys::Event,
+    securitypolicyviolation: web_sys::SecurityPolicyViolationEvent,
+    seeked: web_sys::Event,
+    seeking: web_sys::Event,
+    select: web_sys::Event,
+    selectionchange: web_sys::Event,
+    stalled: web_sys::Event,
+    storage: web_sys::StorageEvent,
+    submit: web_sys::SubmitEvent,
+    suspend: web_sys::Event,
+    timeupdate: web_sys::Event,
+    toggle: web_sys::ToggleEvent,
+    touchcancel: web_sys::TouchEvent,
     touchend: web_sys::TouchEvent,
     touchmove: web_sys::TouchEvent,
-    touchcancel: web_sys::TouchEvent,
-}
-
-// === Wheel Events ===
-generate_events! {
-    wheel: web_sys::WheelEvent,
-}
-
-// === Animation & Transition ===
-generate_events! {
-    animationstart: web_sys::AnimationEvent,
-    animationend: web_sys::AnimationEvent,
-    animationiteration: web_sys::AnimationEvent,
+    touchstart: web_sys::TouchEvent,
+    transitioncancel: web_sys::TransitionEvent,
     transitionend: web_sys::TransitionEvent,
-}
-
-// === Composition Events ===
-generate_events! {
-    compositionstart: web_sys::CompositionEvent,
-    compositionupdate: web_sys::CompositionEvent,
-    compositionend: web_sys::CompositionEvent,
+    transitionrun: web_sys::TransitionEvent,
+    transitionstart: web_sys::TransitionEvent,
+    unhandledrejection: web_sys::PromiseRejectionEvent,
+    unload: web_sys::Event,
+    visibilitychange: web_sys::Event,
+    volumechange: web_sys::Event,
+    waiting: web_sys::Event,
+    waitingforkey: web_sys::Event,
+    wheel: web_sys::WheelEvent,
 }
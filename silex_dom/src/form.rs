@@ -0,0 +1,123 @@
+use silex_core::reactivity::{Memo, Signal, create_scope, provide_context, use_context};
+use silex_core::traits::{IntoSignal, RxGet};
+
+// --- FormContext 表单分组上下文 ---
+
+/// 表单分组上下文：向后代表单控件广播分组级别的禁用状态。
+///
+/// 嵌套分组会与外层分组合并，任一层被禁用时整组都视为禁用。
+#[derive(Clone, Copy)]
+pub struct FormContext {
+    disabled: Signal<bool>,
+}
+
+impl FormContext {
+    /// 在当前作用域提供一个表单分组，其禁用状态与外层分组（若存在）取或。
+    pub fn provide<V>(disabled: V) -> Self
+    where
+        V: IntoSignal<Value = bool> + 'static,
+    {
+        let own = disabled.into_signal();
+        let ctx = Self {
+            disabled: combine_disabled(own, use_context::<FormContext>()),
+        };
+        provide_context(ctx);
+        ctx
+    }
+
+    /// 分组（含所有外层分组）的禁用状态。
+    pub fn disabled(&self) -> Signal<bool> {
+        self.disabled
+    }
+}
+
+fn combine_disabled(own: Signal<bool>, group: Option<FormContext>) -> Signal<bool> {
+    match group {
+        Some(group) => {
+            let group = group.disabled;
+            Memo::new(move |_| group.get() || own.get()).into()
+        }
+        None => own,
+    }
+}
+
+/// 当前所在表单分组的禁用状态；不在任何分组内时始终为 `false`。
+pub fn use_form_disabled() -> Signal<bool> {
+    match use_context::<FormContext>() {
+        Some(ctx) => ctx.disabled,
+        None => Signal::from(false),
+    }
+}
+
+/// 将控件自身的禁用状态与所在分组的禁用状态合并。
+pub fn form_disabled<V>(own: V) -> Signal<bool>
+where
+    V: IntoSignal<Value = bool> + 'static,
+{
+    combine_disabled(own.into_signal(), use_context::<FormContext>())
+}
+
+/// 创建一个禁用分组：`children` 内构建的所有表单控件都会继承 `disabled`。
+///
+/// 由于子节点在容器构建前就已挂载，分组状态需在构建子节点之前提供，
+/// 因此使用闭包延迟构建，例如 `form_group(locked, || fieldset((input(), button("OK"))))`。
+pub fn form_group<V, R>(disabled: V, children: impl FnOnce() -> R) -> R
+where
+    V: IntoSignal<Value = bool> + 'static,
+{
+    let disabled = disabled.into_signal();
+    let mut view = None;
+    create_scope(|| {
+        FormContext::provide(disabled);
+        view = Some(children());
+    });
+    view.expect("form_group children were not built")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::RwSignal;
+    use silex_core::traits::RxWrite;
+
+    #[test]
+    fn group_disabled_cascades_to_children() {
+        create_scope(|| {
+            let locked = RwSignal::new(false);
+            let own = RwSignal::new(false);
+
+            let (child, outside) = (
+                form_group(locked, || form_disabled(own)),
+                form_disabled(own),
+            );
+            assert!(!child.get());
+
+            locked.set(true);
+            assert!(child.get());
+            assert!(!outside.get(), "siblings outside the group are unaffected");
+
+            locked.set(false);
+            own.set(true);
+            assert!(child.get());
+        });
+    }
+
+    #[test]
+    fn nested_groups_combine() {
+        create_scope(|| {
+            let outer = RwSignal::new(false);
+            let inner = RwSignal::new(false);
+
+            let child = form_group(outer, || form_group(inner, || form_disabled(false)));
+            assert!(!child.get());
+
+            outer.set(true);
+            assert!(child.get());
+
+            outer.set(false);
+            inner.set(true);
+            assert!(child.get());
+            assert!(!use_form_disabled().get());
+        });
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use silex_core::reactivity::Effect;
+
+/// 一次更新中需要通过 `classList` 增删的类名
+#[derive(Debug, Default, PartialEq)]
+struct ClassDiff {
+    removed: Vec<String>,
+    added: Vec<String>,
+}
+
+/// 比较本绑定已添加的类名 `owned` 与新的集合 `items`。
+///
+/// `present` 查询元素上是否已有该类名：已存在且不属于 `owned` 的类名不会被添加，
+/// 因此之后也不会被移除。
+fn diff_classes(
+    owned: &HashSet<String>,
+    items: &[String],
+    present: impl Fn(&str) -> bool,
+) -> ClassDiff {
+    let mut next: Vec<&str> = Vec::new();
+    for name in items.iter().flat_map(|item| item.split_whitespace()) {
+        if !next.contains(&name) {
+            next.push(name);
+        }
+    }
+    let mut removed: Vec<String> = owned
+        .iter()
+        .filter(|name| !next.contains(&name.as_str()))
+        .cloned()
+        .collect();
+    removed.sort();
+    ClassDiff {
+        removed,
+        added: next
+            .into_iter()
+            .filter(|name| !owned.contains(*name) && !present(name))
+            .map(str::to_string)
+            .collect(),
+    }
+}
+
+/// 将类名集合与元素的 `class_list` 同步。
+///
+/// 每次更新时与上一次的集合比较，只增删差异部分。只移除由本绑定添加的类名：
+/// 添加前已存在的类名 (静态 `class` 或其他途径设置) 不会被移除。
+/// 每一项可包含以空白分隔的多个类名。
+pub fn bind_class_set<T, F>(el: T, classes: F)
+where
+    T: AsRef<web_sys::Element> + 'static,
+    F: Fn() -> Vec<String> + 'static,
+{
+    Effect::new(move |owned: Option<HashSet<String>>| {
+        let mut owned = owned.unwrap_or_default();
+        let class_list = el.as_ref().class_list();
+        let diff = diff_classes(&owned, &classes(), |name| class_list.contains(name));
+        for name in diff.removed {
+            let _ = class_list.remove_1(&name);
+            owned.remove(&name);
+        }
+        for name in diff.added {
+            let _ = class_list.add_1(&name);
+            owned.insert(name);
+        }
+        owned
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(items: &[&str]) -> Vec<String> {
+        items.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn class_set_applies_only_the_delta() {
+        // 静态类名，即使出现在动态集合中也不会被添加或移除
+        let present = |name: &str| name == "card";
+
+        let first = diff_classes(&HashSet::new(), &names(&["a b", "card", "a"]), present);
+        assert_eq!(
+            first,
+            ClassDiff {
+                removed: vec![],
+                added: names(&["a", "b"]),
+            }
+        );
+
+        let owned: HashSet<String> = first.added.into_iter().collect();
+        assert_eq!(
+            diff_classes(&owned, &names(&["b", "c"]), present),
+            ClassDiff {
+                removed: names(&["a"]),
+                added: names(&["c"]),
+            }
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use silex_core::reactivity::{Effect, ReadSignal, RwSignal, on_cleanup};
+use silex_core::traits::{RxRead, RxWrite};
+
+use super::{request_idle_callback_with_handle, set_timeout_with_handle};
+
+/// 取消已调度任务的函数
+type CancelTask = Box<dyn FnOnce()>;
+
+/// 在浏览器空闲时执行 `task`，不支持 `requestIdleCallback` 时回退到 `setTimeout(0)`
+fn schedule_idle(task: Box<dyn FnOnce()>) -> CancelTask {
+    let task = Rc::new(RefCell::new(Some(task)));
+    let run = move || {
+        if let Some(task) = task.borrow_mut().take() {
+            task();
+        }
+    };
+    match request_idle_callback_with_handle(run.clone()) {
+        Ok(handle) => Box::new(move || handle.cancel()),
+        Err(_) => match set_timeout_with_handle(run, Duration::ZERO) {
+            Ok(handle) => Box::new(move || handle.clear()),
+            Err(_) => Box::new(|| {}),
+        },
+    }
+}
+
+/// 延迟信号：跟随 `source`，但在浏览器空闲时才更新，使高优先级的更新先完成渲染。
+///
+/// 同一次空闲回调之前的多次变化只会产生一次更新 (取最新值)；
+/// 所在作用域销毁时取消尚未执行的回调。
+///
+/// ```rust,ignore
+/// let report = defer_signal(Memo::new(move |_| format_report(&rows.get())));
+/// ```
+pub fn defer_signal<S, T>(source: S) -> ReadSignal<T>
+where
+    S: RxRead<Value = T> + 'static,
+    T: Clone + 'static,
+{
+    defer_signal_with(source, schedule_idle)
+}
+
+fn defer_signal_with<S, T>(
+    source: S,
+    schedule: impl Fn(Box<dyn FnOnce()>) -> CancelTask + 'static,
+) -> ReadSignal<T>
+where
+    S: RxRead<Value = T> + 'static,
+    T: Clone + 'static,
+{
+    let source = Rc::new(source);
+    let deferred = RwSignal::new(source.with_untracked(T::clone));
+    let pending = Rc::new(RefCell::new(None::<CancelTask>));
+
+    Effect::new({
+        let source = source.clone();
+        let pending = pending.clone();
+        move |initialized: Option<()>| {
+            source.track();
+            // 首次运行只建立订阅；已有待执行的回调时，它会读取到最新值
+            if initialized.is_none() || pending.borrow().is_some() {
+                return;
+            }
+            let cancel = schedule(Box::new({
+                let source = source.clone();
+                let pending = pending.clone();
+                move || {
+                    pending.borrow_mut().take();
+                    if let Some(value) = source.try_with_untracked(T::clone) {
+                        deferred.try_set(value);
+                    }
+                }
+            }));
+            *pending.borrow_mut() = Some(cancel);
+        }
+    });
+
+    on_cleanup(move || {
+        if let Some(cancel) = pending.borrow_mut().take() {
+            cancel();
+        }
+    });
+
+    deferred.read_signal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{create_scope, dispose};
+    use silex_core::traits::RxGet;
+    use std::cell::Cell;
+
+    type Tasks = Rc<RefCell<Vec<Box<dyn FnOnce()>>>>;
+
+    fn task_queue(
+        tasks: &Tasks,
+        cancelled: &Rc<Cell<usize>>,
+    ) -> impl Fn(Box<dyn FnOnce()>) -> CancelTask + 'static {
+        let tasks = tasks.clone();
+        let cancelled = cancelled.clone();
+        move |task| {
+            tasks.borrow_mut().push(task);
+            let cancelled = cancelled.clone();
+            Box::new(move || cancelled.set(cancelled.get() + 1))
+        }
+    }
+
+    #[test]
+    fn deferred_signal_lags_until_idle() {
+        let tasks: Tasks = Rc::default();
+        let cancelled = Rc::new(Cell::new(0));
+        create_scope(|| {
+            let source = RwSignal::new(1);
+            let deferred = defer_signal_with(source, task_queue(&tasks, &cancelled));
+            assert!(tasks.borrow().is_empty());
+
+            source.set(2);
+            source.set(3);
+            assert_eq!(tasks.borrow().len(), 1, "changes are coalesced");
+            assert_eq!(deferred.get_untracked(), 1);
+
+            let task = tasks.borrow_mut().pop().unwrap();
+            task();
+            assert_eq!(deferred.get_untracked(), 3);
+
+            source.set(4);
+            assert_eq!(tasks.borrow().len(), 1);
+        });
+    }
+
+    #[test]
+    fn deferred_signal_cancels_pending_on_dispose() {
+        let tasks: Tasks = Rc::default();
+        let cancelled = Rc::new(Cell::new(0));
+        let scope = create_scope(|| {
+            let source = RwSignal::new(1);
+            defer_signal_with(source, task_queue(&tasks, &cancelled));
+            source.set(2);
+        });
+        assert_eq!(cancelled.get(), 0);
+        dispose(scope);
+        assert_eq!(cancelled.get(), 1);
+    }
+}
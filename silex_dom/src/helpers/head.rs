@@ -0,0 +1,231 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use silex_core::reactivity::{Effect, Signal, on_cleanup};
+use silex_core::traits::{IntoSignal, RxGet};
+
+use super::document;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum HeadKey {
+    Title,
+    Meta(String),
+}
+
+impl HeadKey {
+    /// 文档中的当前取值：`document.title` 或 `<meta name="..">` 的 `content` (标签不存在时为 `None`)
+    fn read(&self) -> Option<String> {
+        let document = document();
+        match self {
+            HeadKey::Title => Some(document.title()),
+            HeadKey::Meta(name) => document
+                .query_selector(&format!("meta[name=\"{}\"]", name))
+                .ok()
+                .flatten()
+                .and_then(|meta| meta.get_attribute("content")),
+        }
+    }
+
+    /// 写入文档；meta 不存在时创建，取值为 `None` 时移除该标签
+    fn write(&self, value: Option<&str>) {
+        let document = document();
+        let name = match self {
+            HeadKey::Title => return document.set_title(value.unwrap_or_default()),
+            HeadKey::Meta(name) => name,
+        };
+        let existing = document
+            .query_selector(&format!("meta[name=\"{}\"]", name))
+            .ok()
+            .flatten();
+        match (existing, value) {
+            (Some(meta), Some(content)) => {
+                let _ = meta.set_attribute("content", content);
+            }
+            (Some(meta), None) => meta.remove(),
+            (None, Some(content)) => {
+                let Some(head) = document.head() else {
+                    return;
+                };
+                if let Ok(meta) = document.create_element("meta") {
+                    let _ = meta.set_attribute("name", name);
+                    let _ = meta.set_attribute("content", content);
+                    let _ = head.append_child(&meta);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// 同一标题或 meta 的覆盖栈：最近挂载且仍存活的取值生效
+#[derive(Default)]
+struct HeadEntries {
+    /// 第一个条目挂载前文档中的原始取值，栈清空时恢复
+    original: Option<String>,
+    entries: Vec<(u64, String)>,
+}
+
+/// 全部覆盖栈，只计算应写入文档的取值，不访问 DOM
+#[derive(Default)]
+struct HeadStacks(HashMap<HeadKey, HeadEntries>);
+
+impl HeadStacks {
+    /// 写入条目 `id` 的取值，首次写入时压入栈顶，返回栈顶的取值。
+    ///
+    /// 栈为空时先以 `original` 读取文档的原始取值。
+    fn set(
+        &mut self,
+        key: &HeadKey,
+        id: u64,
+        value: String,
+        original: impl FnOnce() -> Option<String>,
+    ) -> String {
+        let stack = self.0.entry(key.clone()).or_default();
+        if stack.entries.is_empty() {
+            stack.original = original();
+        }
+        match stack.entries.iter_mut().find(|(entry, _)| *entry == id) {
+            Some((_, current)) => *current = value,
+            None => stack.entries.push((id, value)),
+        }
+        stack
+            .entries
+            .last()
+            .map(|(_, top)| top.clone())
+            .unwrap_or_default()
+    }
+
+    /// 移除条目 `id`，返回需要写回的取值：栈顶的取值，栈清空时为原始取值。
+    ///
+    /// `key` 没有覆盖栈时返回 `None`，无需写入。
+    fn remove(&mut self, key: &HeadKey, id: u64) -> Option<Option<String>> {
+        let stack = self.0.get_mut(key)?;
+        stack.entries.retain(|(entry, _)| *entry != id);
+        match stack.entries.last() {
+            Some((_, top)) => Some(Some(top.clone())),
+            None => self.0.remove(key).map(|stack| stack.original),
+        }
+    }
+}
+
+/// 管理 `use_document_title` / `use_meta` 的覆盖栈，并将栈顶写入文档
+#[derive(Default)]
+struct HeadManager {
+    next_id: Cell<u64>,
+    stacks: RefCell<HeadStacks>,
+}
+
+thread_local! {
+    static HEAD: Rc<HeadManager> = Rc::default();
+}
+
+impl HeadManager {
+    fn bind(self: &Rc<Self>, key: HeadKey, value: Signal<String>) {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+
+        let manager = self.clone();
+        let effect_key = key.clone();
+        Effect::new(move |_| {
+            let top = manager
+                .stacks
+                .borrow_mut()
+                .set(&effect_key, id, value.get(), || effect_key.read());
+            effect_key.write(Some(&top));
+        });
+
+        let manager = self.clone();
+        on_cleanup(move || {
+            if let Some(restored) = manager.stacks.borrow_mut().remove(&key, id) {
+                key.write(restored.as_deref());
+            }
+        });
+    }
+}
+
+/// 在当前作用域存活期间设置 `document.title`，取值变化时同步更新。
+///
+/// 嵌套的组件各自调用时，最内层 (最近挂载) 的标题生效；作用域销毁时恢复外层的标题，
+/// 全部销毁后恢复文档原本的标题。
+///
+/// ```rust,ignore
+/// #[component]
+/// fn UserPage(id: u32) -> impl Mount {
+///     use_document_title(rx!(format!("User {}", id)));
+///     // ...
+/// }
+/// ```
+pub fn use_document_title<V>(title: V)
+where
+    V: IntoSignal<Value = String> + 'static,
+{
+    HEAD.with(|head| head.bind(HeadKey::Title, title.into_signal()));
+}
+
+/// 在当前作用域存活期间设置 `<meta name="..">` 的 `content`，规则同 [`use_document_title`]。
+///
+/// 全部销毁后恢复原有的 `content`；原本不存在的标签被移除。
+pub fn use_meta<V>(name: &str, content: V)
+where
+    V: IntoSignal<Value = String> + 'static,
+{
+    HEAD.with(|head| head.bind(HeadKey::Meta(name.to_string()), content.into_signal()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn document_title_follows_route_scopes() {
+        let mut stacks = HeadStacks::default();
+        let title = HeadKey::Title;
+        let mut set = |id, value: &str| {
+            stacks.set(&title, id, value.to_string(), || {
+                Some("index.html".to_string())
+            })
+        };
+
+        assert_eq!(set(0, "Silex"), "Silex");
+
+        // 路由 A：嵌套组件的标题覆盖外层
+        set(1, "Home");
+        assert_eq!(set(2, "Home · Feed"), "Home · Feed");
+
+        // 切换到路由 B：先挂载新路由，再销毁旧路由
+        assert_eq!(set(3, "User 1"), "User 1");
+        let restored = |stacks: &mut HeadStacks, id| stacks.remove(&title, id);
+        assert_eq!(restored(&mut stacks, 2), Some(Some("User 1".to_string())));
+        assert_eq!(restored(&mut stacks, 1), Some(Some("User 1".to_string())));
+        let update = stacks.set(&title, 3, "User 2".to_string(), || unreachable!());
+        assert_eq!(update, "User 2");
+
+        assert_eq!(restored(&mut stacks, 3), Some(Some("Silex".to_string())));
+        assert_eq!(
+            restored(&mut stacks, 0),
+            Some(Some("index.html".to_string()))
+        );
+        assert_eq!(restored(&mut stacks, 0), None, "nothing left to restore");
+    }
+
+    #[test]
+    fn meta_restores_or_removes_original_tag() {
+        let mut stacks = HeadStacks::default();
+        let description = HeadKey::Meta("description".to_string());
+        let robots = HeadKey::Meta("robots".to_string());
+
+        let page = stacks.set(&description, 0, "page".to_string(), || {
+            Some("site".to_string())
+        });
+        assert_eq!(page, "page");
+        let noindex = stacks.set(&robots, 1, "noindex".to_string(), || None);
+        assert_eq!(noindex, "noindex");
+
+        assert_eq!(
+            stacks.remove(&description, 0),
+            Some(Some("site".to_string()))
+        );
+        assert_eq!(stacks.remove(&robots, 1), Some(None), "tag is removed");
+    }
+}
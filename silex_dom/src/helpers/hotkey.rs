@@ -0,0 +1,233 @@
+use wasm_bindgen::JsCast;
+
+use super::{EventTargetKind, use_event_listener};
+
+/// 解析后的快捷键组合，如 `"ctrl+k"`、`"meta+shift+p"`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Hotkey {
+    pub ctrl: bool,
+    pub meta: bool,
+    pub shift: bool,
+    pub alt: bool,
+    /// 小写的按键名
+    pub key: String,
+}
+
+impl Hotkey {
+    /// 解析以 `+` 连接的组合：修饰键 `ctrl` / `meta` / `shift` / `alt` 加上一个按键
+    pub fn parse(combo: &str) -> silex_core::SilexResult<Self> {
+        let invalid = || silex_core::SilexError::Dom(format!("Invalid hotkey `{combo}`"));
+        let mut hotkey = Hotkey::default();
+        for part in combo.split('+').map(|p| p.trim().to_ascii_lowercase()) {
+            match part.as_str() {
+                "ctrl" | "control" => hotkey.ctrl = true,
+                "meta" | "cmd" => hotkey.meta = true,
+                "shift" => hotkey.shift = true,
+                "alt" | "option" => hotkey.alt = true,
+                "" => return Err(invalid()),
+                _ if hotkey.key.is_empty() => hotkey.key = part,
+                _ => return Err(invalid()),
+            }
+        }
+        if hotkey.key.is_empty() {
+            return Err(invalid());
+        }
+        Ok(hotkey)
+    }
+
+    /// 修饰键状态完全一致且按键相同时匹配。
+    ///
+    /// 字母与数字同时比较 `code` (`KeyK`、`Digit1`)，不受 Shift/Alt 改变 `key` 的影响。
+    pub fn matches(&self, event: &web_sys::KeyboardEvent) -> bool {
+        self.matches_press(&KeyPress::from(event))
+    }
+
+    fn matches_press(&self, press: &KeyPress) -> bool {
+        if press.ctrl != self.ctrl
+            || press.meta != self.meta
+            || press.shift != self.shift
+            || press.alt != self.alt
+        {
+            return false;
+        }
+        if press.key.eq_ignore_ascii_case(&self.key) {
+            return true;
+        }
+        let mut chars = self.key.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphabetic() => {
+                press.code == format!("Key{}", c.to_ascii_uppercase())
+            }
+            (Some(c), None) if c.is_ascii_digit() => press.code == format!("Digit{c}"),
+            _ => false,
+        }
+    }
+
+    /// 按下的组合匹配，且焦点位置满足 `options` 时触发
+    fn triggers(&self, press: &KeyPress, options: HotkeyOptions) -> bool {
+        self.matches_press(press) && (options.allow_in_editable || !press.in_editable)
+    }
+}
+
+/// 快捷键匹配读取的按键状态，取自 `KeyboardEvent` 的同名字段
+#[derive(Clone, Debug, Default)]
+struct KeyPress {
+    key: String,
+    code: String,
+    ctrl: bool,
+    meta: bool,
+    shift: bool,
+    alt: bool,
+    /// 焦点是否位于可编辑元素 (input、textarea、select、contenteditable)
+    in_editable: bool,
+}
+
+impl From<&web_sys::KeyboardEvent> for KeyPress {
+    fn from(event: &web_sys::KeyboardEvent) -> Self {
+        let in_editable = event
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+            .is_some_and(|el| {
+                matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                    || el.is_content_editable()
+            });
+        Self {
+            key: event.key(),
+            code: event.code(),
+            ctrl: event.ctrl_key(),
+            meta: event.meta_key(),
+            shift: event.shift_key(),
+            alt: event.alt_key(),
+            in_editable,
+        }
+    }
+}
+
+/// 快捷键选项，见 [`use_hotkey_with`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HotkeyOptions {
+    /// 匹配时调用 `preventDefault`
+    pub prevent_default: bool,
+    /// 焦点位于可编辑元素时也触发
+    pub allow_in_editable: bool,
+}
+
+impl HotkeyOptions {
+    pub fn prevent_default(mut self, prevent: bool) -> Self {
+        self.prevent_default = prevent;
+        self
+    }
+
+    pub fn allow_in_editable(mut self, allow: bool) -> Self {
+        self.allow_in_editable = allow;
+        self
+    }
+}
+
+/// 注册全局快捷键：在 `document` 上监听 `keydown`，组合匹配时调用 `cb`，作用域销毁时移除监听。
+///
+/// 焦点位于输入框等可编辑元素时不会触发，见 [`use_hotkey_with`]。组合无法解析时交给错误处理器。
+/// ```ignore
+/// use_hotkey("ctrl+k", move || palette_open.set(true));
+/// ```
+pub fn use_hotkey(combo: &str, cb: impl Fn() + 'static) {
+    use_hotkey_with(combo, HotkeyOptions::default(), cb);
+}
+
+/// 以指定选项注册全局快捷键
+pub fn use_hotkey_with(combo: &str, options: HotkeyOptions, cb: impl Fn() + 'static) {
+    let hotkey = match Hotkey::parse(combo) {
+        Ok(hotkey) => hotkey,
+        Err(e) => {
+            silex_core::error::handle_error(e);
+            return;
+        }
+    };
+    use_event_listener(EventTargetKind::Document, "keydown", move |e| {
+        let event: &web_sys::KeyboardEvent = e.unchecked_ref();
+        if !hotkey.triggers(&KeyPress::from(event), options) {
+            return;
+        }
+        if options.prevent_default {
+            event.prevent_default();
+        }
+        cb();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hotkey_parse() {
+        let hotkey = Hotkey::parse("Ctrl + Shift + K").unwrap();
+        assert!(hotkey.ctrl && hotkey.shift && !hotkey.meta && !hotkey.alt);
+        assert_eq!(hotkey.key, "k");
+        assert!(Hotkey::parse("ctrl+").is_err());
+        assert!(Hotkey::parse("ctrl+a+b").is_err());
+        assert!(Hotkey::parse("shift").is_err());
+    }
+
+    #[test]
+    fn hotkey_triggers_only_on_matching_combo() {
+        let hotkey = Hotkey::parse("ctrl+k").unwrap();
+        let options = HotkeyOptions::default();
+        let ctrl_k = || KeyPress {
+            key: "k".to_string(),
+            code: "KeyK".to_string(),
+            ctrl: true,
+            ..Default::default()
+        };
+        assert!(hotkey.triggers(&ctrl_k(), options));
+
+        // 不同组合不触发
+        let released = KeyPress {
+            ctrl: false,
+            ..ctrl_k()
+        };
+        let shifted = KeyPress {
+            shift: true,
+            key: "K".to_string(),
+            ..ctrl_k()
+        };
+        let other = KeyPress {
+            key: "j".to_string(),
+            code: "KeyJ".to_string(),
+            ..ctrl_k()
+        };
+        for press in [released, shifted, other] {
+            assert!(!hotkey.triggers(&press, options), "{press:?}");
+        }
+
+        // 可编辑元素中默认不触发
+        let editing = KeyPress {
+            in_editable: true,
+            ..ctrl_k()
+        };
+        assert!(!hotkey.triggers(&editing, options));
+        assert!(hotkey.triggers(&editing, options.allow_in_editable(true)));
+    }
+
+    #[test]
+    fn hotkey_matches_code_when_modifiers_change_the_key() {
+        // macOS 上 Alt+K 产生 "˚"，Shift+1 产生 "!"
+        let alt_k = Hotkey::parse("alt+k").unwrap();
+        let press = KeyPress {
+            key: "˚".to_string(),
+            code: "KeyK".to_string(),
+            alt: true,
+            ..Default::default()
+        };
+        assert!(alt_k.matches_press(&press));
+
+        let shift_1 = Hotkey::parse("shift+1").unwrap();
+        let press = KeyPress {
+            key: "!".to_string(),
+            code: "Digit1".to_string(),
+            shift: true,
+            ..Default::default()
+        };
+        assert!(shift_1.matches_press(&press));
+    }
+}
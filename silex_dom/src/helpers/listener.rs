@@ -0,0 +1,43 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::*;
+
+use silex_core::reactivity::on_cleanup;
+
+use super::{document, window};
+
+/// 全局或元素级的事件目标，配合 [`use_event_listener`] 使用
+#[derive(Clone)]
+pub enum EventTargetKind {
+    Window,
+    Document,
+    Element(web_sys::Element),
+}
+
+/// 添加事件监听器，并在当前响应式作用域被清理时自动移除，无需 `Closure::forget`。
+///
+/// ```ignore
+/// use_event_listener(EventTargetKind::Window, "resize", move |_| {
+///     width.set(window().inner_width().ok().and_then(|w| w.as_f64()).unwrap_or_default());
+/// });
+/// ```
+pub fn use_event_listener(
+    target: EventTargetKind,
+    event_name: &str,
+    handler: impl FnMut(web_sys::Event) + 'static,
+) {
+    let target: web_sys::EventTarget = match target {
+        EventTargetKind::Window => window().into(),
+        EventTargetKind::Document => document().into(),
+        EventTargetKind::Element(el) => el.into(),
+    };
+    let closure = Closure::wrap(Box::new(handler) as Box<dyn FnMut(web_sys::Event)>);
+    let _ = target.add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref());
+
+    let event_name = event_name.to_string();
+    on_cleanup(move || {
+        let _ = target
+            .remove_event_listener_with_callback(&event_name, closure.as_ref().unchecked_ref());
+        // 监听器已移除，此时释放 Closure 是安全的
+        drop(closure);
+    });
+}
@@ -0,0 +1,321 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::*;
+
+use silex_core::NodeRef;
+use silex_core::reactivity::{Effect, ReadSignal, RwSignal, on_cleanup};
+use silex_core::traits::{RxGet, RxWrite};
+
+/// 断开观察的函数
+type Disconnect = Box<dyn FnOnce()>;
+
+/// 元素尺寸 `(width, height)` 的响应式信号，节点加载前为 `(0.0, 0.0)`。
+///
+/// 在 `node_ref` 加载时开始观察，所在作用域销毁时断开观察。
+/// ```ignore
+/// let panel = NodeRef::<HtmlElement>::new();
+/// let size = use_element_size(panel);
+/// div(move || format!("{}px", size.get().0)).node_ref(panel)
+/// ```
+pub fn use_element_size<T>(node_ref: NodeRef<T>) -> ReadSignal<(f64, f64)>
+where
+    T: AsRef<web_sys::Element> + Clone + 'static,
+{
+    element_size(node_ref, |el: &T, on_resize| {
+        observe_resize(el.as_ref(), on_resize)
+    })
+}
+
+fn element_size<T>(
+    node_ref: NodeRef<T>,
+    observe: impl FnOnce(&T, Box<dyn Fn(f64, f64)>) -> Disconnect + 'static,
+) -> ReadSignal<(f64, f64)>
+where
+    T: Clone + 'static,
+{
+    let size = RwSignal::new((0.0, 0.0));
+    observe_on_load(node_ref, move |el| {
+        observe(
+            el,
+            Box::new(move |width, height| {
+                // 元素卸载后的最后一次回调不再写入
+                size.try_set((width, height));
+            }),
+        )
+    });
+    size.read_signal()
+}
+
+/// 通过 `ResizeObserver` 观察元素，以 `contentRect` 的 `(width, height)` 调用 `on_resize`
+fn observe_resize(el: &web_sys::Element, on_resize: Box<dyn Fn(f64, f64)>) -> Disconnect {
+    let callback = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+        for entry in entries.iter() {
+            let rect = entry
+                .unchecked_into::<web_sys::ResizeObserverEntry>()
+                .content_rect();
+            on_resize(rect.width(), rect.height());
+        }
+    }) as Box<dyn Fn(js_sys::Array)>);
+
+    match web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref()) {
+        Ok(observer) => {
+            observer.observe(el);
+            Box::new(move || {
+                observer.disconnect();
+                drop(callback);
+            })
+        }
+        Err(e) => {
+            silex_core::log::console_error(format!("Failed to create ResizeObserver: {e:?}"));
+            Box::new(|| {})
+        }
+    }
+}
+
+/// 在 `node_ref` 加载时调用 `observe` 开始观察，所在作用域销毁时调用其返回的断开函数
+fn observe_on_load<T>(node_ref: NodeRef<T>, observe: impl FnOnce(&T) -> Disconnect + 'static)
+where
+    T: Clone + 'static,
+{
+    let alive = Rc::new(Cell::new(true));
+    let disconnect = Rc::new(RefCell::new(None::<Disconnect>));
+
+    node_ref.on_load({
+        let alive = alive.clone();
+        let disconnect = disconnect.clone();
+        move |el| {
+            if alive.get() {
+                *disconnect.borrow_mut() = Some(observe(el));
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        alive.set(false);
+        if let Some(stop) = disconnect.borrow_mut().take() {
+            stop();
+        }
+    });
+}
+
+/// [`use_in_viewport_with`] 的观察参数，对应 `IntersectionObserver` 的 `rootMargin` 与 `threshold`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewportOptions {
+    pub root_margin: String,
+    pub threshold: f64,
+}
+
+impl Default for ViewportOptions {
+    fn default() -> Self {
+        Self {
+            root_margin: "0px".to_string(),
+            threshold: 0.0,
+        }
+    }
+}
+
+impl ViewportOptions {
+    pub fn root_margin(mut self, margin: impl Into<String>) -> Self {
+        self.root_margin = margin.into();
+        self
+    }
+
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+/// 元素当前是否与视口相交，节点加载前为 `false`。见 [`use_in_viewport_with`]。
+pub fn use_in_viewport<T>(node_ref: NodeRef<T>) -> ReadSignal<bool>
+where
+    T: AsRef<web_sys::Element> + Clone + 'static,
+{
+    use_in_viewport_with(node_ref, ViewportOptions::default())
+}
+
+/// 以指定的 `rootMargin` 与 `threshold` 观察元素与视口的相交状态。
+///
+/// 在 `node_ref` 加载时开始观察，所在作用域销毁时断开观察。
+/// ```ignore
+/// let sentinel = NodeRef::<HtmlElement>::new();
+/// let near_end = use_in_viewport_with(sentinel, ViewportOptions::default().root_margin("200px"));
+/// Effect::new(move |_| if near_end.get() { load_more() });
+/// ```
+pub fn use_in_viewport_with<T>(node_ref: NodeRef<T>, options: ViewportOptions) -> ReadSignal<bool>
+where
+    T: AsRef<web_sys::Element> + Clone + 'static,
+{
+    in_viewport(node_ref, move |el: &T, on_change| {
+        observe_intersection(el.as_ref(), &options, on_change)
+    })
+}
+
+/// 元素首次进入视口后永久为 `true`，适用于一次性的懒加载。
+pub fn use_once_visible<T>(node_ref: NodeRef<T>) -> ReadSignal<bool>
+where
+    T: AsRef<web_sys::Element> + Clone + 'static,
+{
+    latch(use_in_viewport(node_ref))
+}
+
+fn in_viewport<T>(
+    node_ref: NodeRef<T>,
+    observe: impl FnOnce(&T, Box<dyn Fn(bool)>) -> Disconnect + 'static,
+) -> ReadSignal<bool>
+where
+    T: Clone + 'static,
+{
+    let visible = RwSignal::new(false);
+    observe_on_load(node_ref, move |el| {
+        observe(
+            el,
+            Box::new(move |intersecting| {
+                if visible.get_untracked() != intersecting {
+                    visible.try_set(intersecting);
+                }
+            }),
+        )
+    });
+    visible.read_signal()
+}
+
+/// `visible` 首次为 `true` 后保持为 `true`
+fn latch(visible: ReadSignal<bool>) -> ReadSignal<bool> {
+    let seen = RwSignal::new(false);
+    Effect::new(move |_| {
+        if visible.get() && !seen.get_untracked() {
+            seen.set(true);
+        }
+    });
+    seen.read_signal()
+}
+
+/// 通过 `IntersectionObserver` 观察元素，相交状态变化时调用 `on_change`
+fn observe_intersection(
+    el: &web_sys::Element,
+    options: &ViewportOptions,
+    on_change: Box<dyn Fn(bool)>,
+) -> Disconnect {
+    let callback = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+        // 同一批次中以最后一条记录为准
+        if let Some(entry) = entries.iter().last() {
+            let entry = entry.unchecked_into::<web_sys::IntersectionObserverEntry>();
+            on_change(entry.is_intersecting());
+        }
+    }) as Box<dyn Fn(js_sys::Array)>);
+
+    let init = web_sys::IntersectionObserverInit::new();
+    init.set_root_margin(&options.root_margin);
+    init.set_threshold(&JsValue::from_f64(options.threshold));
+
+    match web_sys::IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init)
+    {
+        Ok(observer) => {
+            observer.observe(el);
+            Box::new(move || {
+                observer.disconnect();
+                drop(callback);
+            })
+        }
+        Err(e) => {
+            silex_core::log::console_error(format!("Failed to create IntersectionObserver: {e:?}"));
+            Box::new(|| {})
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{create_scope, dispose};
+
+    type Callback<A> = RefCell<Option<Box<dyn Fn(A)>>>;
+
+    /// 记录观察回调，测试中代替 `ResizeObserver` / `IntersectionObserver` 上报
+    struct Observed<A> {
+        callback: Callback<A>,
+        disconnected: Cell<bool>,
+    }
+
+    impl<A: 'static> Observed<A> {
+        fn new() -> Rc<Self> {
+            Rc::new(Self {
+                callback: RefCell::new(None),
+                disconnected: Cell::new(false),
+            })
+        }
+
+        fn observe(self: &Rc<Self>, callback: Box<dyn Fn(A)>) -> Disconnect {
+            *self.callback.borrow_mut() = Some(callback);
+            let this = self.clone();
+            Box::new(move || this.disconnected.set(true))
+        }
+
+        fn report(&self, value: A) {
+            (self.callback.borrow().as_ref().unwrap())(value);
+        }
+    }
+
+    #[test]
+    fn element_size_follows_observer_until_dispose() {
+        let observed = Observed::<(f64, f64)>::new();
+        let size = Rc::new(Cell::new(None));
+        let scope = create_scope({
+            let observed = observed.clone();
+            let size = size.clone();
+            move || {
+                let node = NodeRef::new();
+                let watched = observed.clone();
+                size.set(Some(element_size(node, move |_: &(), on_resize| {
+                    watched.observe(Box::new(move |(w, h)| on_resize(w, h)))
+                })));
+                assert!(observed.callback.borrow().is_none(), "waits for the ref");
+                node.load(());
+            }
+        });
+        let size = size.get().unwrap();
+        assert_eq!(size.get_untracked(), (0.0, 0.0));
+
+        observed.report((120.0, 40.0));
+        assert_eq!(size.get_untracked(), (120.0, 40.0));
+
+        dispose(scope);
+        assert!(observed.disconnected.get());
+        // 断开后迟到的回调不会 panic
+        observed.report((10.0, 10.0));
+    }
+
+    #[test]
+    fn viewport_signals_follow_intersection() {
+        create_scope(|| {
+            let sentinel = Observed::<bool>::new();
+            let node = NodeRef::new();
+            let in_view = in_viewport(node, {
+                let sentinel = sentinel.clone();
+                move |_: &(), on_change| sentinel.observe(on_change)
+            });
+            node.load(());
+            assert!(!in_view.get_untracked());
+
+            sentinel.report(true);
+            assert!(in_view.get_untracked());
+            sentinel.report(false);
+            assert!(!in_view.get_untracked());
+
+            let image = Observed::<bool>::new();
+            let node = NodeRef::new();
+            let once = latch(in_viewport(node, {
+                let image = image.clone();
+                move |_: &(), on_change| image.observe(on_change)
+            }));
+            node.load(());
+            assert!(!once.get_untracked());
+            image.report(true);
+            image.report(false);
+            assert!(once.get_untracked(), "latched after first visibility");
+        });
+    }
+}
@@ -0,0 +1,161 @@
+use wasm_bindgen::JsValue;
+
+use silex_core::reactivity::{Effect, Memo, RwSignal, Signal};
+use silex_core::traits::{IntoSignal, RxGet, RxWrite};
+
+use super::{get_property, set_property};
+
+/// 将元素的 `open` 状态与 `signal` 双向同步。
+///
+/// 信号变化时写入元素；返回的回调应在元素的 `toggle` 事件中调用，将用户的展开/收起写回信号。
+pub fn bind_open_state<T>(el: T, signal: RwSignal<bool>) -> impl FnMut() + 'static
+where
+    T: AsRef<web_sys::Element> + Clone + 'static,
+{
+    let target = el.clone();
+    sync_open_state(
+        move || {
+            get_property(el.as_ref(), "open")
+                .ok()
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+        },
+        move |open| set_property(target.as_ref(), "open", &Some(JsValue::from_bool(open))),
+        signal,
+    )
+}
+
+/// 两个方向都先比较再写入：`read` 读取元素当前的 `open`，`write` 写入新值
+fn sync_open_state(
+    read: impl Fn() -> bool + Clone + 'static,
+    write: impl Fn(bool) + 'static,
+    signal: RwSignal<bool>,
+) -> impl FnMut() + 'static {
+    let is_open = read.clone();
+    Effect::new(move |_| {
+        let open = signal.get();
+        if is_open() != open {
+            write(open);
+        }
+    });
+
+    move || {
+        let open = read();
+        if signal.get_untracked() != open {
+            signal.set(open);
+        }
+    }
+}
+
+/// 将展开状态映射为 `data-state` 的取值："open" / "closed"。
+pub fn open_state<V>(open: V) -> Signal<String>
+where
+    V: IntoSignal<Value = bool> + 'static,
+{
+    let open = open.into_signal();
+    Memo::new(move |_| if open.get() { "open" } else { "closed" }.to_string()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::create_scope;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    #[test]
+    fn open_state_syncs_both_ways() {
+        create_scope(|| {
+            let details = Rc::new(Cell::new(false));
+            let writes = Rc::new(Cell::new(0));
+            let open = RwSignal::new(false);
+            let mut on_toggle = sync_open_state(
+                {
+                    let details = details.clone();
+                    move || details.get()
+                },
+                {
+                    let details = details.clone();
+                    let writes = writes.clone();
+                    move |open| {
+                        writes.set(writes.get() + 1);
+                        details.set(open);
+                    }
+                },
+                open,
+            );
+            assert_eq!(writes.get(), 0, "matching initial state is not rewritten");
+
+            // 用户点击 summary：浏览器先切换 open，再派发 toggle
+            details.set(true);
+            on_toggle();
+            assert!(open.get());
+            assert_eq!(writes.get(), 0, "echo from the signal is skipped");
+
+            open.set(false);
+            assert!(!details.get());
+            assert_eq!(writes.get(), 1);
+
+            // toggle 同样会在程序化修改后派发，此时信号已一致
+            on_toggle();
+            assert!(!open.get());
+        });
+    }
+
+    #[derive(Default)]
+    struct Attrs(Vec<crate::attribute::PendingAttribute>);
+
+    impl crate::attribute::AttributeBuilder for Attrs {
+        fn build_attribute<V>(mut self, target: crate::attribute::ApplyTarget, value: V) -> Self
+        where
+            V: crate::attribute::IntoStorable,
+        {
+            self.0.push(crate::attribute::PendingAttribute::build(
+                value.into_storable(),
+                target.into(),
+            ));
+            self
+        }
+
+        fn build_event<E, F, M>(self, _event: E, _callback: F) -> Self
+        where
+            E: crate::event::EventDescriptor + 'static,
+            F: crate::event::EventHandler<E::EventType, M> + Clone + 'static,
+        {
+            self
+        }
+    }
+
+    #[test]
+    fn data_state_attribute_follows_signal() {
+        use crate::attribute::{AttrData, AttrOp, GlobalAttributes};
+
+        create_scope(|| {
+            let open = RwSignal::new(false);
+            let attrs = Attrs::default().data_state(open_state(open));
+            let [attr] = attrs.0.as_slice() else {
+                panic!("expected a single attribute");
+            };
+            let AttrOp::Update {
+                name,
+                data: AttrData::ReactiveString(state),
+                ..
+            } = &attr.op
+            else {
+                panic!("expected a reactive attribute update");
+            };
+            assert_eq!(name, "data-state");
+
+            // 与挂载时相同，由 Effect 将最新取值写入属性
+            let written = Rc::new(RefCell::new(Vec::new()));
+            let state = state.clone();
+            Effect::new({
+                let written = written.clone();
+                move |_| written.borrow_mut().push(state.get())
+            });
+            open.set(true);
+            open.set(false);
+            assert_eq!(*written.borrow(), vec!["closed", "open", "closed"]);
+        });
+    }
+}
@@ -0,0 +1,81 @@
+use silex_core::reactivity::{Effect, untrack};
+use silex_core::traits::{IntoSignal, RxGet};
+
+/// 每当 `target` 的值发生变化时，将 `resolve` 解析出的元素滚动到可视区域。
+///
+/// 初始值不会触发滚动；解析在非追踪上下文中执行，通常配合 `NodeRef` 集合使用：
+/// ```ignore
+/// let rows: HashMap<usize, NodeRef<HtmlElement>> = ...;
+/// scroll_into_view_on(selected, move |id| rows.get(id).and_then(|r| r.get()));
+/// ```
+pub fn scroll_into_view_on<S, K, T, F>(target: S, resolve: F)
+where
+    S: IntoSignal<Value = K> + 'static,
+    K: Clone + PartialEq + 'static,
+    T: AsRef<web_sys::Element>,
+    F: Fn(&K) -> Option<T> + 'static,
+{
+    scroll_on_change(target, resolve, |el: T| el.as_ref().scroll_into_view());
+}
+
+/// 值变化且解析出目标时以该目标调用 `scroll`
+fn scroll_on_change<S, K, T, F>(target: S, resolve: F, scroll: impl Fn(T) + 'static)
+where
+    S: IntoSignal<Value = K> + 'static,
+    K: Clone + PartialEq + 'static,
+    F: Fn(&K) -> Option<T> + 'static,
+{
+    let target = target.into_signal();
+    Effect::new(move |prev: Option<K>| {
+        let key = target.get();
+        if prev.is_some_and(|prev| prev != key)
+            && let Some(el) = untrack(|| resolve(&key))
+        {
+            scroll(el);
+        }
+        key
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::NodeRef;
+    use silex_core::reactivity::{RwSignal, create_scope};
+    use silex_core::traits::RxWrite;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    #[test]
+    fn scrolls_to_resolved_element_on_change() {
+        create_scope(|| {
+            let log = Rc::new(RefCell::new(Vec::new()));
+            let rows: HashMap<usize, NodeRef<usize>> = (0..3)
+                .map(|id| {
+                    let node = NodeRef::new();
+                    node.load(id);
+                    (id, node)
+                })
+                .collect();
+
+            let selected = RwSignal::new(0usize);
+            scroll_on_change(selected, move |id| rows.get(id).and_then(|r| r.get()), {
+                let log = log.clone();
+                move |row| log.borrow_mut().push(row)
+            });
+            assert!(log.borrow().is_empty(), "initial value does not scroll");
+
+            selected.set(2);
+            assert_eq!(*log.borrow(), vec![2]);
+
+            // Unchanged value and unresolved keys are ignored
+            selected.set(2);
+            selected.set(7);
+            assert_eq!(*log.borrow(), vec![2]);
+
+            selected.set(1);
+            assert_eq!(*log.borrow(), vec![2, 1]);
+        });
+    }
+}
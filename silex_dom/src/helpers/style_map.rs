@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use silex_core::reactivity::{Effect, Signal};
+use silex_core::traits::RxRead;
+
+/// 样式表：属性名到取值的映射。`Vec` 中重复的属性以最后一项为准。
+pub trait StyleMap {
+    fn style_entries(&self) -> HashMap<String, String>;
+}
+
+impl StyleMap for HashMap<String, String> {
+    fn style_entries(&self) -> HashMap<String, String> {
+        self.clone()
+    }
+}
+
+impl StyleMap for Vec<(String, String)> {
+    fn style_entries(&self) -> HashMap<String, String> {
+        self.iter().cloned().collect()
+    }
+}
+
+/// 两次样式表之间的差异
+#[derive(Debug, Default, PartialEq)]
+struct StyleDiff<'a> {
+    /// 不再存在、需要 `removeProperty` 的属性
+    removed: Vec<&'a str>,
+    /// 新增或取值变化、需要 `setProperty` 的属性
+    changed: Vec<(&'a str, &'a str)>,
+}
+
+fn diff_styles<'a>(
+    prev: &'a HashMap<String, String>,
+    next: &'a HashMap<String, String>,
+) -> StyleDiff<'a> {
+    StyleDiff {
+        removed: prev
+            .keys()
+            .filter(|name| !next.contains_key(*name))
+            .map(String::as_str)
+            .collect(),
+        changed: next
+            .iter()
+            .filter(|(name, value)| prev.get(*name) != Some(*value))
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect(),
+    }
+}
+
+/// 将样式表与元素的内联样式同步。
+///
+/// 每次更新时与上一次的样式表比较：只写入取值变化的属性，移除不再存在的属性，
+/// 不会覆盖其他途径设置的样式属性。
+pub fn bind_style_map<T, M>(el: T, map: Signal<M>)
+where
+    T: AsRef<web_sys::Element> + 'static,
+    M: StyleMap + 'static,
+{
+    Effect::new(move |prev: Option<HashMap<String, String>>| {
+        let next = map.with(|map| map.style_entries());
+        let prev = prev.unwrap_or_default();
+        let diff = diff_styles(&prev, &next);
+        if let Some(style) = crate::attribute::get_style_decl(el.as_ref()) {
+            for name in diff.removed {
+                let _ = style.remove_property(name);
+            }
+            for (name, value) in diff.changed {
+                let _ = style.set_property(name, value);
+            }
+        }
+        next
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styles(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>()
+            .style_entries()
+    }
+
+    #[test]
+    fn style_map_only_touches_changed_properties() {
+        let first = styles(&[("color", "red"), ("width", "10px")]);
+        let empty = HashMap::new();
+        let diff = diff_styles(&empty, &first);
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.changed.len(), 2);
+
+        let recolored = styles(&[("color", "blue"), ("width", "10px")]);
+        assert_eq!(
+            diff_styles(&first, &recolored),
+            StyleDiff {
+                removed: vec![],
+                changed: vec![("color", "blue")],
+            }
+        );
+
+        let narrowed = styles(&[("color", "blue")]);
+        assert_eq!(
+            diff_styles(&recolored, &narrowed),
+            StyleDiff {
+                removed: vec!["width"],
+                changed: vec![],
+            }
+        );
+        assert_eq!(diff_styles(&narrowed, &narrowed), StyleDiff::default());
+    }
+
+    #[test]
+    fn duplicate_properties_keep_the_last_value() {
+        let map = styles(&[("color", "red"), ("color", "blue")]);
+        assert_eq!(map.get("color").map(String::as_str), Some("blue"));
+    }
+}
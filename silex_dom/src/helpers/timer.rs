@@ -0,0 +1,195 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::*;
+
+use silex_core::reactivity::on_cleanup;
+
+use super::window;
+
+fn closure_once(cb: impl FnOnce() + 'static) -> JsValue {
+    let mut wrapped_cb: Option<Box<dyn FnOnce()>> = Some(Box::new(cb));
+    let closure = Closure::new(move || {
+        if let Some(cb) = wrapped_cb.take() {
+            cb()
+        }
+    });
+    closure.into_js_value()
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AnimationFrameRequestHandle(i32);
+
+impl AnimationFrameRequestHandle {
+    pub fn cancel(&self) {
+        let _ = window().cancel_animation_frame(self.0);
+    }
+}
+
+pub fn request_animation_frame(cb: impl FnOnce() + 'static) {
+    let _ = request_animation_frame_with_handle(cb);
+}
+
+pub fn request_animation_frame_with_handle(
+    cb: impl FnOnce() + 'static,
+) -> Result<AnimationFrameRequestHandle, JsValue> {
+    window()
+        .request_animation_frame(closure_once(cb).as_ref().unchecked_ref())
+        .map(AnimationFrameRequestHandle)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IdleCallbackHandle(u32);
+
+impl IdleCallbackHandle {
+    pub fn cancel(&self) {
+        window().cancel_idle_callback(self.0);
+    }
+}
+
+pub fn request_idle_callback(cb: impl Fn() + 'static) {
+    let _ = request_idle_callback_with_handle(cb);
+}
+
+pub fn request_idle_callback_with_handle(
+    cb: impl Fn() + 'static,
+) -> Result<IdleCallbackHandle, JsValue> {
+    let cb = Closure::wrap(Box::new(cb) as Box<dyn Fn()>).into_js_value();
+    window()
+        .request_idle_callback(cb.as_ref().unchecked_ref())
+        .map(IdleCallbackHandle)
+}
+
+pub fn queue_microtask(task: impl FnOnce() + 'static) {
+    let task = Closure::once_into_js(task);
+    window().queue_microtask(&task.unchecked_into());
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TimeoutHandle(i32);
+
+impl TimeoutHandle {
+    pub fn clear(&self) {
+        window().clear_timeout_with_handle(self.0);
+    }
+}
+
+pub fn set_timeout(cb: impl FnOnce() + 'static, duration: Duration) {
+    let _ = set_timeout_with_handle(cb, duration);
+}
+
+pub fn set_timeout_with_handle(
+    cb: impl FnOnce() + 'static,
+    duration: Duration,
+) -> Result<TimeoutHandle, JsValue> {
+    window()
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            closure_once(cb).as_ref().unchecked_ref(),
+            duration.as_millis().try_into().unwrap_or(0),
+        )
+        .map(TimeoutHandle)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalHandle(i32);
+
+impl IntervalHandle {
+    pub fn clear(&self) {
+        window().clear_interval_with_handle(self.0);
+    }
+}
+
+pub fn set_interval(cb: impl Fn() + 'static, duration: Duration) {
+    let _ = set_interval_with_handle(cb, duration);
+}
+
+pub fn set_interval_with_handle(
+    cb: impl Fn() + 'static,
+    duration: Duration,
+) -> Result<IntervalHandle, JsValue> {
+    let cb = Closure::wrap(Box::new(cb) as Box<dyn FnMut()>).into_js_value();
+    window()
+        .set_interval_with_callback_and_timeout_and_arguments_0(
+            cb.as_ref().unchecked_ref(),
+            duration.as_millis().try_into().unwrap_or(0),
+        )
+        .map(IntervalHandle)
+}
+
+// --- Debounce ---
+
+/// Debounce a callback function.
+pub fn debounce<T: 'static>(delay: Duration, cb: impl FnMut(T) + 'static) -> impl FnMut(T) {
+    let cb = Rc::new(RefCell::new(cb));
+    let timer = Rc::new(RefCell::new(None::<TimeoutHandle>));
+
+    on_cleanup({
+        let timer = Rc::clone(&timer);
+        move || {
+            if let Some(timer) = timer.borrow_mut().take() {
+                timer.clear();
+            }
+        }
+    });
+
+    move |arg| {
+        if let Some(timer) = timer.borrow_mut().take() {
+            timer.clear();
+        }
+        let handle = set_timeout_with_handle(
+            {
+                let cb = Rc::clone(&cb);
+                move || {
+                    cb.borrow_mut()(arg);
+                }
+            },
+            delay,
+        );
+        if let Ok(handle) = handle {
+            *timer.borrow_mut() = Some(handle);
+        }
+    }
+}
+
+// --- Auto-cleanup Hooks ---
+
+/// 类似于 `set_interval`，但在当前响应式作用域被清理时自动取消定时器。
+///
+/// 参数顺序设计为支持尾随闭包语法：
+/// ```ignore
+/// use_interval(Duration::from_millis(100), || {
+///     /* 每 100ms 执行一次 */
+/// });
+/// ```
+///
+/// 返回 `Result<IntervalHandle, JsValue>`，允许在必要时手动提前清除。
+pub fn use_interval(
+    duration: Duration,
+    cb: impl Fn() + 'static,
+) -> Result<IntervalHandle, JsValue> {
+    let handle = set_interval_with_handle(cb, duration)?;
+    // IntervalHandle 实现了 Copy，可以直接 move 进闭包
+    let cleanup_handle = handle;
+    on_cleanup(move || cleanup_handle.clear());
+    Ok(handle)
+}
+
+/// 类似于 `set_timeout`，但在当前响应式作用域被清理时自动取消定时器（如果尚未执行）。
+///
+/// 参数顺序设计为支持尾随闭包语法：
+/// ```ignore
+/// use_timeout(Duration::from_secs(1), || {
+///     /* 1秒后执行一次 */
+/// });
+/// ```
+pub fn use_timeout(
+    duration: Duration,
+    cb: impl FnOnce() + 'static,
+) -> Result<TimeoutHandle, JsValue> {
+    let handle = set_timeout_with_handle(cb, duration)?;
+    let cleanup_handle = handle;
+    on_cleanup(move || cleanup_handle.clear());
+    Ok(handle)
+}
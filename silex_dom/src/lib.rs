@@ -1,6 +1,7 @@
 pub mod attribute;
 pub mod element;
 pub mod event;
+pub mod form;
 pub mod helpers;
 pub mod view;
 
@@ -9,11 +10,12 @@ pub mod prelude {
     pub use crate::element::*;
     pub use crate::event;
     pub use crate::event::{EventDescriptor, EventHandler, WithEventArg, WithoutEventArg};
+    pub use crate::form::*;
     pub use crate::helpers::*;
     pub use crate::setup_global_error_handlers;
     pub use crate::view::{
-        ApplyAttributes, AutoReactiveView, Mount, MountExt, MountRef, MountRefExt,
-        ViewCons, ViewNil, any::*, reactive::*,
+        ApplyAttributes, AutoReactiveView, Mount, MountExt, MountRef, MountRefExt, ViewCons,
+        ViewNil, any::*, reactive::*, template::*,
     };
     pub use crate::{view_chain, view_match};
 }
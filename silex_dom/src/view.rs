@@ -1,13 +1,15 @@
 pub mod any;
 pub mod reactive;
+pub mod template;
 
 pub use any::*;
 pub use reactive::*;
+pub use template::*;
 
 use crate::attribute::PendingAttribute;
 use silex_core::error::handle_error;
 use silex_core::logic::Map;
-use silex_core::reactivity::Effect;
+use silex_core::reactivity::{Effect, MountGate, use_context};
 use silex_core::traits::{IntoRx, IntoSignal, RxValue};
 use silex_core::{Rx, RxValueKind, SilexError, SilexResult};
 use std::ops::Deref;
@@ -430,12 +432,16 @@ pub fn mount_dynamic_view_universal(
         return;
     }
 
+    // 动态区域每次重渲染都拥有独立的挂载闸门，并在父级挂载完成后才放行
+    let parent_gate = use_context::<MountGate>();
+
     Effect::new(move |_| {
         let start_node = start_node.clone();
         let end_node = end_node.clone();
         let document = document.clone();
         let attrs = attrs.clone();
         let renderer = &renderer;
+        let gate = parent_gate.map(|_| MountGate::provide());
 
         let result = catch_unwind(AssertUnwindSafe(move || {
             // 1. 在生产新视图前，先同步清理旧 DOM 节点。
@@ -459,6 +465,10 @@ pub fn mount_dynamic_view_universal(
             if let Some(parent) = end_node.parent_node() {
                 let _ = parent.insert_before(&fragment_node, Some(&end_node));
             }
+
+            if let (Some(parent_gate), Some(gate)) = (parent_gate, gate) {
+                parent_gate.on_mount(move || gate.mark_mounted());
+            }
         }));
 
         if let Err(payload) = result {
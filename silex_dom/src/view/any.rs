@@ -252,6 +252,7 @@ pub type RenderThunk = silex_vtable::thunk::ThunkBox<(Node, Vec<PendingAttribute
 /// 优化的 SharedView，专用于需要重复使用或需要 Children 的组件边界
 #[derive(Default)]
 pub enum SharedView {
+    /// 空视图：挂载时不创建任何节点，是 `Children` 的默认值
     #[default]
     Empty,
     Text(String),
@@ -263,6 +264,7 @@ pub enum SharedView {
 /// 优化的 AnyView，作为所有视图类型擦除的终点（不要求 Clone）
 #[derive(Default)]
 pub enum AnyView {
+    /// 空视图：挂载时不创建任何节点，是 `AnyView` 属性的默认值
     #[default]
     Empty,
     Text(String),
@@ -630,6 +632,9 @@ where
 ///
 /// 它会自动对每个分支的结果调用 `.into_shared()`，从而允许不同类型的 View 在同一个 `match` 块中返回。
 ///
+/// 分支模式原样传给内部的 `match`，因此字符串字面量、枚举变体、元组或带守卫的模式均可使用；
+/// 穷尽性检查也与 `match` 一致，未覆盖全部变体时需要 `_` 分支。
+///
 /// # 示例
 ///
 /// ```rust, ignore
@@ -638,6 +643,13 @@ where
 ///     AppRoute::Basics => "Basics Page",
 ///     AppRoute::NotFound => (),
 /// })
+///
+/// // 配合 Dynamic 根据枚举信号切换视图
+/// Dynamic::bind(mode, |m| view_match!(m, {
+///     Mode::List => ListView::new(),
+///     Mode::Grid { columns } if columns > 1 => GridView::new(columns),
+///     _ => "Unsupported",
+/// }))
 /// ```
 #[macro_export]
 macro_rules! view_match {
@@ -650,12 +662,13 @@ macro_rules! view_match {
     };
 }
 
+/// 与 [`view_match!`] 相同，但各分支转换为 `AnyView`。
 #[macro_export]
 macro_rules! any_view_match {
     ($target:expr, { $($pat:pat $(if $guard:expr)? => $val:expr),* $(,)? }) => {
         match $target {
             $(
-                $pat $(if $guard)? => $MountExt::into_any($val),
+                $pat $(if $guard)? => $crate::view::MountExt::into_any($val),
             )*
         }
     };
@@ -1,7 +1,7 @@
 use crate::attribute::PendingAttribute;
 use silex_core::error::handle_error;
-use silex_core::reactivity::Effect;
-use silex_core::traits::{IntoRx, RxCloneData, RxRead};
+use silex_core::reactivity::{Effect, Signal};
+use silex_core::traits::{IntoRx, RxCloneData, RxData, RxRead};
 use silex_core::{Rx, SilexError};
 use std::fmt::Display;
 use web_sys::Node;
@@ -30,6 +30,46 @@ where
     });
 }
 
+/// 以 `write` 更新同一文本节点，仅在格式化后的字符串变化时写入
+pub(crate) fn bind_text_memo(text: impl Fn() -> String + 'static, write: impl Fn(&str) + 'static) {
+    Effect::new(move |prev: Option<String>| {
+        let next = text();
+        if prev.as_ref() != Some(&next) {
+            write(&next);
+        }
+        next
+    });
+}
+
+/// 文本节点视图，由 [`text_memo`](crate::element::text_memo) 创建
+#[derive(Clone)]
+pub struct TextMemo<T> {
+    pub(crate) source: Signal<T>,
+}
+
+impl<T> crate::view::ApplyAttributes for TextMemo<T> {}
+
+impl<T: Display + RxData> crate::view::Mount for TextMemo<T> {
+    fn mount(self, parent: &Node, attrs: Vec<PendingAttribute>) {
+        crate::view::MountRef::mount_ref(&self, parent, attrs);
+    }
+}
+
+impl<T: Display + RxData> crate::view::MountRef for TextMemo<T> {
+    fn mount_ref(&self, parent: &Node, _attrs: Vec<PendingAttribute>) {
+        let node: Node = crate::document().create_text_node("").into();
+        if let Err(e) = parent.append_child(&node).map_err(SilexError::from) {
+            handle_error(e);
+            return;
+        }
+        let source = self.source;
+        bind_text_memo(
+            move || source.with(|value| value.to_string()),
+            move |text| node.set_node_value(Some(text)),
+        );
+    }
+}
+
 // --- 响应式组件视图内核 (Reactive View Core) ---
 
 pub(crate) fn mount_reactive_view<V, M>(parent: &Node, rx: Rx<V, M>, attrs: Vec<PendingAttribute>)
@@ -343,3 +383,28 @@ where
         self.clone().into_rx().mount_ref(parent, attrs);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{RwSignal, create_scope};
+    use silex_core::traits::RxWrite;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn text_memo_skips_identical_strings() {
+        create_scope(|| {
+            let writes = Rc::new(RefCell::new(Vec::new()));
+            let count = RwSignal::new(0);
+            bind_text_memo(move || count.with(|c| c.to_string()), {
+                let writes = writes.clone();
+                move |text| writes.borrow_mut().push(text.to_string())
+            });
+
+            count.set(1);
+            count.set(1);
+            assert_eq!(*writes.borrow(), vec!["0", "1"]);
+        });
+    }
+}
@@ -0,0 +1,275 @@
+use crate::attribute::PendingAttribute;
+use crate::element::Element;
+use crate::view::{AnyView, ApplyAttributes, Mount, MountExt, MountRef};
+use silex_core::SilexError;
+use silex_core::error::handle_error;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+use web_sys::{DocumentFragment, HtmlTemplateElement, Node};
+
+/// 按内容缓存已解析的模板，同一段标记只构建一次
+pub(crate) struct TemplateCache<T> {
+    entries: HashMap<Box<str>, T>,
+}
+
+impl<T> Default for TemplateCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<T> TemplateCache<T> {
+    /// 取出缓存的模板，未命中时以 `build` 构建并缓存
+    pub(crate) fn get_or_build(&mut self, html: &str, build: impl FnOnce(&str) -> T) -> &T {
+        if !self.entries.contains_key(html) {
+            self.entries.insert(html.into(), build(html));
+        }
+        &self.entries[html]
+    }
+}
+
+thread_local! {
+    static TEMPLATES: RefCell<TemplateCache<HtmlTemplateElement>> = RefCell::new(TemplateCache::default());
+}
+
+/// 实例化模板所需的 DOM 操作：解析标记、克隆内容、查找插槽
+trait TemplateDom {
+    type Template;
+    type Fragment;
+    type Slot;
+
+    fn parse(&self, html: &str) -> Self::Template;
+    fn clone_content(&self, template: &Self::Template) -> Self::Fragment;
+    /// 查找 `data-hole="name"` 元素并移除该标记
+    fn take_hole(
+        &self,
+        fragment: &Self::Fragment,
+        name: &str,
+    ) -> Result<Option<Self::Slot>, SilexError>;
+}
+
+/// 基于 `<template>` 元素的实现
+struct WebTemplates;
+
+impl TemplateDom for WebTemplates {
+    type Template = HtmlTemplateElement;
+    type Fragment = DocumentFragment;
+    type Slot = web_sys::Element;
+
+    fn parse(&self, html: &str) -> HtmlTemplateElement {
+        let template: HtmlTemplateElement = crate::document()
+            .create_element("template")
+            .expect("Failed to create template")
+            .unchecked_into();
+        template.set_inner_html(html);
+        template
+    }
+
+    fn clone_content(&self, template: &HtmlTemplateElement) -> DocumentFragment {
+        template
+            .content()
+            .clone_node_with_deep(true)
+            .expect("Failed to clone template")
+            .unchecked_into()
+    }
+
+    fn take_hole(
+        &self,
+        fragment: &DocumentFragment,
+        name: &str,
+    ) -> Result<Option<web_sys::Element>, SilexError> {
+        let slot = fragment
+            .query_selector(&format!("[data-hole=\"{name}\"]"))
+            .map_err(SilexError::from)?;
+        if let Some(slot) = &slot {
+            let _ = slot.remove_attribute("data-hole");
+        }
+        Ok(slot)
+    }
+}
+
+/// 静态模板视图，见 [`static_html`]
+pub struct StaticHtml {
+    html: &'static str,
+    holes: Vec<(&'static str, AnyView)>,
+    attrs: Vec<PendingAttribute>,
+}
+
+/// 以模板克隆的方式渲染一段静态标记
+///
+/// 标记在首次挂载时解析为 `<template>` 并按内容缓存，之后每次挂载只需克隆，
+/// 适合列表行等大量重复的静态子树。带 `data-hole="name"` 的元素为动态插槽，
+/// 克隆后通过 [`hole`](StaticHtml::hole) 挂载对应视图；转发的属性应用到第一个根元素。
+///
+/// ```rust,ignore
+/// static_html(r#"<li class="row"><span class="icon">★</span><span data-hole="label"></span></li>"#)
+///     .hole("label", item.name.clone())
+/// ```
+///
+/// 标记原样写入 `innerHTML`，只应传入可信的静态内容。
+pub fn static_html(html: &'static str) -> StaticHtml {
+    StaticHtml {
+        html,
+        holes: Vec::new(),
+        attrs: Vec::new(),
+    }
+}
+
+impl StaticHtml {
+    /// 在 `data-hole="name"` 元素内挂载视图
+    pub fn hole<V: MountExt + 'static>(mut self, name: &'static str, view: V) -> Self {
+        self.holes.push((name, AnyView::new(view)));
+        self
+    }
+
+    /// 克隆 (首次则解析) 模板，并在挂载任何视图之前查找全部插槽，
+    /// 避免已挂载视图中的 `data-hole` 被后面的插槽误匹配。找不到的插槽为 `None`。
+    fn prepare<D: TemplateDom>(
+        &self,
+        dom: &D,
+        cache: &mut TemplateCache<D::Template>,
+    ) -> (D::Fragment, Vec<Option<D::Slot>>) {
+        let template = cache.get_or_build(self.html, |html| dom.parse(html));
+        let fragment = dom.clone_content(template);
+        let slots = self
+            .holes
+            .iter()
+            .map(|(name, _)| match dom.take_hole(&fragment, name) {
+                Ok(Some(slot)) => Some(slot),
+                Ok(None) => {
+                    handle_error(SilexError::Dom(format!(
+                        "static_html: hole `{name}` not found"
+                    )));
+                    None
+                }
+                Err(e) => {
+                    handle_error(e);
+                    None
+                }
+            })
+            .collect();
+        (fragment, slots)
+    }
+
+    fn instantiate(&self, parent: &Node, attrs: Vec<PendingAttribute>) {
+        let (fragment, slots) =
+            TEMPLATES.with(|cache| self.prepare(&WebTemplates, &mut cache.borrow_mut()));
+
+        for ((_, view), slot) in self.holes.iter().zip(slots) {
+            if let Some(slot) = slot {
+                view.mount_ref(&slot, Vec::new());
+            }
+        }
+
+        let mut attrs_all = self.attrs.clone();
+        attrs_all.extend(attrs);
+        if !attrs_all.is_empty()
+            && let Some(root) = fragment.first_element_child()
+        {
+            Element { dom_element: root }.apply_attributes(attrs_all);
+        }
+
+        if let Err(e) = parent.append_child(&fragment).map_err(SilexError::from) {
+            handle_error(e);
+        }
+    }
+}
+
+impl ApplyAttributes for StaticHtml {
+    fn apply_attributes(&mut self, attrs: Vec<PendingAttribute>) {
+        self.attrs.extend(attrs);
+    }
+}
+
+impl Mount for StaticHtml {
+    fn mount(self, parent: &Node, attrs: Vec<PendingAttribute>) {
+        self.instantiate(parent, attrs);
+    }
+}
+
+impl MountRef for StaticHtml {
+    fn mount_ref(&self, parent: &Node, attrs: Vec<PendingAttribute>) {
+        self.instantiate(parent, attrs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// 以字符串模拟模板：插槽为标记中的 `data-hole="name"`
+    #[derive(Default)]
+    struct MockDom {
+        parses: Cell<usize>,
+        clones: Cell<usize>,
+    }
+
+    impl TemplateDom for MockDom {
+        type Template = String;
+        type Fragment = RefCell<String>;
+        type Slot = String;
+
+        fn parse(&self, html: &str) -> String {
+            self.parses.set(self.parses.get() + 1);
+            html.to_string()
+        }
+
+        fn clone_content(&self, template: &String) -> RefCell<String> {
+            self.clones.set(self.clones.get() + 1);
+            RefCell::new(template.clone())
+        }
+
+        fn take_hole(
+            &self,
+            fragment: &RefCell<String>,
+            name: &str,
+        ) -> Result<Option<String>, SilexError> {
+            let marker = format!(" data-hole=\"{name}\"");
+            let mut html = fragment.borrow_mut();
+            Ok(html.contains(&marker).then(|| {
+                *html = html.replacen(&marker, "", 1);
+                name.to_string()
+            }))
+        }
+    }
+
+    const ROW: &str = "<li><b data-hole=\"icon\"></b><span data-hole=\"label\"></span></li>";
+
+    #[test]
+    fn same_markup_is_parsed_once_and_cloned() {
+        let dom = MockDom::default();
+        let mut cache = TemplateCache::default();
+
+        for label in ["a", "b", "c"] {
+            let row = static_html(ROW).hole("icon", "★").hole("label", label);
+            let (fragment, slots) = row.prepare(&dom, &mut cache);
+            assert_eq!(
+                slots,
+                vec![Some("icon".to_string()), Some("label".to_string())]
+            );
+            assert!(!fragment.borrow().contains("data-hole"));
+        }
+        assert_eq!((dom.parses.get(), dom.clones.get()), (1, 3));
+
+        let (_, slots) = static_html("<p></p>").prepare(&dom, &mut cache);
+        assert!(slots.is_empty());
+        assert_eq!(dom.parses.get(), 2);
+    }
+
+    #[test]
+    fn all_holes_are_resolved_before_mounting() {
+        let dom = MockDom::default();
+        let mut cache = TemplateCache::default();
+        // 重复与缺失的插槽：每个插槽只匹配模板自身的一个元素
+        let row = static_html(ROW)
+            .hole("label", "x")
+            .hole("missing", "y")
+            .hole("label", "z");
+        let (_, slots) = row.prepare(&dom, &mut cache);
+        assert_eq!(slots, vec![Some("label".to_string()), None, None]);
+    }
+}
@@ -0,0 +1,253 @@
+use silex_core::reactivity::{Memo, ReadSignal, RwSignal, Signal};
+use silex_core::traits::RxGet;
+use silex_dom::prelude::*;
+
+macro_rules! define_aria_attrs {
+    ($($variant:ident => $name:literal),* $(,)?) => {
+        /// WAI-ARIA 状态与属性，配合 [`AriaDataAttributes::aria`] 使用
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum AriaAttr {
+            $($variant,)*
+        }
+
+        impl AriaAttr {
+            /// 对应的属性名 (e.g. `aria-label`)
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+define_aria_attrs! {
+    // --- Widget ---
+    Autocomplete => "aria-autocomplete",
+    Checked => "aria-checked",
+    Disabled => "aria-disabled",
+    Errormessage => "aria-errormessage",
+    Expanded => "aria-expanded",
+    Haspopup => "aria-haspopup",
+    Hidden => "aria-hidden",
+    Invalid => "aria-invalid",
+    Label => "aria-label",
+    Level => "aria-level",
+    Modal => "aria-modal",
+    Multiline => "aria-multiline",
+    Multiselectable => "aria-multiselectable",
+    Orientation => "aria-orientation",
+    Placeholder => "aria-placeholder",
+    Pressed => "aria-pressed",
+    Readonly => "aria-readonly",
+    Required => "aria-required",
+    Selected => "aria-selected",
+    Sort => "aria-sort",
+    Valuemax => "aria-valuemax",
+    Valuemin => "aria-valuemin",
+    Valuenow => "aria-valuenow",
+    Valuetext => "aria-valuetext",
+    // --- Live Region ---
+    Atomic => "aria-atomic",
+    Busy => "aria-busy",
+    Live => "aria-live",
+    Relevant => "aria-relevant",
+    // --- Relationship ---
+    Activedescendant => "aria-activedescendant",
+    Colcount => "aria-colcount",
+    Colindex => "aria-colindex",
+    Colspan => "aria-colspan",
+    Controls => "aria-controls",
+    Current => "aria-current",
+    Describedby => "aria-describedby",
+    Description => "aria-description",
+    Details => "aria-details",
+    Flowto => "aria-flowto",
+    Labelledby => "aria-labelledby",
+    Owns => "aria-owns",
+    Posinset => "aria-posinset",
+    Rowcount => "aria-rowcount",
+    Rowindex => "aria-rowindex",
+    Rowspan => "aria-rowspan",
+    Setsize => "aria-setsize",
+    // --- Other ---
+    Keyshortcuts => "aria-keyshortcuts",
+    Roledescription => "aria-roledescription",
+}
+
+impl std::fmt::Display for AriaAttr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// ARIA 属性的取值
+///
+/// 布尔值写为 `"true"`/`"false"` 字符串，而非 HTML 布尔属性的有无；其余取值原样透传。
+pub trait IntoAriaValue {
+    type Value: IntoStorable;
+    fn into_aria_value(self) -> Self::Value;
+}
+
+fn bool_str(value: bool) -> &'static str {
+    if value { "true" } else { "false" }
+}
+
+impl IntoAriaValue for bool {
+    type Value = &'static str;
+    fn into_aria_value(self) -> Self::Value {
+        bool_str(self)
+    }
+}
+
+macro_rules! impl_aria_passthrough {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl IntoAriaValue for $ty {
+                type Value = $ty;
+                fn into_aria_value(self) -> Self::Value {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_aria_passthrough!(
+    &'static str,
+    String,
+    i32,
+    i64,
+    u32,
+    u64,
+    usize,
+    f64,
+    Signal<String>,
+    ReadSignal<String>,
+    RwSignal<String>,
+    Memo<String>,
+);
+
+macro_rules! impl_aria_bool_signal {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl IntoAriaValue for $ty {
+                type Value = Memo<String>;
+                fn into_aria_value(self) -> Self::Value {
+                    Memo::new(move |_| bool_str(self.get()).to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_aria_bool_signal!(Signal<bool>, ReadSignal<bool>, RwSignal<bool>, Memo<bool>);
+
+/// 类型化的 `aria-*` 与 `data-*` 属性：提供给所有元素使用
+pub trait AriaDataAttributes: AttributeBuilder {
+    /// 设置 ARIA 状态/属性，例如 `.aria(AriaAttr::Expanded, open)`，传入信号时随信号更新
+    fn aria(self, attr: AriaAttr, value: impl IntoAriaValue) -> Self {
+        self.attr(attr.as_str(), value.into_aria_value())
+    }
+
+    /// 设置 `data-{key}` 属性，例如 `.data("user-id", id)`
+    fn data(self, key: &str, value: impl IntoStorable) -> Self {
+        self.attr(&format!("data-{key}"), value)
+    }
+}
+
+impl<T: AttributeBuilder> AriaDataAttributes for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silex_core::reactivity::{Effect, create_scope};
+    use silex_core::traits::RxWrite;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Default)]
+    struct Attrs(Vec<PendingAttribute>);
+
+    impl AttributeBuilder for Attrs {
+        fn build_attribute<V>(mut self, target: ApplyTarget, value: V) -> Self
+        where
+            V: IntoStorable,
+        {
+            self.0.push(PendingAttribute::build(
+                value.into_storable(),
+                target.into(),
+            ));
+            self
+        }
+
+        fn build_event<E, F, M>(self, _event: E, _callback: F) -> Self
+        where
+            E: EventDescriptor + 'static,
+            F: EventHandler<E::EventType, M> + Clone + 'static,
+        {
+            self
+        }
+    }
+
+    fn static_attrs(attrs: &Attrs) -> Vec<(String, String)> {
+        attrs
+            .0
+            .iter()
+            .map(|attr| match &attr.op {
+                AttrOp::Update {
+                    name,
+                    data: AttrData::StaticString(value),
+                    ..
+                } => (name.to_string(), value.to_string()),
+                _ => panic!("expected a static string attribute"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn aria_booleans_are_written_as_strings() {
+        let attrs = Attrs::default()
+            .aria(AriaAttr::Hidden, true)
+            .aria(AriaAttr::Label, "Close")
+            .data("user-id", "42");
+        assert_eq!(
+            static_attrs(&attrs),
+            vec![
+                ("aria-hidden".to_string(), "true".to_string()),
+                ("aria-label".to_string(), "Close".to_string()),
+                ("data-user-id".to_string(), "42".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn aria_follows_bool_signal() {
+        create_scope(|| {
+            let open = RwSignal::new(false);
+            let attrs = Attrs::default().aria(AriaAttr::Expanded, open);
+            let [attr] = attrs.0.as_slice() else {
+                panic!("expected a single attribute");
+            };
+            let AttrOp::Update {
+                name,
+                data: AttrData::ReactiveString(value),
+                ..
+            } = &attr.op
+            else {
+                panic!("expected a reactive attribute update");
+            };
+            assert_eq!(name.as_ref(), "aria-expanded");
+
+            // 与挂载时相同，由 Effect 将最新取值写入属性
+            let written = Rc::new(RefCell::new(Vec::new()));
+            let value = value.clone();
+            Effect::new({
+                let written = written.clone();
+                move |_| written.borrow_mut().push(value.get())
+            });
+            open.set(true);
+            assert_eq!(*written.borrow(), vec!["false", "true"]);
+        });
+    }
+}
@@ -19,6 +19,14 @@ pub trait FormAttributes: AttributeBuilder {
         self.prop("disabled", value)
     }
 
+    /// 按条件设置禁用状态，并与所在表单分组（见 [`form_group`]）的禁用状态合并。
+    fn disabled_if<V>(self, value: V) -> Self
+    where
+        V: silex_core::traits::IntoSignal<Value = bool> + 'static,
+    {
+        self.prop("disabled", form_disabled(value))
+    }
+
     fn readonly(self, value: impl IntoStorable) -> Self {
         self.prop("readOnly", value)
     }
@@ -163,6 +171,27 @@ pub trait OpenAttributes: AttributeBuilder {
     fn open(self, value: impl IntoStorable) -> Self {
         self.prop("open", value)
     }
+
+    /// 将 `open` 状态与信号双向绑定：信号变化时展开/收起元素，用户操作触发 `toggle` 事件时写回信号。
+    ///
+    /// 同时将 `data-state` 属性设置为 "open"/"closed"，便于编写过渡样式。
+    fn bind_open<S>(self, signal: S) -> Self
+    where
+        S: Into<silex_core::reactivity::RwSignal<bool>>,
+    {
+        let signal = signal.into();
+        self.data_state(open_state(signal))
+            .apply(PendingAttribute::new_listener(
+                move |el: &web_sys::Element| {
+                    let mut on_toggle = bind_open_state(el.clone(), signal);
+                    bind_event_impl(
+                        el,
+                        "toggle".to_string(),
+                        Box::new(move |_: web_sys::Event| on_toggle()),
+                    );
+                },
+            ))
+    }
 }
 
 /// 表格单元格属性：主要用于 td, th
@@ -191,6 +220,51 @@ pub trait TableHeaderAttributes: AttributeBuilder {
     }
 }
 
+/// SVG 表现属性：用于所有 SVG 元素
+///
+/// SVG 元素以 `createElementNS` 创建，属性名区分大小写，因此按原样 (如 `viewBox`) 写入。
+pub trait SvgPresentationAttributes: AttributeBuilder {
+    fn fill(self, value: impl IntoStorable) -> Self {
+        self.attr("fill", value)
+    }
+
+    fn stroke(self, value: impl IntoStorable) -> Self {
+        self.attr("stroke", value)
+    }
+
+    fn stroke_width(self, value: impl IntoStorable) -> Self {
+        self.attr("stroke-width", value)
+    }
+
+    fn stroke_linecap(self, value: impl IntoStorable) -> Self {
+        self.attr("stroke-linecap", value)
+    }
+
+    fn stroke_linejoin(self, value: impl IntoStorable) -> Self {
+        self.attr("stroke-linejoin", value)
+    }
+
+    fn transform(self, value: impl IntoStorable) -> Self {
+        self.attr("transform", value)
+    }
+
+    /// 设置 `viewBox="{min_x} {min_y} {width} {height}"`
+    fn view_box(
+        self,
+        min_x: impl std::fmt::Display,
+        min_y: impl std::fmt::Display,
+        width: impl std::fmt::Display,
+        height: impl std::fmt::Display,
+    ) -> Self {
+        self.attr("viewBox", format!("{min_x} {min_y} {width} {height}"))
+    }
+
+    /// path 的路径数据
+    fn d(self, value: impl IntoStorable) -> Self {
+        self.attr("d", value)
+    }
+}
+
 // --- Blanket Implementations for TypedElement<T> ---
 
 impl<T: FormTag> FormAttributes for TypedElement<T> {}
@@ -200,3 +274,125 @@ impl<T: MediaTag> MediaAttributes for TypedElement<T> {}
 impl<T: OpenTag> OpenAttributes for TypedElement<T> {}
 impl<T: TableCellTag> TableCellAttributes for TypedElement<T> {}
 impl<T: TableHeaderTag> TableHeaderAttributes for TypedElement<T> {}
+impl<T: SvgTag> SvgPresentationAttributes for TypedElement<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aria::{AriaAttr, AriaDataAttributes};
+
+    #[derive(Default)]
+    struct Attrs(Vec<PendingAttribute>);
+
+    impl AttributeBuilder for Attrs {
+        fn build_attribute<V>(mut self, target: ApplyTarget, value: V) -> Self
+        where
+            V: IntoStorable,
+        {
+            self.0.push(PendingAttribute::build(
+                value.into_storable(),
+                target.into(),
+            ));
+            self
+        }
+
+        fn build_event<E, F, M>(self, _event: E, _callback: F) -> Self
+        where
+            E: EventDescriptor + 'static,
+            F: EventHandler<E::EventType, M> + Clone + 'static,
+        {
+            self
+        }
+    }
+
+    impl SvgPresentationAttributes for Attrs {}
+    impl FormAttributes for Attrs {}
+
+    fn static_attrs(attrs: &Attrs) -> Vec<(String, String)> {
+        attrs
+            .0
+            .iter()
+            .map(|attr| match &attr.op {
+                AttrOp::Update {
+                    name,
+                    data: AttrData::StaticString(value),
+                    ..
+                } => (name.to_string(), value.to_string()),
+                _ => panic!("expected a static string attribute"),
+            })
+            .collect()
+    }
+
+    /// 仅检查类型：所有标签都带有全局与 ARIA 属性方法
+    #[allow(dead_code)]
+    fn tags_have_global_attributes() {
+        let _ = crate::div(())
+            .tabindex(0)
+            .contenteditable("true")
+            .aria(AriaAttr::Labelledby, "heading");
+        let _ = crate::svg(()).tabindex(-1).role("img");
+    }
+
+    #[test]
+    fn global_attributes_write_attribute_strings() {
+        let attrs = Attrs::default()
+            .tabindex(0)
+            .contenteditable("true")
+            .spellcheck("false")
+            .aria(AriaAttr::Expanded, true);
+        assert_eq!(
+            static_attrs(&attrs),
+            [
+                ("tabindex", "0"),
+                ("contenteditable", "true"),
+                ("spellcheck", "false"),
+                ("aria-expanded", "true"),
+            ]
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+        );
+    }
+
+    /// 仅检查类型：`disabled` 仍接受任意 `IntoStorable`
+    #[allow(dead_code)]
+    fn disabled_accepts_storable_values() {
+        let _ = crate::input().disabled("");
+        let _ = crate::button(()).disabled(true).disabled_if(false);
+    }
+
+    #[test]
+    fn disabled_if_follows_the_form_group() {
+        silex_core::reactivity::create_scope(|| {
+            let attrs = form_group(true, || Attrs::default().disabled_if(false));
+            let [attr] = attrs.0.as_slice() else {
+                panic!("expected a single attribute");
+            };
+            let AttrOp::Update {
+                data: AttrData::ReactiveBool(rx),
+                ..
+            } = &attr.op
+            else {
+                panic!("expected a reactive bool property");
+            };
+            assert!(silex_core::traits::RxGet::get_untracked(rx));
+        });
+    }
+
+    #[test]
+    fn svg_presentation_attributes_use_svg_names() {
+        let attrs = Attrs::default()
+            .view_box(0, 0, 24, 24)
+            .stroke_width(2)
+            .fill("none")
+            .d("M0 0L24 24");
+        assert_eq!(
+            static_attrs(&attrs),
+            [
+                ("viewBox", "0 0 24 24"),
+                ("stroke-width", "2"),
+                ("fill", "none"),
+                ("d", "M0 0L24 24"),
+            ]
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+        );
+    }
+}
@@ -6,8 +6,12 @@ mod tags {
 pub use silex_dom::view::{ViewCons, ViewNil};
 pub use silex_dom::view_chain;
 
+pub mod aria;
 pub mod attributes;
+pub mod svg_path;
+pub use aria::*;
 pub use attributes::*;
+pub use svg_path::*;
 
 pub use tags::{html, svg};
 
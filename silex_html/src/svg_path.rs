@@ -0,0 +1,187 @@
+use std::fmt::Write;
+
+/// SVG 路径数据 (`d` 属性) 构建器
+///
+/// `*_to` 方法使用绝对坐标 (大写命令)，`*_by` 方法使用相对坐标 (小写命令)：
+///
+/// ```rust,ignore
+/// path().d(
+///     PathBuilder::new()
+///         .move_to(9, 12)
+///         .line_by(2, 2)
+///         .line_by(4, -4)
+///         .close()
+///         .build(),
+/// )
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PathBuilder {
+    data: String,
+}
+
+impl PathBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn command(mut self, command: char, args: &[f64]) -> Self {
+        if !self.data.is_empty() {
+            self.data.push(' ');
+        }
+        self.data.push(command);
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                self.data.push(' ');
+            }
+            let _ = write!(self.data, "{arg}");
+        }
+        self
+    }
+
+    /// `M x y`
+    pub fn move_to(self, x: impl Into<f64>, y: impl Into<f64>) -> Self {
+        self.command('M', &[x.into(), y.into()])
+    }
+
+    /// `m dx dy`
+    pub fn move_by(self, dx: impl Into<f64>, dy: impl Into<f64>) -> Self {
+        self.command('m', &[dx.into(), dy.into()])
+    }
+
+    /// `L x y`
+    pub fn line_to(self, x: impl Into<f64>, y: impl Into<f64>) -> Self {
+        self.command('L', &[x.into(), y.into()])
+    }
+
+    /// `l dx dy`
+    pub fn line_by(self, dx: impl Into<f64>, dy: impl Into<f64>) -> Self {
+        self.command('l', &[dx.into(), dy.into()])
+    }
+
+    /// `H x`
+    pub fn horizontal_to(self, x: impl Into<f64>) -> Self {
+        self.command('H', &[x.into()])
+    }
+
+    /// `h dx`
+    pub fn horizontal_by(self, dx: impl Into<f64>) -> Self {
+        self.command('h', &[dx.into()])
+    }
+
+    /// `V y`
+    pub fn vertical_to(self, y: impl Into<f64>) -> Self {
+        self.command('V', &[y.into()])
+    }
+
+    /// `v dy`
+    pub fn vertical_by(self, dy: impl Into<f64>) -> Self {
+        self.command('v', &[dy.into()])
+    }
+
+    /// 三次贝塞尔曲线 `C x1 y1 x2 y2 x y`
+    pub fn curve_to(self, control1: (f64, f64), control2: (f64, f64), end: (f64, f64)) -> Self {
+        self.command(
+            'C',
+            &[control1.0, control1.1, control2.0, control2.1, end.0, end.1],
+        )
+    }
+
+    /// 相对坐标的三次贝塞尔曲线 `c dx1 dy1 dx2 dy2 dx dy`
+    pub fn curve_by(self, control1: (f64, f64), control2: (f64, f64), end: (f64, f64)) -> Self {
+        self.command(
+            'c',
+            &[control1.0, control1.1, control2.0, control2.1, end.0, end.1],
+        )
+    }
+
+    /// 二次贝塞尔曲线 `Q x1 y1 x y`
+    pub fn quad_to(self, control: (f64, f64), end: (f64, f64)) -> Self {
+        self.command('Q', &[control.0, control.1, end.0, end.1])
+    }
+
+    /// 相对坐标的二次贝塞尔曲线 `q dx1 dy1 dx dy`
+    pub fn quad_by(self, control: (f64, f64), end: (f64, f64)) -> Self {
+        self.command('q', &[control.0, control.1, end.0, end.1])
+    }
+
+    /// 椭圆弧 `A rx ry rotation large_arc sweep x y`
+    pub fn arc_to(
+        self,
+        radii: (f64, f64),
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        end: (f64, f64),
+    ) -> Self {
+        self.command(
+            'A',
+            &[
+                radii.0,
+                radii.1,
+                rotation,
+                f64::from(u8::from(large_arc)),
+                f64::from(u8::from(sweep)),
+                end.0,
+                end.1,
+            ],
+        )
+    }
+
+    /// 相对坐标的椭圆弧 `a rx ry rotation large_arc sweep dx dy`
+    pub fn arc_by(
+        self,
+        radii: (f64, f64),
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        end: (f64, f64),
+    ) -> Self {
+        self.command(
+            'a',
+            &[
+                radii.0,
+                radii.1,
+                rotation,
+                f64::from(u8::from(large_arc)),
+                f64::from(u8::from(sweep)),
+                end.0,
+                end.1,
+            ],
+        )
+    }
+
+    /// `Z`
+    pub fn close(self) -> Self {
+        self.command('Z', &[])
+    }
+
+    /// 生成 `d` 属性的字符串
+    pub fn build(self) -> String {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_exact_path_data() {
+        let d = PathBuilder::new()
+            .move_to(9, 12)
+            .line_by(2, 2)
+            .line_by(4, -4)
+            .horizontal_to(20.5)
+            .vertical_by(-1)
+            .curve_to((1.0, 2.0), (3.0, 4.0), (5.0, 6.0))
+            .quad_by((1.0, 1.0), (2.0, 0.0))
+            .arc_by((9.0, 9.0), 0.0, true, true, (-18.0, 0.0))
+            .close()
+            .build();
+        assert_eq!(
+            d,
+            "M9 12 l2 2 l4 -4 H20.5 v-1 C1 2 3 4 5 6 q1 1 2 0 a9 9 0 1 1 -18 0 Z"
+        );
+        assert_eq!(PathBuilder::new().build(), "");
+    }
+}
@@ -74,6 +74,18 @@ pub fn generate_component(input_fn: ItemFn, attrs: ComponentAttrs) -> syn::Resul
 
         let mut prop_attrs = parse_prop_attrs(attrs)?;
 
+        // 具名插槽：等价于 `#[prop(default, into)]` 的 Children，未设置时为空视图
+        if prop_attrs.slot {
+            if get_base_type_name(ty) != "Children" {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "`#[slot]` parameters must have type `Children`",
+                ));
+            }
+            prop_attrs.default = true;
+            prop_attrs.into_trait = true;
+        }
+
         // Auto-enable `into` for specific types to improve DX
         let type_ident = get_base_type_name(ty);
         if !prop_attrs.into_trait
@@ -112,7 +124,16 @@ pub fn generate_component(input_fn: ItemFn, attrs: ComponentAttrs) -> syn::Resul
         // 2. 如果无 default 值 (必填)，字段类型为 Option<T>，初始化为 None。
         //    在 mount 时 check unwrap。
 
-        let is_required = !prop_attrs.default && prop_attrs.default_value.is_none();
+        if prop_attrs.optional && prop_attrs.default {
+            return Err(syn::Error::new_spanned(
+                pat,
+                "`optional` and `default` cannot be combined",
+            ));
+        }
+
+        let is_required = !prop_attrs.optional && !prop_attrs.default;
+        // 必填与 optional 字段均存为 Option<T>，构建器需要包裹 Some
+        let wrap_some = is_required || prop_attrs.optional;
 
         if is_required {
             // 必填字段：存为 Option<T>
@@ -130,6 +151,20 @@ pub fn generate_component(input_fn: ItemFn, attrs: ComponentAttrs) -> syn::Resul
             mount_ref_checks.push(quote! {
                 let #param_name = ::silex::dom::view::Prop::new_borrowed(self.#param_name.as_ref().expect(concat!("Component '", stringify!(#struct_name), "' missing required prop: '", #param_name_str, "'")));
             });
+        } else if prop_attrs.optional {
+            // optional 字段：存为 Option<T>，未设置时为 None，不要求 T: Default
+            struct_fields.push(quote! {
+                pub #param_name: Option<#ty>
+            });
+            new_initializers.push(quote! {
+                #param_name: None
+            });
+            mount_checks.push(quote! {
+                let #param_name = ::silex::dom::view::Prop::new_owned(self.#param_name);
+            });
+            mount_ref_checks.push(quote! {
+                let #param_name = ::silex::dom::view::Prop::new_borrowed(&self.#param_name);
+            });
         } else {
             // 可选字段：直接存 T
             struct_fields.push(quote! {
@@ -171,7 +206,7 @@ pub fn generate_component(input_fn: ItemFn, attrs: ComponentAttrs) -> syn::Resul
             let type_ident = get_base_type_name(ty);
 
             if type_ident == "Children" || type_ident == "SharedView" {
-                if is_required {
+                if wrap_some {
                     builder_methods.push(quote! {
                         pub fn #param_name<__SilexValue: ::silex::dom::view::ApplyAttributes + ::silex::dom::view::MountRefExt + Clone>(mut self, val: __SilexValue) -> Self {
                             use ::silex::dom::view::MountRefExt;
@@ -189,7 +224,7 @@ pub fn generate_component(input_fn: ItemFn, attrs: ComponentAttrs) -> syn::Resul
                     });
                 }
             } else if type_ident == "AnyView" {
-                if is_required {
+                if wrap_some {
                     builder_methods.push(quote! {
                         pub fn #param_name<__SilexValue: ::silex::dom::view::MountExt>(mut self, val: __SilexValue) -> Self {
                             use ::silex::dom::view::MountExt;
@@ -206,7 +241,7 @@ pub fn generate_component(input_fn: ItemFn, attrs: ComponentAttrs) -> syn::Resul
                         }
                     });
                 }
-            } else if is_required {
+            } else if wrap_some {
                 builder_methods.push(quote! {
                     pub fn #param_name(mut self, val: impl Into<#ty>) -> Self {
                         self.#param_name = Some(val.into());
@@ -221,7 +256,7 @@ pub fn generate_component(input_fn: ItemFn, attrs: ComponentAttrs) -> syn::Resul
                     }
                 });
             }
-        } else if is_required {
+        } else if wrap_some {
             builder_methods.push(quote! {
                 pub fn #param_name(mut self, val: impl ::silex::dom::view::PropInto<#ty>) -> Self {
                     self.#param_name = Some(::silex::dom::view::PropInto::prop_into(val));
@@ -402,6 +437,8 @@ struct PropAttrs {
     default: bool,
     default_value: Option<TokenStream2>,
     into_trait: bool,
+    optional: bool,
+    slot: bool,
 }
 
 fn parse_prop_attrs(attrs: &[Attribute]) -> syn::Result<PropAttrs> {
@@ -409,10 +446,15 @@ fn parse_prop_attrs(attrs: &[Attribute]) -> syn::Result<PropAttrs> {
         default: false,
         default_value: None,
         into_trait: false,
+        optional: false,
+        slot: false,
     };
 
     for attr in attrs {
-        if attr.path().is_ident("prop") {
+        if attr.path().is_ident("slot") {
+            attr.meta.require_path_only()?;
+            result.slot = true;
+        } else if attr.path().is_ident("prop") {
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("default") {
                     result.default = true;
@@ -427,8 +469,11 @@ fn parse_prop_attrs(attrs: &[Attribute]) -> syn::Result<PropAttrs> {
                 } else if meta.path.is_ident("into") {
                     result.into_trait = true;
                     Ok(())
+                } else if meta.path.is_ident("optional") {
+                    result.optional = true;
+                    Ok(())
                 } else {
-                    Err(meta.error("expected `default` or `into`"))
+                    Err(meta.error("expected `default`, `into` or `optional`"))
                 }
             })?;
         }
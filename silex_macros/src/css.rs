@@ -4,6 +4,7 @@ pub mod compiler;
 pub mod error;
 pub mod styled;
 pub mod theme;
+pub mod value;
 
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
@@ -8,7 +8,7 @@ use syn::{Expr, Result, Token};
 
 enum ClassItem {
     Simple(Expr),
-    Conditional(Expr, Expr),
+    Conditional(Expr, Box<Expr>),
 }
 
 impl Parse for ClassItem {
@@ -16,7 +16,7 @@ impl Parse for ClassItem {
         let expr = input.parse::<Expr>()?;
         if input.peek(Token![=>]) {
             input.parse::<Token![=>]>()?;
-            Ok(ClassItem::Conditional(expr, input.parse()?))
+            Ok(ClassItem::Conditional(expr, Box::new(input.parse()?)))
         } else {
             Ok(ClassItem::Simple(expr))
         }
@@ -1,6 +1,6 @@
 use crate::css::ast::{CssBlock, CssRule};
 use lightningcss::stylesheet::{MinifyOptions, ParserOptions, PrinterOptions, StyleSheet};
-use lightningcss::targets::Targets;
+use lightningcss::targets::{Features, Targets};
 use proc_macro2::token_stream::IntoIter;
 use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
 use std::iter::Peekable;
@@ -24,15 +24,20 @@ pub struct CssCompileResult {
 struct ParserState {
     static_css: String,
     lifted_css: String,
+    keyframes_css: String,
+    keyframes: Vec<String>,
     expressions: Vec<(String, TokenStream)>,
     dynamic_rules: Vec<DynamicRule>,
     class_name: String,
     is_unsafe: bool,
+    /// 位于其它规则或 @-规则内部：`:global(...)` 只去掉包装，不再提升为全局规则
+    in_rule: bool,
 }
 
 #[derive(Clone, Copy)]
 struct DynamicContext<'a> {
     class_name: &'a str,
+    keyframes: &'a [String],
     is_unsafe: bool,
 }
 
@@ -57,16 +62,20 @@ impl CssCompiler {
         wrap_in_class: bool,
         is_unsafe: bool,
     ) -> Result<CssCompileResult> {
-        let ts_string = ts.to_string();
-        let hash = silex_hash::css::hash_one(&ts_string);
-        let mut buf = [0u8; 13];
-        let class_base = silex_hash::css::encode_base36(hash, &mut buf);
-        let class_name = format!("slx-{}", class_base);
-        let style_id = format!("style-{}", class_name);
+        // 类名取决于编译结果，先以占位符编译，最后再替换为内容 Hash
+        let class_name = CLASS_PLACEHOLDER.to_string();
+
+        let block: CssBlock = syn::parse2(ts)?;
 
         let mut state = ParserState {
             static_css: String::new(),
             lifted_css: String::new(),
+            keyframes_css: String::new(),
+            keyframes: if wrap_in_class {
+                collect_keyframes_names(&block)?
+            } else {
+                Vec::new()
+            },
             expressions: Vec::new(),
             dynamic_rules: Vec::new(),
             class_name: if wrap_in_class {
@@ -75,9 +84,9 @@ impl CssCompiler {
                 "".to_string()
             },
             is_unsafe,
+            in_rule: false,
         };
 
-        let block: CssBlock = syn::parse2(ts)?;
         process_css_block(&block, &mut state)?;
 
         let final_static_css = if state.lifted_css.is_empty() {
@@ -103,17 +112,28 @@ impl CssCompiler {
                 .code
         };
 
-        let final_component_css = if wrap_in_class && !state.static_css.trim().is_empty() {
-            let wrapped = format!(".{} {{ {} }}", class_name, state.static_css);
+        let has_component_css =
+            !state.static_css.trim().is_empty() || !state.keyframes_css.is_empty();
+        let final_component_css = if wrap_in_class && has_component_css {
+            // Scoped @keyframes can't live inside the class wrapper, so they follow it
+            let wrapped = format!(
+                ".{} {{ {} }} {}",
+                class_name, state.static_css, state.keyframes_css
+            );
             let mut stylesheet =
                 StyleSheet::parse(&wrapped, ParserOptions::default()).map_err(|e| {
                     crate::css::error::report_lightning_error(format!("Component CSS: {}", e), span)
                 })?;
-            stylesheet.minify(MinifyOptions::default()).ok();
+            stylesheet
+                .minify(MinifyOptions {
+                    targets: scoped_targets(),
+                    ..MinifyOptions::default()
+                })
+                .ok();
             stylesheet
                 .to_css(PrinterOptions {
                     minify: true,
-                    targets: Targets::default(),
+                    targets: scoped_targets(),
                     ..Default::default()
                 })
                 .map_err(|e| {
@@ -155,22 +175,52 @@ impl CssCompiler {
             "".to_string()
         };
 
+        // 按压缩后的 CSS 内容计算类名：内容相同的 `css!` (即使书写格式或插值表达式不同)
+        // 共享同一个类名与样式 id，只注入一次
+        let mut dynamic_rules = state.dynamic_rules;
+        let hash = silex_hash::css::hash_one((
+            wrap_in_class,
+            &final_static_css,
+            &final_component_css,
+            dynamic_rules
+                .iter()
+                .map(|rule| rule.template.as_str())
+                .collect::<Vec<_>>(),
+        ));
+        let mut buf = [0u8; 13];
+        let class_name = format!("slx-{}", silex_hash::css::encode_base36(hash, &mut buf));
+        let style_id = format!("style-{}", class_name);
+        for rule in &mut dynamic_rules {
+            rule.template = rule.template.replace(CLASS_PLACEHOLDER, &class_name);
+        }
+
         Ok(CssCompileResult {
+            static_css: final_static_css,
+            component_css: final_component_css.replace(CLASS_PLACEHOLDER, &class_name),
             class_name,
             style_id,
             static_id,
-            static_css: final_static_css,
-            component_css: final_component_css,
             expressions: state.expressions,
-            dynamic_rules: state.dynamic_rules,
+            dynamic_rules,
         })
     }
 }
 
+/// 编译期间使用的类名占位符，编译完成后替换为 `slx-{内容 Hash}`
+const CLASS_PLACEHOLDER: &str = "slx-__content_hash__";
+
+fn scoped_targets() -> Targets {
+    Targets {
+        include: Features::Nesting | Features::MediaRangeSyntax,
+        ..Targets::default()
+    }
+}
+
 fn process_css_block(block: &CssBlock, state: &mut ParserState) -> Result<()> {
     for rule in &block.rules {
         let ctx = DynamicContext {
             class_name: &state.class_name,
+            keyframes: &state.keyframes,
             is_unsafe: state.is_unsafe,
         };
         match rule {
@@ -189,7 +239,12 @@ fn process_css_block(block: &CssBlock, state: &mut ParserState) -> Result<()> {
                     prop_for_expr,
                     &ctx,
                 )?;
-                state.static_css.push_str(&val);
+                state.static_css.push_str(&scope_animation_names(
+                    &decl.property,
+                    &val,
+                    ctx.class_name,
+                    ctx.keyframes,
+                ));
 
                 if decl.semi_token.is_some() {
                     state.static_css.push_str("; ");
@@ -202,7 +257,14 @@ fn process_css_block(block: &CssBlock, state: &mut ParserState) -> Result<()> {
                 state.is_unsafe = old;
             }
             CssRule::Nested(nested) => {
-                if contains_dynamic_selector(&nested.selectors) {
+                let global = if state.class_name.is_empty() || state.in_rule {
+                    None
+                } else {
+                    global_selector_inner(&nested.selectors)
+                };
+                if let Some(selector) = global {
+                    lift_global_rule(&selector, &nested.block, state)?;
+                } else if contains_dynamic_selector(&nested.selectors) {
                     let mut selector_exprs = Vec::new();
                     let template = build_dynamic_template(
                         nested,
@@ -218,10 +280,12 @@ fn process_css_block(block: &CssBlock, state: &mut ParserState) -> Result<()> {
                         expressions: selector_exprs,
                     });
                 } else {
-                    let sel_str = build_static_selector(&nested.selectors, &state.class_name)?;
+                    let sel_str = build_static_selector(&nested.selectors)?;
                     state.static_css.push_str(&sel_str);
                     state.static_css.push_str(" { ");
+                    let in_rule = std::mem::replace(&mut state.in_rule, true);
                     process_css_block(&nested.block, state)?;
+                    state.in_rule = in_rule;
                     state.static_css.push_str(" } ");
                 }
             }
@@ -230,7 +294,13 @@ fn process_css_block(block: &CssBlock, state: &mut ParserState) -> Result<()> {
                     (at.name == "keyframes" || at.name == "font-face" || at.name == "import")
                         && !state.class_name.is_empty();
 
-                let params = extract_at_rule_params(&at.params)?;
+                let mut params = extract_at_rule_params(&at.params)?;
+                let is_scoped_keyframes = at.name == "keyframes"
+                    && !state.class_name.is_empty()
+                    && state.keyframes.contains(&params);
+                if is_scoped_keyframes {
+                    params = scoped_keyframes_name(&state.class_name, &params);
+                }
 
                 let mut rule_str = String::new();
                 rule_str.push('@');
@@ -244,6 +314,8 @@ fn process_css_block(block: &CssBlock, state: &mut ParserState) -> Result<()> {
                 let mut inner_state = ParserState {
                     static_css: String::new(),
                     lifted_css: String::new(),
+                    keyframes_css: String::new(),
+                    keyframes: state.keyframes.clone(),
                     expressions: state.expressions.clone(),
                     dynamic_rules: Vec::new(),
                     class_name: if at.name == "keyframes" {
@@ -252,6 +324,7 @@ fn process_css_block(block: &CssBlock, state: &mut ParserState) -> Result<()> {
                         state.class_name.clone()
                     },
                     is_unsafe: state.is_unsafe,
+                    in_rule: true,
                 };
 
                 process_css_block(&at.block, &mut inner_state)?;
@@ -260,13 +333,18 @@ fn process_css_block(block: &CssBlock, state: &mut ParserState) -> Result<()> {
 
                 // Sync back state
                 state.expressions = inner_state.expressions;
-                // Dynamic rules inside @-rules is not fully supported yet in this implementation,
-                // but we should probably collect them anyway.
-                for dr in inner_state.dynamic_rules {
+                // Dynamic rules inside conditional @-rules (@media, @supports...) keep their wrapper,
+                // so the runtime class rewrite only touches selectors, never the condition itself.
+                for mut dr in inner_state.dynamic_rules {
+                    dr.template = format!("@{} {} {{ {} }}", at.name, params, dr.template);
                     state.dynamic_rules.push(dr);
                 }
 
-                if is_lifted {
+                state.keyframes_css.push_str(&inner_state.keyframes_css);
+
+                if is_scoped_keyframes {
+                    state.keyframes_css.push_str(&rule_str);
+                } else if is_lifted {
                     state.lifted_css.push_str(&rule_str);
                     state.lifted_css.push('\n');
                 } else {
@@ -278,6 +356,90 @@ fn process_css_block(block: &CssBlock, state: &mut ParserState) -> Result<()> {
     Ok(())
 }
 
+/// 整个选择器为 `:global(...)` 时返回括号内的选择器
+fn global_selector_inner(ts: &TokenStream) -> Option<TokenStream> {
+    let tokens: Vec<TokenTree> = ts.clone().into_iter().collect();
+    match tokens.as_slice() {
+        [
+            TokenTree::Punct(colon),
+            TokenTree::Ident(id),
+            TokenTree::Group(g),
+        ] if colon.as_char() == ':'
+            && id == "global"
+            && g.delimiter() == Delimiter::Parenthesis =>
+        {
+            Some(g.stream())
+        }
+        _ => None,
+    }
+}
+
+/// 将顶层的 `:global(selector) { ... }` 作为不带组件类名的规则提升到全局样式
+fn lift_global_rule(
+    selector: &TokenStream,
+    block: &CssBlock,
+    state: &mut ParserState,
+) -> Result<()> {
+    let mut inner_state = ParserState {
+        static_css: String::new(),
+        lifted_css: String::new(),
+        keyframes_css: String::new(),
+        keyframes: Vec::new(),
+        expressions: Vec::new(),
+        dynamic_rules: Vec::new(),
+        class_name: "".to_string(),
+        is_unsafe: state.is_unsafe,
+        in_rule: true,
+    };
+    process_css_block(block, &mut inner_state)?;
+    if !inner_state.expressions.is_empty() || !inner_state.dynamic_rules.is_empty() {
+        return Err(syn::Error::new(
+            selector
+                .clone()
+                .into_iter()
+                .next()
+                .map_or_else(Span::call_site, |tt| tt.span()),
+            "`:global(...)` rules are injected once for all instances and cannot contain dynamic values",
+        ));
+    }
+
+    state.lifted_css.push_str(&build_static_selector(selector)?);
+    state.lifted_css.push_str(" { ");
+    state.lifted_css.push_str(&inner_state.static_css);
+    state.lifted_css.push_str(" }\n");
+    state.lifted_css.push_str(&inner_state.lifted_css);
+    Ok(())
+}
+
+/// 去掉选择器中嵌套的 `:global(...)` 包装，保留括号内的选择器
+fn strip_global_wrapper(
+    tt: &TokenTree,
+    iter: &mut Peekable<IntoIter>,
+    out: &mut String,
+    space_before: bool,
+) -> Result<bool> {
+    if let TokenTree::Punct(p) = tt
+        && p.as_char() == ':'
+    {
+        let mut ahead = iter.clone();
+        if let Some(TokenTree::Ident(id)) = ahead.next()
+            && id == "global"
+            && let Some(TokenTree::Group(g)) = ahead.next()
+            && g.delimiter() == Delimiter::Parenthesis
+        {
+            if space_before {
+                out.push(' ');
+            }
+            out.push_str(&process_selector_tokens(&g.stream(), &mut |_, _, _, _| {
+                Ok(false)
+            })?);
+            *iter = ahead;
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
 fn build_dynamic_template(
     nested: &crate::css::ast::CssNested,
     selector_exprs: &mut Vec<(String, TokenStream)>,
@@ -312,7 +474,12 @@ fn build_dynamic_block_recursive(
                 let prop_for_expr = if ctx.is_unsafe { "any" } else { &decl.property };
                 let val =
                     extract_dynamic_value(&decl.values, global_expressions, prop_for_expr, ctx)?;
-                template.push_str(&val);
+                template.push_str(&scope_animation_names(
+                    &decl.property,
+                    &val,
+                    ctx.class_name,
+                    ctx.keyframes,
+                ));
                 if decl.semi_token.is_some() {
                     template.push_str("; ");
                 }
@@ -341,7 +508,7 @@ fn build_dynamic_block_recursive(
                 template.push('@');
                 template.push_str(&at.name.to_string());
                 template.push(' ');
-                template.push_str(&append_token_stream_strings(&at.params)?);
+                template.push_str(&extract_at_rule_params(&at.params)?);
                 template.push_str(" { ");
                 build_dynamic_block_recursive(
                     &at.block,
@@ -398,10 +565,46 @@ where
     F: FnMut(&TokenTree, &mut Peekable<IntoIter>, &mut String, bool) -> Result<bool>,
 {
     let mut iter = ts.clone().into_iter().peekable();
-    process_tokens_iter(&mut iter, handler)
+    process_tokens_iter(&mut iter, handler, false)
 }
 
-fn process_tokens_iter<F>(iter: &mut Peekable<IntoIter>, handler: &mut F) -> Result<String>
+/// 与 [`process_tokens`] 相同，但按源码中的空白保留后代组合器 (`.dark &`、`&:hover .icon`)
+fn process_selector_tokens<F>(ts: &TokenStream, handler: &mut F) -> Result<String>
+where
+    F: FnMut(&TokenTree, &mut Peekable<IntoIter>, &mut String, bool) -> Result<bool>,
+{
+    let mut iter = ts.clone().into_iter().peekable();
+    process_tokens_iter(&mut iter, handler, true)
+}
+
+/// 两个 token 在源码中是否以空白分隔
+///
+/// span 没有位置信息 (较旧的 rustc 上的真实宏展开)、或 token 由其他宏生成而共享同一个 span 时无法判断，
+/// 此时在可能构成后代组合器的位置 (`.dark &`、`:hover .icon`) 保留空格；
+/// 复合选择器请紧贴书写 (`&.active`、`&:hover`)，它们不受影响。
+fn separated_in_source(prev: &TokenTree, next: &TokenTree) -> bool {
+    let prev_start = prev.span().start();
+    let end = prev.span().end();
+    let start = next.span().start();
+    let known = start.line != 0
+        && end.line != 0
+        && (start.line, start.column) != (prev_start.line, prev_start.column)
+        && (start.line, start.column) >= (end.line, end.column);
+    if known {
+        return (start.line, start.column) > (end.line, end.column);
+    }
+    let ends_compound = matches!(prev, TokenTree::Ident(_) | TokenTree::Literal(_))
+        || matches!(prev, TokenTree::Group(g) if g.delimiter() != Delimiter::Brace);
+    let starts_compound =
+        matches!(next, TokenTree::Punct(p) if matches!(p.as_char(), '&' | '.' | '#'));
+    ends_compound && starts_compound
+}
+
+fn process_tokens_iter<F>(
+    iter: &mut Peekable<IntoIter>,
+    handler: &mut F,
+    keep_spacing: bool,
+) -> Result<String>
 where
     F: FnMut(&TokenTree, &mut Peekable<IntoIter>, &mut String, bool) -> Result<bool>,
 {
@@ -414,8 +617,9 @@ where
             match (prev, &tt) {
                 (TokenTree::Ident(_), TokenTree::Ident(_))
                 | (TokenTree::Ident(_), TokenTree::Literal(_))
+                | (TokenTree::Literal(_), TokenTree::Ident(_))
                 | (TokenTree::Literal(_), TokenTree::Literal(_)) => space_before = true,
-                _ => {}
+                _ => space_before = keep_spacing && separated_in_source(prev, &tt),
             }
         }
 
@@ -440,7 +644,7 @@ where
                     out.push(delim.0);
                 }
                 let mut sub_iter = g.stream().into_iter().peekable();
-                out.push_str(&process_tokens_iter(&mut sub_iter, handler)?);
+                out.push_str(&process_tokens_iter(&mut sub_iter, handler, keep_spacing)?);
                 if delim.1 != ' ' {
                     out.push(delim.1);
                 }
@@ -511,33 +715,114 @@ fn extract_at_rule_params(ts: &TokenStream) -> Result<String> {
     // Note: At-rules with $Path currently treat the result as a static string if possible,
     // but at-rules usually don't support runtime dynamic values in the same way.
     // For now we just stringify it.
+    //
+    // Top-level groups and keywords are separated by whitespace so that media conditions
+    // like `screen and (max-width: 600px)` don't collapse into a function token `and(`.
     process_tokens(ts, &mut |tt, iter, out, space_before| {
-        if matches!(tt, TokenTree::Punct(p) if p.as_char() == '$')
-            && let Some(var) = handle_dollar_path(iter)?
-        {
-            if space_before {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '$' => {
+                if let Some(var) = handle_dollar_path(iter)? {
+                    if space_before {
+                        out.push(' ');
+                    }
+                    out.push_str(&var.to_string());
+                    return Ok(true);
+                }
+            }
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis => {
+                if out.ends_with(|c: char| c.is_alphanumeric() || c == ')') {
+                    out.push(' ');
+                }
+                out.push('(');
+                out.push_str(&append_token_stream_strings(&g.stream())?);
+                out.push(')');
+                return Ok(true);
+            }
+            TokenTree::Ident(id) if out.ends_with(')') => {
                 out.push(' ');
+                out.push_str(&id.to_string());
+                return Ok(true);
             }
-            out.push_str(&var.to_string());
-            return Ok(true);
+            _ => {}
         }
         Ok(false)
     })
 }
 
-fn build_static_selector(ts: &TokenStream, class_name: &str) -> Result<String> {
-    process_tokens(ts, &mut |tt, _, out, space_before| {
-        if let TokenTree::Punct(p) = tt
-            && p.as_char() == '&'
-            && !class_name.is_empty()
-        {
-            if space_before {
-                out.push(' ');
+/// Collects every `@keyframes` name declared in the block (at any depth), so that
+/// `animation` references can be rewritten even when they appear before the declaration.
+fn collect_keyframes_names(block: &CssBlock) -> Result<Vec<String>> {
+    fn walk(block: &CssBlock, names: &mut Vec<String>) -> Result<()> {
+        for rule in &block.rules {
+            match rule {
+                CssRule::AtRule(at) => {
+                    if at.name == "keyframes" {
+                        let name = extract_at_rule_params(&at.params)?;
+                        if !names.contains(&name) {
+                            names.push(name);
+                        }
+                    } else {
+                        walk(&at.block, names)?;
+                    }
+                }
+                CssRule::Nested(nested) => walk(&nested.block, names)?,
+                CssRule::Unsafe(u) => walk(&u.block, names)?,
+                CssRule::Declaration(_) => {}
             }
-            out.push_str(&format!(".{}", class_name));
-            return Ok(true);
         }
-        Ok(false)
+        Ok(())
+    }
+
+    let mut names = Vec::new();
+    walk(block, &mut names)?;
+    Ok(names)
+}
+
+fn scoped_keyframes_name(class_name: &str, name: &str) -> String {
+    format!("{}-{}", class_name, name)
+}
+
+/// Rewrites local keyframes names referenced by `animation` / `animation-name`
+/// to their component-scoped identifiers.
+fn scope_animation_names(
+    property: &str,
+    value: &str,
+    class_name: &str,
+    keyframes: &[String],
+) -> String {
+    if class_name.is_empty()
+        || keyframes.is_empty()
+        || (property != "animation" && property != "animation-name")
+    {
+        return value.to_string();
+    }
+
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find(is_ident_char) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest.find(|c: char| !is_ident_char(c)).unwrap_or(rest.len());
+        let ident = &rest[..end];
+        if keyframes.iter().any(|k| k == ident) {
+            out.push_str(&scoped_keyframes_name(class_name, ident));
+        } else {
+            out.push_str(ident);
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// 静态规则的选择器
+///
+/// 作用域化的静态规则位于 `.slx-xxx { ... }` 包装内，由 lightningcss 按 CSS Nesting 展开，
+/// `&` 在任意位置 (`& > .item`、`.dark &`、`& + &`) 原样保留，指向外层规则。
+fn build_static_selector(ts: &TokenStream) -> Result<String> {
+    process_selector_tokens(ts, &mut |tt, iter, out, space_before| {
+        strip_global_wrapper(tt, iter, out, space_before)
     })
 }
 
@@ -546,7 +831,10 @@ fn extract_dynamic_selector(
     exprs: &mut Vec<(String, TokenStream)>,
     ctx: &DynamicContext,
 ) -> Result<String> {
-    process_tokens(ts, &mut |tt, iter, out, space_before| {
+    process_selector_tokens(ts, &mut |tt, iter, out, space_before| {
+        if strip_global_wrapper(tt, iter, out, space_before)? {
+            return Ok(true);
+        }
         if let TokenTree::Punct(p) = tt {
             if p.as_char() == '$' {
                 if let Some(TokenTree::Group(g)) = iter.peek()
@@ -639,3 +927,193 @@ fn extract_dynamic_value(
         Ok(false)
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn ampersand_is_scoped_in_every_position() {
+        // 从源码字符串解析以保留 token 之间的空白 (quote! 生成的 token 共享同一个 span)
+        let tokens: TokenStream = r#"
+            color: red;
+            & > .item { margin: 0; }
+            .dark & { color: white; }
+            &:hover .icon { opacity: 1; }
+            .list { & + & { padding: 0; } }
+            @media (max-width: 600px) { .dark & > .item { margin: 1px; } }
+        "#
+        .parse()
+        .unwrap();
+        let result = CssCompiler::compile(tokens, Span::call_site(), false).unwrap();
+
+        let class = &result.class_name;
+        assert_eq!(
+            result.component_css,
+            format!(
+                ".{class}{{color:red}}.{class}>.item{{margin:0}}.dark .{class}{{color:#fff}}.{class}:hover .icon{{opacity:1}}.{class} .list+:is(.{class} .list){{padding:0}}@media (max-width:600px){{.dark .{class}>.item{{margin:1px}}}}"
+            )
+        );
+    }
+
+    #[test]
+    fn descendant_spacing_survives_shared_spans() {
+        // quote! 生成的 token 共享同一个 span，与由其他宏生成的 token 相同，无法从源码判断空白
+        let result = CssCompiler::compile(
+            quote! {
+                .dark & { color: white; }
+                &:hover .icon { opacity: 1; }
+                &.active { margin: 0; }
+            },
+            Span::call_site(),
+            false,
+        )
+        .unwrap();
+
+        let class = &result.class_name;
+        assert_eq!(
+            result.component_css,
+            format!(
+                ".dark .{class}{{color:#fff}}.{class}:hover .icon{{opacity:1}}.{class}.active{{margin:0}}"
+            )
+        );
+    }
+
+    #[test]
+    fn identical_css_shares_class_and_style_id() {
+        let compile = |ts: TokenStream| CssCompiler::compile(ts, Span::call_site(), false).unwrap();
+        let a = compile(quote! { color: red; &:hover { margin: 0px; } width: $(width); });
+        let b = compile(quote! { color:red; &:hover{ margin: 0 } width: $(other.width); });
+        let c = compile(quote! { color: blue; });
+
+        // 内容相同：同一个类名与样式 id，运行时注册表按 id 只注入一次
+        assert_eq!(a.class_name, b.class_name);
+        assert_eq!(a.style_id, b.style_id);
+        assert_eq!(a.component_css, b.component_css);
+        assert_ne!(a.class_name, c.class_name);
+
+        let class = &a.class_name;
+        assert!(a.component_css.starts_with(&format!(".{class}{{")));
+        assert!(a.component_css.contains(&format!("var(--{class}-0)")));
+        assert!(!a.component_css.contains(CLASS_PLACEHOLDER));
+    }
+
+    #[test]
+    fn global_rules_are_lifted_unscoped() {
+        let result = CssCompiler::compile(
+            quote! {
+                color: red;
+                :global(.foo) { margin: 0; }
+                .bar { :global(.baz) { padding: 0; } }
+            },
+            Span::call_site(),
+            false,
+        )
+        .unwrap();
+
+        let class = &result.class_name;
+        // 顶层 :global 去掉包装后作为普通规则注入
+        assert_eq!(result.static_css, ".foo{margin:0}");
+        // 兄弟规则与嵌套在其它规则中的 :global 仍然相对于组件类名
+        assert_eq!(
+            result.component_css,
+            format!(".{class}{{color:red}}.{class} .bar .baz{{padding:0}}")
+        );
+        assert!(!result.component_css.contains("global"));
+
+        let dynamic = CssCompiler::compile(
+            quote! { :global(.foo) { color: $(color); } },
+            Span::call_site(),
+            false,
+        );
+        assert!(dynamic.is_err());
+    }
+
+    #[test]
+    fn media_query_is_hoisted_and_scoped_to_class() {
+        let result = CssCompiler::compile(
+            quote! {
+                color: red;
+                @media screen and (max-width: 600px) {
+                    padding: 0;
+                    .child { color: green; }
+                }
+            },
+            Span::call_site(),
+            false,
+        )
+        .unwrap();
+
+        let class = &result.class_name;
+        assert_eq!(
+            result.component_css,
+            format!(
+                ".{class}{{color:red}}@media screen and (max-width:600px){{.{class}{{padding:0}}.{class} .child{{color:green}}}}"
+            )
+        );
+    }
+
+    #[test]
+    fn media_query_wraps_dynamic_selector_rules() {
+        let result = CssCompiler::compile(
+            quote! {
+                @media (max-width: 600px) {
+                    &.$(variant) { color: blue; }
+                }
+            },
+            Span::call_site(),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(result.dynamic_rules.len(), 1);
+        assert_eq!(
+            result.dynamic_rules[0].template,
+            format!(
+                "@media (max-width:600px) {{ .{}.{{}} {{ color: blue;  }} }}",
+                result.class_name
+            )
+        );
+    }
+
+    #[test]
+    fn keyframes_are_scoped_per_component() {
+        let compile = |color: TokenStream| {
+            CssCompiler::compile(
+                quote! {
+                    color: #color;
+                    animation: spin 1s linear infinite;
+                    @keyframes spin {
+                        from { transform: rotate(0deg); }
+                        to { transform: rotate(360deg); }
+                    }
+                },
+                Span::call_site(),
+                false,
+            )
+            .unwrap()
+        };
+
+        let a = compile(quote!(red));
+        let b = compile(quote!(blue));
+        assert_ne!(a.class_name, b.class_name);
+
+        for result in [&a, &b] {
+            let scoped = format!("{}-spin", result.class_name);
+            // Keyframes travel with the component rule instead of the shared static sheet
+            assert!(result.static_css.is_empty());
+            assert!(
+                result
+                    .component_css
+                    .contains(&format!("@keyframes {}{{", scoped))
+            );
+            assert!(
+                result
+                    .component_css
+                    .contains(&format!("animation:1s linear infinite {}}}", scoped))
+            );
+            assert!(!result.component_css.contains("@keyframes spin"));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, LitStr, Result};
+
+/// `#[derive(CssValue)]`：为单字段的单位类型生成 `Display` (`{value}{unit}`)、
+/// 响应式转换与 `CssLength` 标记，使其可用于 `sty()` 构建器、`css!` 插值与 `.style(..)`。
+pub fn derive_css_value_impl(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let mut unit = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("css") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("unit") {
+                    unit = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("Expected `unit = \"..\"`"))
+                }
+            })?;
+        }
+    }
+    let unit = unit.ok_or_else(|| {
+        Error::new_spanned(name, "CssValue requires a unit, e.g. #[css(unit = \"em\")]")
+    })?;
+
+    let is_newtype = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) => fields.unnamed.len() == 1,
+            _ => false,
+        },
+        _ => false,
+    };
+    if !is_newtype {
+        return Err(Error::new_spanned(
+            name,
+            "CssValue only supports tuple structs with a single field, e.g. struct Em(f32);",
+        ));
+    }
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "CssValue does not support generic types",
+        ));
+    }
+
+    Ok(quote! {
+        impl ::std::fmt::Display for #name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}{}", self.0, #unit)
+            }
+        }
+
+        // 长度单位：可参与 calc 运算，并对所有尺寸类属性通过类型校验
+        impl ::silex::css::types::CssLength for #name {}
+        ::silex::css::impl_valid_for_length!(#name);
+
+        impl ::silex::core::traits::RxValue for #name {
+            type Value = Self;
+        }
+
+        impl ::silex::core::traits::IntoRx for #name {
+            type RxType = ::silex::core::Rx<Self, ::silex::core::RxValueKind>;
+            fn into_rx(self) -> Self::RxType {
+                ::silex::core::Rx::new_constant(self)
+            }
+            fn is_constant(&self) -> bool {
+                true
+            }
+        }
+
+        impl ::silex::core::traits::IntoSignal for #name {
+            fn into_signal(self) -> ::silex::core::reactivity::Signal<Self> {
+                ::silex::core::reactivity::Signal::from(self)
+            }
+        }
+
+        // 作为属性值时以字符串形式写入 (如 `.style(..)`、`.attr("width", ..)`)
+        impl ::silex::dom::attribute::IntoStorable for #name {
+            type Stored = ::std::string::String;
+            fn into_storable(self) -> Self::Stored {
+                ::std::string::ToString::to_string(&self)
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn css_value_requires_unit_and_newtype() {
+        let input: DeriveInput = parse_quote! {
+            #[css(unit = "em")]
+            struct MyEm(f32);
+        };
+        let expanded = derive_css_value_impl(input).unwrap().to_string();
+        assert!(expanded.contains("write ! (f , \"{}{}\" , self . 0 , \"em\")"));
+        assert!(expanded.contains(":: silex :: css :: types :: CssLength for MyEm"));
+        // 逐个属性实现 `ValidFor`，不引入 `CssLength` 的 blanket impl
+        assert!(expanded.contains(":: silex :: css :: impl_valid_for_length ! (MyEm)"));
+
+        let missing: DeriveInput = parse_quote! {
+            struct MyEm(f32);
+        };
+        assert!(derive_css_value_impl(missing).is_err());
+
+        let named: DeriveInput = parse_quote! {
+            #[css(unit = "em")]
+            struct MyEm { value: f32 }
+        };
+        assert!(derive_css_value_impl(named).is_err());
+
+        let generic: DeriveInput = parse_quote! {
+            #[css(unit = "em")]
+            struct MyEm<T>(T);
+        };
+        assert!(derive_css_value_impl(generic).is_err());
+    }
+}
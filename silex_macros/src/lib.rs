@@ -83,6 +83,8 @@ pub fn theme(input: TokenStream) -> TokenStream {
 /// - `#[prop(default)]`: 该属性将使用 `Default::default()` 作为默认值
 /// - `#[prop(into)]`: 该属性将使用 `Into<T>` 转换输入
 /// - `#[prop(default, into)]`: 可以组合使用
+/// - `#[prop(optional)]`: 字段存为 `Option<T>`，未设置时为 `None`，构建方法直接接受 `T`，不要求 `T: Default`
+/// - `#[slot]`: 具名插槽，类型须为 `Children`；生成同名构建方法 (如 `.header(view)`)，未设置时为空
 #[cfg(feature = "component")]
 #[proc_macro_attribute]
 pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -100,6 +102,19 @@ pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/// `#[derive(CssValue)]`：将单字段的单位类型 (如 `struct Em(f32)`) 注册为 CSS 长度值
+///
+/// 需要 `#[css(unit = "..")]` 指定单位后缀，`Display` 输出 `{value}{unit}`。
+#[cfg(feature = "css")]
+#[proc_macro_derive(CssValue, attributes(css))]
+pub fn derive_css_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match css::value::derive_css_value_impl(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 #[cfg(feature = "store")]
 #[proc_macro_derive(Store, attributes(store, persist))]
 pub fn derive_store(input: TokenStream) -> TokenStream {
@@ -11,6 +11,8 @@ struct RouteDef {
     fields: Fields,
     path_segments: Vec<Segment>,
     is_wildcard: bool,
+    // 具名通配符 (`*name`) 的参数名，捕获剩余路径
+    wildcard_param: Option<String>,
     // 如果存在嵌套路由字段，存储其成员标识符 (字段名或索引)
     nested_field: Option<Member>,
     view: Option<syn::Path>,
@@ -20,6 +22,8 @@ struct RouteDef {
 enum Segment {
     Static(String),
     Param(String), // name without ':'
+    // 末尾可选参数 `:name?`，字段类型为 `Option<T>`
+    Optional(String),
 }
 
 pub fn derive_route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
@@ -53,10 +57,22 @@ pub fn derive_route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
             ));
         };
 
-        let (segments, is_wildcard) = parse_path_segments(&route_path);
+        let (segments, is_wildcard, wildcard_param) = parse_path_segments(&route_path);
 
         // 检测嵌套字段
-        let nested_field = detect_nested_field(&variant.fields, &segments, route_attr_span)?;
+        let nested_field = detect_nested_field(
+            &variant.fields,
+            &segments,
+            wildcard_param.as_deref(),
+            route_attr_span,
+        )?;
+        validate_optional_segments(&segments, is_wildcard, &nested_field, route_attr_span)?;
+        if wildcard_param.is_some() && nested_field.is_some() {
+            return Err(Error::new(
+                route_attr_span,
+                "A named wildcard (`*name`) cannot be combined with a nested route field.",
+            ));
+        }
 
         route_defs.push(RouteDef {
             variant_ident: variant.ident.clone(),
@@ -64,6 +80,7 @@ pub fn derive_route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
             fields: variant.fields.clone(),
             path_segments: segments,
             is_wildcard,
+            wildcard_param,
             nested_field,
             view: view_component,
             guards,
@@ -152,44 +169,93 @@ fn parse_route_attr(attr: &Attribute) -> syn::Result<(String, Option<syn::Path>,
     })
 }
 
-fn parse_path_segments(path: &str) -> (Vec<Segment>, bool) {
+/// 解析路由路径，返回路径段、是否以通配符结尾以及具名通配符的参数名
+fn parse_path_segments(path: &str) -> (Vec<Segment>, bool, Option<String>) {
     let clean = path.trim_matches('/');
     if clean == "*" {
-        return (Vec::new(), true);
+        return (Vec::new(), true, None);
     }
 
     let mut segments = Vec::new();
     let mut wildcard = false;
+    let mut wildcard_param = None;
 
     for s in clean.split('/') {
         if s.is_empty() {
             continue;
         }
-        if s == "*" {
+        if let Some(name) = s.strip_prefix('*') {
             wildcard = true;
+            if !name.is_empty() {
+                wildcard_param = Some(name.to_string());
+            }
             break;
         }
         if let Some(stripped) = s.strip_prefix(':') {
-            segments.push(Segment::Param(stripped.to_string()));
+            match stripped.strip_suffix('?') {
+                Some(name) => segments.push(Segment::Optional(name.to_string())),
+                None => segments.push(Segment::Param(stripped.to_string())),
+            }
         } else {
             segments.push(Segment::Static(s.to_string()));
         }
     }
 
-    (segments, wildcard)
+    (segments, wildcard, wildcard_param)
+}
+
+/// 可选段只能位于路径末尾，且不能与通配符或嵌套路由同时使用
+fn validate_optional_segments(
+    segments: &[Segment],
+    is_wildcard: bool,
+    nested_field: &Option<Member>,
+    route_attr_span: proc_macro2::Span,
+) -> syn::Result<()> {
+    let Some(first) = segments
+        .iter()
+        .position(|s| matches!(s, Segment::Optional(_)))
+    else {
+        return Ok(());
+    };
+    if segments[first..]
+        .iter()
+        .any(|s| !matches!(s, Segment::Optional(_)))
+    {
+        return Err(Error::new(
+            route_attr_span,
+            "Optional route segments (`:name?`) are only allowed at the end of the path.",
+        ));
+    }
+    if is_wildcard || nested_field.is_some() {
+        return Err(Error::new(
+            route_attr_span,
+            "Optional route segments (`:name?`) cannot be combined with a wildcard or nested route.",
+        ));
+    }
+    Ok(())
+}
+
+/// 必需段的数量 (不含末尾的可选段)
+fn required_len(segments: &[Segment]) -> usize {
+    segments
+        .iter()
+        .take_while(|s| !matches!(s, Segment::Optional(_)))
+        .count()
 }
 
 fn detect_nested_field(
     fields: &Fields,
     segments: &[Segment],
+    wildcard_param: Option<&str>,
     route_attr_span: proc_macro2::Span,
 ) -> syn::Result<Option<Member>> {
     let param_names: Vec<&str> = segments
         .iter()
         .filter_map(|s| match s {
-            Segment::Param(n) => Some(n.as_str()),
+            Segment::Param(n) | Segment::Optional(n) => Some(n.as_str()),
             _ => None,
         })
+        .chain(wildcard_param)
         .collect();
 
     let mut nested = None;
@@ -290,7 +356,8 @@ struct Node {
     param_child: Option<Box<Node>>,
 
     // Indices in `route_defs` that match at this node
-    exact_matches: Vec<usize>,
+    // 精确匹配同时记录已匹配的段数 (末尾可选段可能缺省)
+    exact_matches: Vec<(usize, usize)>,
     wildcard_matches: Vec<usize>,
     nested_matches: Vec<usize>,
 }
@@ -302,6 +369,17 @@ impl Node {
         route_idx: usize,
         is_wildcard: bool,
         is_nested: bool,
+    ) {
+        self.insert_at(segments, 0, route_idx, is_wildcard, is_nested);
+    }
+
+    fn insert_at(
+        &mut self,
+        segments: &[Segment],
+        depth: usize,
+        route_idx: usize,
+        is_wildcard: bool,
+        is_nested: bool,
     ) {
         if segments.is_empty() {
             if is_wildcard {
@@ -309,26 +387,24 @@ impl Node {
             } else if is_nested {
                 self.nested_matches.push(route_idx);
             } else {
-                self.exact_matches.push(route_idx);
+                self.exact_matches.push((route_idx, depth));
             }
             return;
         }
 
         match &segments[0] {
             Segment::Static(s) => {
-                self.static_children.entry(s.clone()).or_default().insert(
-                    &segments[1..],
-                    route_idx,
-                    is_wildcard,
-                    is_nested,
-                );
+                self.static_children
+                    .entry(s.clone())
+                    .or_default()
+                    .insert_at(&segments[1..], depth + 1, route_idx, is_wildcard, is_nested);
             }
-            Segment::Param(_) => {
+            Segment::Param(_) | Segment::Optional(_) => {
                 if self.param_child.is_none() {
                     self.param_child = Some(Box::new(Node::default()));
                 }
                 if let Some(child) = self.param_child.as_mut() {
-                    child.insert(&segments[1..], route_idx, is_wildcard, is_nested);
+                    child.insert_at(&segments[1..], depth + 1, route_idx, is_wildcard, is_nested);
                 }
             }
         }
@@ -339,12 +415,15 @@ fn generate_match_arms(enum_name: &syn::Ident, defs: &[RouteDef]) -> syn::Result
     let mut root = Node::default();
 
     for (i, def) in defs.iter().enumerate() {
-        root.insert(
-            &def.path_segments,
-            i,
-            def.is_wildcard,
-            def.nested_field.is_some(),
-        );
+        // 末尾可选段缺省的每种长度各插入一次
+        for len in required_len(&def.path_segments)..=def.path_segments.len() {
+            root.insert(
+                &def.path_segments[..len],
+                i,
+                def.is_wildcard,
+                def.nested_field.is_some(),
+            );
+        }
     }
 
     let match_logic = generate_node_logic(&root, 0, defs, enum_name)?;
@@ -362,15 +441,23 @@ fn generate_node_logic(
     let check_end_logic = {
         let mut attempts = Vec::new();
         // Exact matches
-        for &idx in &node.exact_matches {
-            attempts.push(generate_route_handler(&defs[idx], enum_name)?);
+        for &(idx, present) in &node.exact_matches {
+            attempts.push(generate_route_handler(&defs[idx], present, enum_name)?);
         }
         // Wildcard / Nested can also match empty remainder
         for &idx in &node.wildcard_matches {
-            attempts.push(generate_route_handler(&defs[idx], enum_name)?);
+            attempts.push(generate_route_handler(
+                &defs[idx],
+                defs[idx].path_segments.len(),
+                enum_name,
+            )?);
         }
         for &idx in &node.nested_matches {
-            attempts.push(generate_route_handler(&defs[idx], enum_name)?);
+            attempts.push(generate_route_handler(
+                &defs[idx],
+                defs[idx].path_segments.len(),
+                enum_name,
+            )?);
         }
 
         quote! {
@@ -422,10 +509,18 @@ fn generate_node_logic(
 
     let mut fallback_attempts = Vec::new();
     for &idx in &node.wildcard_matches {
-        fallback_attempts.push(generate_route_handler(&defs[idx], enum_name)?);
+        fallback_attempts.push(generate_route_handler(
+            &defs[idx],
+            defs[idx].path_segments.len(),
+            enum_name,
+        )?);
     }
     for &idx in &node.nested_matches {
-        fallback_attempts.push(generate_route_handler(&defs[idx], enum_name)?);
+        fallback_attempts.push(generate_route_handler(
+            &defs[idx],
+            defs[idx].path_segments.len(),
+            enum_name,
+        )?);
     }
 
     Ok(quote! {
@@ -441,7 +536,12 @@ fn generate_node_logic(
     })
 }
 
-fn generate_route_handler(def: &RouteDef, enum_name: &syn::Ident) -> syn::Result<TokenStream> {
+/// 生成单条路由的匹配代码，`present` 为路径中实际存在的段数 (末尾可选段可能缺省)
+fn generate_route_handler(
+    def: &RouteDef,
+    present: usize,
+    enum_name: &syn::Ident,
+) -> syn::Result<TokenStream> {
     let variant_ident = &def.variant_ident;
     let expected_len = def.path_segments.len();
 
@@ -450,19 +550,45 @@ fn generate_route_handler(def: &RouteDef, enum_name: &syn::Ident) -> syn::Result
     // Param Parsing
     // We trust the tree structure, so we just grab params by their known indices.
     for (idx, seg) in def.path_segments.iter().enumerate() {
-        if let Segment::Param(name) = seg {
-            let ident = format_ident!("{}", name);
-            let field_ty = find_field_type(&def.fields, name).ok_or_else(|| {
-                Error::new(
-                    def.route_attr_span,
-                    format!("Route param '{}' not found in variant fields", name),
-                )
-            })?;
-
-            param_parsing.push(quote! {
+        let (Segment::Param(name) | Segment::Optional(name)) = seg else {
+            continue;
+        };
+        let ident = format_ident!("{}", name);
+        let field_ty = find_field_type(&def.fields, name).ok_or_else(|| {
+            Error::new(
+                def.route_attr_span,
+                format!("Route param '{}' not found in variant fields", name),
+            )
+        })?;
+
+        param_parsing.push(match seg {
+            // 可选段字段为 `Option<T>`，缺省时为 None
+            Segment::Optional(_) if idx < present => quote! {
+                let #ident: #field_ty = Some(segments[#idx].parse().ok()?);
+            },
+            Segment::Optional(_) => quote! {
+                let #ident: #field_ty = None;
+            },
+            _ => quote! {
                 let #ident = segments[#idx].parse::<#field_ty>().ok()?;
-            });
-        }
+            },
+        });
+    }
+
+    // 具名通配符：剩余路径段以 `/` 连接并 URL 解码
+    if let Some(name) = &def.wildcard_param {
+        let ident = format_ident!("{}", name);
+        let field_ty = find_field_type(&def.fields, name).ok_or_else(|| {
+            Error::new(
+                def.route_attr_span,
+                format!("Route param '{}' not found in variant fields", name),
+            )
+        })?;
+        param_parsing.push(quote! {
+            let #ident = ::silex::router::decode_path(&segments[#expected_len..].join("/"))
+                .parse::<#field_ty>()
+                .ok()?;
+        });
     }
 
     // Construct Variant
@@ -470,11 +596,15 @@ fn generate_route_handler(def: &RouteDef, enum_name: &syn::Ident) -> syn::Result
         Fields::Named(_) => {
             let mut inits = Vec::new();
             for s in &def.path_segments {
-                if let Segment::Param(name) = s {
+                if let Segment::Param(name) | Segment::Optional(name) = s {
                     let ident = format_ident!("{}", name);
                     inits.push(quote! { #ident: #ident });
                 }
             }
+            if let Some(name) = &def.wildcard_param {
+                let ident = format_ident!("{}", name);
+                inits.push(quote! { #ident: #ident });
+            }
             if let Some(Member::Named(nested_name)) = &def.nested_field {
                 inits.push(quote! { #nested_name: sub_route });
             }
@@ -555,16 +685,45 @@ fn generate_to_path_arms(enum_name: &syn::Ident, defs: &[RouteDef]) -> syn::Resu
 
         // 构建当前层的路径格式
         for seg in &def.path_segments {
-            format_string.push('/');
             match seg {
-                Segment::Static(s) => format_string.push_str(s),
+                Segment::Static(s) => {
+                    format_string.push('/');
+                    format_string.push_str(s);
+                }
                 Segment::Param(name) => {
-                    format_string.push_str("{}");
+                    format_string.push_str("/{}");
                     let ident = format_ident!("{}", name);
                     format_args.push(quote! { #ident });
                     field_bindings.push(ident); // 绑定参数字段
                 }
+                Segment::Optional(name) => {
+                    // 值为 None 时省略该段
+                    format_string.push_str("{}");
+                    let ident = format_ident!("{}", name);
+                    format_args.push(quote! {
+                        #ident.as_ref().map(|v| format!("/{}", v)).unwrap_or_default()
+                    });
+                    field_bindings.push(ident);
+                }
+            }
+        }
+
+        // 具名通配符：捕获为空时不追加路径段 (根路径除外)
+        if let Some(name) = &def.wildcard_param {
+            let ident = format_ident!("{}", name);
+            if def.path_segments.is_empty() {
+                format_string.push_str("/{}");
+                format_args.push(quote! { #ident });
+            } else {
+                format_string.push_str("{}");
+                format_args.push(quote! {
+                    {
+                        let rest = #ident.to_string();
+                        if rest.is_empty() { rest } else { format!("/{}", rest) }
+                    }
+                });
             }
+            field_bindings.push(ident);
         }
 
         // 处理嵌套字段的 to_path
@@ -12,11 +12,14 @@ struct PersistFieldConfig {
 pub fn derive_store_impl(input: DeriveInput) -> Result<TokenStream> {
     let name = &input.ident;
     let store_name = format_ident!("{}Store", name);
+    let patch_name = format_ident!("{}Patch", name);
+    let read_view_name = format_ident!("{}ReadView", name);
     let vis = &input.vis;
 
     let mut hook_name: Option<syn::Ident> = None;
     let mut err_msg: Option<String> = None;
     let mut persist_prefix: Option<String> = None;
+    let mut persisted = false;
 
     for attr in &input.attrs {
         if attr.path().is_ident("store") {
@@ -24,7 +27,11 @@ pub fn derive_store_impl(input: DeriveInput) -> Result<TokenStream> {
                 syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
             )?;
             for meta in nested {
-                if let Meta::NameValue(nv) = meta {
+                if let Meta::Path(path) = &meta
+                    && path.is_ident("persisted")
+                {
+                    persisted = true;
+                } else if let Meta::NameValue(nv) = meta {
                     if nv.path.is_ident("name")
                         && let syn::Expr::Lit(syn::ExprLit {
                             lit: syn::Lit::Str(lit_str),
@@ -90,6 +97,13 @@ pub fn derive_store_impl(input: DeriveInput) -> Result<TokenStream> {
         .map(|field| {
             let name = &field.ident;
             let ty = &field.ty;
+            match parse_field_store(field)? {
+                StoreFieldKind::Nested(store_ty) => return Ok(quote! { pub #name: #store_ty }),
+                StoreFieldKind::Keyed(item) => {
+                    return Ok(quote! { pub #name: ::silex::store::KeyedStore<#item> });
+                }
+                StoreFieldKind::Signal => {}
+            }
             match parse_field_persist(field)? {
                 Some(_) => Ok(quote! { pub #name: ::silex::prelude::Persistent<#ty> }),
                 None => Ok(quote! { pub #name: ::silex::prelude::RwSignal<#ty> }),
@@ -102,11 +116,95 @@ pub fn derive_store_impl(input: DeriveInput) -> Result<TokenStream> {
         .map(|field| build_field_initializer(field, persist_prefix.as_deref()))
         .collect::<Result<Vec<_>>>()?;
 
-    let get_fields = fields.iter().map(|f| {
+    // 嵌套 Store 的 `get()` 同样会重建出完整的内部值；KeyedStore 则由 `snapshot()` 收集各行
+    let get_fields = fields
+        .iter()
+        .map(|f| {
+            let name = &f.ident;
+            match parse_field_store(f)? {
+                StoreFieldKind::Keyed(_) => Ok(quote! { #name: self.#name.snapshot() }),
+                _ => Ok(quote! { #name: self.#name.get() }),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // 只读视图：普通字段为 `ReadSignal`，嵌套 Store 与 KeyedStore 递归使用各自的只读视图
+    let read_view_fields = fields
+        .iter()
+        .map(|field| {
+            let name = &field.ident;
+            let ty = &field.ty;
+            match parse_field_store(field)? {
+                StoreFieldKind::Nested(store_ty) => {
+                    let view_ty = read_view_path(&store_ty);
+                    Ok(quote! { pub #name: #view_ty })
+                }
+                StoreFieldKind::Keyed(item) => {
+                    Ok(quote! { pub #name: ::silex::store::ReadKeyedStore<#item> })
+                }
+                StoreFieldKind::Signal => {
+                    Ok(quote! { pub #name: ::silex::prelude::ReadSignal<#ty> })
+                }
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let read_fields = fields
+        .iter()
+        .map(|field| {
+            let name = &field.ident;
+            match parse_field_store(field)? {
+                StoreFieldKind::Nested(_) | StoreFieldKind::Keyed(_) => {
+                    Ok(quote! { #name: self.#name.read() })
+                }
+                StoreFieldKind::Signal if parse_field_persist(field)?.is_some() => {
+                    Ok(quote! { #name: self.#name.signal().read_signal() })
+                }
+                StoreFieldKind::Signal => Ok(quote! { #name: self.#name.read_signal() }),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let set_fields = fields.iter().map(|f| {
         let name = &f.ident;
-        quote! { #name: self.#name.get() }
+        quote! { self.#name.set(value.#name); }
     });
 
+    let patch_fields = fields.iter().map(|f| {
+        let name = &f.ident;
+        let ty = &f.ty;
+        quote! { pub #name: ::std::option::Option<#ty> }
+    });
+
+    let apply_fields = fields.iter().map(|f| {
+        let name = &f.ident;
+        quote! {
+            if let ::std::option::Option::Some(value) = patch.#name {
+                self.#name.set(value);
+            }
+        }
+    });
+
+    let persisted_constructors = if persisted {
+        quote! {
+            /// 从 localStorage 的 `key` 恢复 Store，不存在或损坏时使用 `source`，此后任一字段变化都会写回
+            pub fn persisted(key: &str, source: #name) -> Self {
+                Self::persisted_in(::silex::persist::LocalStorageBackend::new(), key, source)
+            }
+
+            /// 与 `persisted` 相同，但使用指定的存储后端
+            pub fn persisted_in<B: ::silex::persist::PersistenceBackend>(
+                backend: B,
+                key: &str,
+                source: #name,
+            ) -> Self {
+                ::silex::store::persist_store(backend, key, source, Self::new, Self::get)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let panic_msg = err_msg.unwrap_or_else(|| format!("Context for {} not found", store_name));
 
     Ok(quote! {
@@ -116,6 +214,26 @@ pub fn derive_store_impl(input: DeriveInput) -> Result<TokenStream> {
             #(#struct_fields),*
         }
 
+        /// Generated read-only view of the Store, exposing only read handles
+        #[derive(Clone, Copy)]
+        #vis struct #read_view_name {
+            #(#read_view_fields),*
+        }
+
+        impl #read_view_name {
+            pub fn get(&self) -> #name {
+                #name {
+                    #(#get_fields),*
+                }
+            }
+        }
+
+        /// Generated partial update for the Store, only `Some` fields are written
+        #[derive(Default)]
+        #vis struct #patch_name {
+            #(#patch_fields),*
+        }
+
         impl #store_name {
             pub fn new(source: #name) -> Self {
                 Self {
@@ -128,6 +246,48 @@ pub fn derive_store_impl(input: DeriveInput) -> Result<TokenStream> {
                     #(#get_fields),*
                 }
             }
+
+            /// 只读视图，用于传给只展示、不得修改状态的组件
+            pub fn read(&self) -> #read_view_name {
+                #read_view_name {
+                    #(#read_fields),*
+                }
+            }
+
+            /// 在一个 batch 中设置所有字段
+            pub fn set(&self, value: #name) {
+                ::silex::prelude::batch(|| {
+                    #(#set_fields)*
+                });
+            }
+
+            /// 部分更新：在闭包中填写需要修改的字段，见 `apply`
+            pub fn patch(&self, f: impl FnOnce(&mut #patch_name)) {
+                let mut patch = #patch_name::default();
+                f(&mut patch);
+                self.apply(patch);
+            }
+
+            /// 在一个 batch 中写入补丁中为 `Some` 的字段
+            pub fn apply(&self, patch: #patch_name) {
+                ::silex::prelude::batch(|| {
+                    #(#apply_fields)*
+                });
+            }
+
+            #persisted_constructors
+        }
+
+        impl ::silex::store::StoreSnapshot for #store_name {
+            type Value = #name;
+
+            fn snapshot(&self) -> #name {
+                self.get()
+            }
+
+            fn restore(&self, value: #name) {
+                self.set(value)
+            }
         }
 
         impl ::silex::store::Store for #store_name {
@@ -146,6 +306,20 @@ fn build_field_initializer(field: &Field, persist_prefix: Option<&str>) -> Resul
     let name = field.ident.as_ref().expect("named field");
     let ty = &field.ty;
 
+    match parse_field_store(field)? {
+        StoreFieldKind::Nested(store_ty) => {
+            return Ok(quote! {
+                #name: #store_ty::new(source.#name)
+            });
+        }
+        StoreFieldKind::Keyed(_) => {
+            return Ok(quote! {
+                #name: ::silex::store::KeyedStore::new(source.#name)
+            });
+        }
+        StoreFieldKind::Signal => {}
+    }
+
     if let Some(config) = parse_field_persist(field)? {
         let key = config.key.unwrap_or_else(|| name.to_string());
         let full_key = if let Some(prefix) = persist_prefix {
@@ -169,6 +343,115 @@ fn build_field_initializer(field: &Field, persist_prefix: Option<&str>) -> Resul
     }
 }
 
+/// 字段级 `#[store(...)]` 选项
+enum StoreFieldKind {
+    /// 普通字段，映射为 `RwSignal<T>` 或 `Persistent<T>`
+    Signal,
+    /// `#[store(nested)]`：内部类型派生出的 Store (`Address` -> `AddressStore`)
+    Nested(syn::Path),
+    /// `#[store(keyed)]`：`Vec<T>` 字段映射为 `KeyedStore<T>`
+    Keyed(Box<Type>),
+}
+
+fn parse_field_store(field: &Field) -> Result<StoreFieldKind> {
+    let mut nested = false;
+    let mut keyed = false;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("store") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nested") {
+                nested = true;
+                Ok(())
+            } else if meta.path.is_ident("keyed") {
+                keyed = true;
+                Ok(())
+            } else {
+                Err(meta
+                    .error("unsupported #[store(...)] field option, expected `nested` or `keyed`"))
+            }
+        })?;
+    }
+
+    if !nested && !keyed {
+        return Ok(StoreFieldKind::Signal);
+    }
+
+    if nested && keyed {
+        return Err(syn::Error::new_spanned(
+            field,
+            "#[store(nested)] and #[store(keyed)] cannot be combined",
+        ));
+    }
+
+    if field
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("persist"))
+    {
+        return Err(syn::Error::new_spanned(
+            field,
+            "#[store(nested)] and #[store(keyed)] cannot be combined with #[persist(...)]",
+        ));
+    }
+
+    if keyed {
+        return vec_item_type(&field.ty)
+            .map(|item| StoreFieldKind::Keyed(Box::new(item)))
+            .ok_or_else(|| {
+                syn::Error::new_spanned(&field.ty, "#[store(keyed)] fields must be a `Vec<T>`")
+            });
+    }
+
+    match &field.ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let mut path = type_path.path.clone();
+            let last = path.segments.last_mut().expect("non-empty type path");
+            if !last.arguments.is_none() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "#[store(nested)] fields must be a non-generic struct deriving Store",
+                ));
+            }
+            last.ident = format_ident!("{}Store", last.ident);
+            Ok(StoreFieldKind::Nested(path))
+        }
+        ty => Err(syn::Error::new_spanned(
+            ty,
+            "#[store(nested)] fields must be a struct type deriving Store",
+        )),
+    }
+}
+
+/// 嵌套 Store 对应的只读视图类型 (`models::AddressStore` -> `models::AddressReadView`)
+fn read_view_path(store_ty: &syn::Path) -> syn::Path {
+    let mut path = store_ty.clone();
+    let last = path.segments.last_mut().expect("non-empty type path");
+    let name = last.ident.to_string();
+    let base = name.strip_suffix("Store").unwrap_or(&name);
+    last.ident = format_ident!("{}ReadView", base);
+    path
+}
+
+/// `Vec<T>` 中的 `T`
+fn vec_item_type(ty: &Type) -> Option<Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let last = type_path.path.segments.last()?;
+    if last.ident != "Vec" {
+        return None;
+    }
+    match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            syn::GenericArgument::Type(item) => Some(item.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn parse_field_persist(field: &Field) -> Result<Option<PersistFieldConfig>> {
     let mut config = None;
 
@@ -314,4 +597,92 @@ mod tests {
             ":: silex :: prelude :: Persistent :: builder (\"settings-page\") . query () . parse :: < u32 > () . default (source . page) . build ()"
         ));
     }
+
+    #[test]
+    fn store_macro_emits_patch_struct() {
+        let input: DeriveInput = parse_quote! {
+            pub struct User {
+                pub name: String,
+                pub age: u32,
+            }
+        };
+
+        let expanded = derive_store_impl(input).unwrap().to_string();
+
+        assert!(expanded.contains("pub struct UserPatch"));
+        assert!(expanded.contains("pub age : :: std :: option :: Option < u32 >"));
+        assert!(expanded.contains("pub fn apply (& self , patch : UserPatch)"));
+        assert!(expanded.contains(
+            "if let :: std :: option :: Option :: Some (value) = patch . name { self . name . set (value) ; }"
+        ));
+    }
+
+    #[test]
+    fn store_macro_emits_persisted_constructors_on_request() {
+        let plain: DeriveInput = parse_quote! {
+            pub struct Settings { pub theme: String }
+        };
+        assert!(
+            !derive_store_impl(plain)
+                .unwrap()
+                .to_string()
+                .contains("fn persisted")
+        );
+
+        let input: DeriveInput = parse_quote! {
+            #[store(persisted)]
+            pub struct Settings { pub theme: String }
+        };
+        let expanded = derive_store_impl(input).unwrap().to_string();
+        assert!(expanded.contains("pub fn persisted (key : & str , source : Settings) -> Self"));
+        assert!(expanded.contains(
+            ":: silex :: store :: persist_store (backend , key , source , Self :: new , Self :: get)"
+        ));
+    }
+
+    #[test]
+    fn store_macro_uses_inner_store_for_nested_fields() {
+        let input: DeriveInput = parse_quote! {
+            pub struct User {
+                pub name: String,
+                #[store(nested)]
+                pub address: models::Address,
+            }
+        };
+
+        let expanded = derive_store_impl(input).unwrap().to_string();
+
+        assert!(expanded.contains("pub address : models :: AddressStore"));
+        assert!(expanded.contains("address : models :: AddressStore :: new (source . address)"));
+        assert!(expanded.contains("address : self . address . get ()"));
+        assert!(expanded.contains("pub address : models :: AddressReadView"));
+        assert!(expanded.contains("pub name : :: silex :: prelude :: ReadSignal < String >"));
+        assert!(expanded.contains("address : self . address . read ()"));
+    }
+
+    #[test]
+    fn store_macro_emits_keyed_store_for_keyed_vec_fields() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TodoList {
+                #[store(keyed)]
+                pub todos: Vec<Todo>,
+            }
+        };
+
+        let expanded = derive_store_impl(input).unwrap().to_string();
+
+        assert!(expanded.contains("pub todos : :: silex :: store :: KeyedStore < Todo >"));
+        assert!(
+            expanded.contains("todos : :: silex :: store :: KeyedStore :: new (source . todos)")
+        );
+        assert!(expanded.contains("todos : self . todos . snapshot ()"));
+
+        let not_vec: DeriveInput = parse_quote! {
+            pub struct TodoList {
+                #[store(keyed)]
+                pub todos: String,
+            }
+        };
+        assert!(derive_store_impl(not_vec).is_err());
+    }
 }
@@ -12,6 +12,11 @@ use runtime::RUNTIME;
 mod primitive;
 pub use primitive::*;
 
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "serde")]
+pub use serialize::*;
+
 /// 具有 16 字节对齐要求的 64 字节固定宽度缓冲区。
 /// 用于跨 crate 安全地传递和存储类型擦除后的 Payload。
 #[repr(C, align(16))]
@@ -36,6 +41,34 @@ pub fn batch<R>(f: impl FnOnce() -> R) -> R {
     RUNTIME.with(|rt| rt.batch(f))
 }
 
+/// 信号写入后何时运行依赖的 Effect
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Scheduler {
+    /// 每次写入后立即同步运行 (`batch` 内除外)
+    #[default]
+    Sync,
+    /// 写入只将 Effect 入队，并安排一个微任务在本 tick 末尾统一运行，合并同一 tick 内的多次写入。
+    /// 需先通过 [`set_microtask_queue`] 设置微任务队列，否则退化为同步。
+    Microtask,
+}
+
+/// 安排一个微任务 (如浏览器的 `queueMicrotask`)
+pub type MicrotaskQueue = fn(Box<dyn FnOnce()>);
+
+/// 设置调度模式。切换到 [`Scheduler::Sync`] 时立即运行已入队的 Effect。
+pub fn set_scheduler(scheduler: Scheduler) {
+    RUNTIME.with(|rt| rt.set_scheduler(scheduler));
+}
+
+pub fn set_microtask_queue(queue: MicrotaskQueue) {
+    RUNTIME.with(|rt| rt.scheduler.microtask_queue.set(Some(queue)));
+}
+
+/// 立即同步运行所有已入队的 Effect，无需等待微任务。在 `batch` 内调用时无效果，由 `batch` 结束时排空。
+pub fn flush() {
+    RUNTIME.with(|rt| rt.flush());
+}
+
 pub fn create_scope<F>(f: F) -> NodeId
 where
     F: FnOnce(),
@@ -43,6 +76,16 @@ where
     RUNTIME.with(|rt| rt.create_scope(f))
 }
 
+/// 当前的所有者 (作用域、Effect 或 Memo)，在任何作用域之外时为 `None`
+pub fn current_owner() -> Option<NodeId> {
+    RUNTIME.with(|rt| rt.current_owner())
+}
+
+/// 以 `owner` 为当前所有者运行 `f`：其中创建的作用域与 Effect 归属于 `owner`，随它一起销毁
+pub fn with_owner<T>(owner: Option<NodeId>, f: impl FnOnce() -> T) -> T {
+    RUNTIME.with(|rt| rt.with_owner(owner, f))
+}
+
 pub fn dispose(id: NodeId) {
     RUNTIME.with(|rt| rt.dispose(id));
 }
@@ -114,3 +157,33 @@ pub fn get_debug_label(_id: NodeId) -> Option<String> {
         return None;
     }
 }
+
+// --- Effect Timing Diagnostics ---
+
+/// 设置 Effect 计时所用的时钟 (返回毫秒)，未设置时只统计运行次数。仅在 debug 构建中生效。
+pub fn set_effect_clock(_clock: fn() -> f64) {
+    #[cfg(debug_assertions)]
+    RUNTIME.with(|rt| rt.scheduler.effect_clock.set(Some(_clock)));
+}
+
+/// 各 Effect 的累计耗时 (ms) 与运行次数，按累计耗时降序排列。release 构建中始终为空。
+pub fn effect_timings() -> Vec<(NodeId, f64, u32)> {
+    #[cfg(debug_assertions)]
+    {
+        RUNTIME.with(|rt| {
+            let mut timings: Vec<_> = rt
+                .scheduler
+                .effect_timings
+                .borrow()
+                .iter()
+                .map(|(id, (total, runs))| (*id, *total, *runs))
+                .collect();
+            timings.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.2.cmp(&a.2)));
+            timings
+        })
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        Vec::new()
+    }
+}
@@ -1,7 +1,7 @@
 use crate::core::arena::Index as NodeId;
 use crate::core::value::{AnyValue, ThunkValue};
 use crate::runtime::RUNTIME;
-use crate::runtime::storage::ExtraData;
+use crate::runtime::storage::{CallbackFn, ExtraData};
 use std::any::{Any, TypeId};
 
 // --- Context ---
@@ -14,6 +14,10 @@ fn internal_provide_context(key: TypeId, value: Box<dyn Any>) {
     RUNTIME.with(|rt| rt.provide_context(key, value));
 }
 
+/// 从当前所有者向上查找最近提供的 `T`
+///
+/// 上下文以 `TypeId::of::<T>()` 为键存储，查到的值必然是 `T`，不存在“已提供但类型不符”的情况；
+/// 返回 `None` 只表示祖先作用域中没有提供该类型。
 pub fn use_context<T: Clone + 'static>() -> Option<T> {
     internal_use_context(TypeId::of::<T>())?
         .downcast_ref::<T>()
@@ -78,6 +82,8 @@ where
     });
 }
 
+/// 注册派生计算。与 [`memo`] 共用同一条计算路径：追踪依赖，
+/// 在依赖变化前读取 ([`run_derived`]) 直接返回缓存值。
 #[track_caller]
 pub fn register_derived<T: 'static>(f: Box<dyn Fn() -> T>) -> NodeId {
     let id = RUNTIME.with(|rt| rt.register_node());
@@ -85,6 +91,18 @@ pub fn register_derived<T: 'static>(f: Box<dyn Fn() -> T>) -> NodeId {
     id
 }
 
+/// 泛型版本的 [`register_derived`]，同一帧内多次读取只计算一次
+///
+/// 与 [`memo`] 不同，不要求 `T: PartialEq`：依赖变化后总是通知下游。
+#[track_caller]
+pub fn register_cached_derived<T, F>(f: F) -> NodeId
+where
+    T: Clone + 'static,
+    F: Fn() -> T + 'static,
+{
+    register_derived(Box::new(f))
+}
+
 #[inline(never)]
 fn internal_init_derived<T: 'static>(id: NodeId, f: Box<dyn Fn() -> T>) {
     let mut data = [0usize; 3];
@@ -226,6 +244,20 @@ pub fn try_update_signal_silent<T: 'static, R>(
     })
 }
 
+/// 为信号开启历史记录：此后每次修改前保存旧值，可通过 [`try_get_signal_previous`] 读取
+pub fn enable_signal_history<T: Clone + 'static>(id: NodeId) {
+    RUNTIME.with(|rt| {
+        rt.enable_signal_history(id, |value| {
+            value.downcast_ref::<T>().cloned().map(AnyValue::new)
+        })
+    });
+}
+
+/// 读取信号最近一次修改前的值 (不追踪)，未开启历史或尚未修改时返回 `None`
+pub fn try_get_signal_previous<T: Clone + 'static>(id: NodeId) -> Option<T> {
+    RUNTIME.with(|rt| rt.get_signal_previous(id)?.downcast_ref::<T>().cloned())
+}
+
 // --- Storage ---
 
 #[track_caller]
@@ -315,22 +347,43 @@ pub fn is_op_valid(id: NodeId) -> bool {
 pub fn register_callback<F>(f: F) -> NodeId
 where
     F: Fn(Box<dyn Any>) + 'static,
+{
+    internal_register_callback(std::rc::Rc::new(move |arg| {
+        f(arg);
+        Box::new(()) as Box<dyn Any>
+    }))
+}
+
+/// 注册带返回值的回调，返回值可通过 [`try_invoke_callback`] 取得
+#[track_caller]
+pub fn register_callback_with_result<F>(f: F) -> NodeId
+where
+    F: Fn(Box<dyn Any>) -> Box<dyn Any> + 'static,
 {
     internal_register_callback(std::rc::Rc::new(f))
 }
 
-fn internal_register_callback(f: std::rc::Rc<dyn Fn(Box<dyn Any>)>) -> NodeId {
+fn internal_register_callback(f: CallbackFn) -> NodeId {
     RUNTIME.with(|rt| rt.register_callback_untyped(f))
 }
 
 pub fn invoke_callback(id: NodeId, arg: Box<dyn Any>) {
-    RUNTIME.with(|rt| {
+    try_invoke_callback(id, arg);
+}
+
+/// 调用回调并返回其结果，回调已被销毁时返回 `None`
+pub fn try_invoke_callback(id: NodeId, arg: Box<dyn Any>) -> Option<Box<dyn Any>> {
+    let f = RUNTIME.with(|rt| {
         if let Some(extra) = rt.storage.extras.get(id)
             && let ExtraData::Callback(data) = extra
         {
-            (data.f)(arg);
+            Some(data.f.clone())
+        } else {
+            None
         }
-    })
+    })?;
+    // 在释放运行时借用后调用，回调内部可以自由访问运行时
+    Some(f(arg))
 }
 
 pub fn is_callback_valid(id: NodeId) -> bool {
@@ -365,12 +418,30 @@ pub fn get_node_ref<T: Clone + 'static>(id: NodeId) -> Option<T> {
     })
 }
 
-pub fn set_node_ref<T: 'static>(id: NodeId, element: T) {
+pub fn set_node_ref<T: Clone + 'static>(id: NodeId, element: T) {
+    let callbacks = RUNTIME.with(|rt| {
+        if let Some(extra) = rt.storage.extras.get_mut(id)
+            && let ExtraData::NodeRef(data) = extra
+        {
+            data.element = Some(Box::new(element.clone()));
+            std::mem::take(&mut data.on_load)
+        } else {
+            Vec::new()
+        }
+    });
+    // 在释放运行时借用后执行，回调中可再次访问该 NodeRef
+    for callback in callbacks {
+        callback(&element);
+    }
+}
+
+/// 注册在下一次 `set_node_ref` 时执行一次的回调
+pub fn on_node_ref_load(id: NodeId, f: impl FnOnce(&dyn Any) + 'static) {
     RUNTIME.with(|rt| {
         if let Some(extra) = rt.storage.extras.get_mut(id)
             && let ExtraData::NodeRef(data) = extra
         {
-            data.element = Some(Box::new(element));
+            data.on_load.push(Box::new(f));
         }
     })
 }
@@ -190,11 +190,45 @@ impl Runtime {
 
     pub(crate) fn notify_update(&self, id: NodeId) {
         self.queue_dependents(id);
+        if self.scheduler.batch_depth.get() == 0 {
+            match self.scheduler.mode.get() {
+                crate::Scheduler::Sync => self.run_queue(),
+                crate::Scheduler::Microtask => self.schedule_flush(),
+            }
+        }
+    }
+
+    /// 微任务模式：每个 tick 只安排一次排空。正在排空时由当前循环处理新入队的 Effect；
+    /// 未设置微任务队列时退化为同步排空。
+    fn schedule_flush(&self) {
+        if self.scheduler.running_queue.get() || self.scheduler.flush_scheduled.get() {
+            return;
+        }
+        match self.scheduler.microtask_queue.get() {
+            Some(queue) => {
+                self.scheduler.flush_scheduled.set(true);
+                queue(Box::new(|| RUNTIME.with(|rt| rt.flush())));
+            }
+            None => self.run_queue(),
+        }
+    }
+
+    /// 立即排空队列，已安排的微任务随后执行时不再有工作
+    pub fn flush(&self) {
+        self.scheduler.flush_scheduled.set(false);
         if self.scheduler.batch_depth.get() == 0 {
             self.run_queue();
         }
     }
 
+    pub fn set_scheduler(&self, mode: crate::Scheduler) {
+        self.scheduler.mode.set(mode);
+        // 切回同步模式时不遗留待排空的更新
+        if mode == crate::Scheduler::Sync {
+            self.flush();
+        }
+    }
+
     pub(crate) fn prepare_read(&self, id: NodeId) {
         self.track_dependency(id);
         self.update_if_necessary(id);
@@ -210,6 +244,7 @@ impl Runtime {
             && let Some(signal) = &mut n.signal
         {
             signal.version = signal.version.wrapping_add(1);
+            self.record_previous(id, &signal.value);
             updater(&mut signal.value);
             self.notify_update(id);
         }
@@ -239,9 +274,40 @@ impl Runtime {
         let n = self.storage.reactive.get_mut(id)?;
         let signal = n.signal.as_mut()?;
         signal.version = signal.version.wrapping_add(1);
+        self.record_previous(id, &signal.value);
         Some(&mut signal.value)
     }
 
+    pub(crate) fn enable_signal_history(
+        &self,
+        id: NodeId,
+        snapshot: fn(&AnyValue) -> Option<AnyValue>,
+    ) {
+        if self.storage.extras.get(id).is_none() {
+            self.storage.extras.insert(
+                id,
+                ExtraData::History(HistoryData {
+                    previous: None,
+                    snapshot,
+                }),
+            );
+        }
+    }
+
+    /// 若信号开启了历史记录，在修改前保存当前值
+    fn record_previous(&self, id: NodeId, current: &AnyValue) {
+        if let Some(ExtraData::History(history)) = self.storage.extras.get_mut(id) {
+            history.previous = (history.snapshot)(current);
+        }
+    }
+
+    pub(crate) fn get_signal_previous(&self, id: NodeId) -> Option<&AnyValue> {
+        match self.storage.extras.get(id)? {
+            ExtraData::History(history) => history.previous.as_ref(),
+            _ => None,
+        }
+    }
+
     pub(crate) fn prepare_memo_node(&self, id: NodeId) {
         self.storage.reactive.insert(
             id,
@@ -368,10 +434,7 @@ impl Runtime {
         }
     }
 
-    pub fn register_callback_untyped(
-        &self,
-        f: std::rc::Rc<dyn Fn(Box<dyn std::any::Any>)>,
-    ) -> NodeId {
+    pub fn register_callback_untyped(&self, f: CallbackFn) -> NodeId {
         let id = self.register_node();
         self.storage
             .extras
@@ -381,9 +444,13 @@ impl Runtime {
 
     pub fn register_node_ref(&self) -> NodeId {
         let id = self.register_node();
-        self.storage
-            .extras
-            .insert(id, ExtraData::NodeRef(NodeRefData { element: None }));
+        self.storage.extras.insert(
+            id,
+            ExtraData::NodeRef(NodeRefData {
+                element: None,
+                on_load: Vec::new(),
+            }),
+        );
         id
     }
 
@@ -473,7 +540,11 @@ impl Runtime {
         if let Some(f) = computation_fn {
             let prev_owner = self.current_owner();
             self.set_owner(Some(effect_id));
+            #[cfg(debug_assertions)]
+            let started = self.scheduler.effect_clock.get().map(|now| now());
             unsafe { f.call(self as *const Runtime as *const ()) };
+            #[cfg(debug_assertions)]
+            self.record_effect_timing(effect_id, started);
             self.set_owner(prev_owner);
 
             if let Some(n) = self.storage.reactive.get_mut(effect_id)
@@ -485,6 +556,29 @@ impl Runtime {
     }
 }
 
+impl Runtime {
+    #[cfg(debug_assertions)]
+    fn record_effect_timing(&self, effect_id: NodeId, started: Option<f64>) {
+        // Memo 同样经由此路径重新计算，只统计 Effect
+        if self
+            .storage
+            .reactive
+            .get(effect_id)
+            .is_some_and(|n| n.signal.is_some())
+        {
+            return;
+        }
+        let elapsed = match (started, self.scheduler.effect_clock.get()) {
+            (Some(started), Some(now)) => now() - started,
+            _ => 0.0,
+        };
+        let mut timings = self.scheduler.effect_timings.borrow_mut();
+        let entry = timings.entry(effect_id).or_insert((0.0, 0));
+        entry.0 += elapsed;
+        entry.1 += 1;
+    }
+}
+
 impl Runtime {
     #[inline(never)]
     pub(crate) fn update_memo_core(
@@ -574,7 +668,11 @@ impl GraphExecutor for Runtime {
         if let Some(f) = computation_fn {
             let prev_owner = self.current_owner();
             self.set_owner(Some(id));
+            #[cfg(debug_assertions)]
+            let started = self.scheduler.effect_clock.get().map(|now| now());
             unsafe { f.call(self as *const Runtime as *const ()) };
+            #[cfg(debug_assertions)]
+            self.record_effect_timing(id, started);
             self.set_owner(prev_owner);
 
             if let Some(n) = self.storage.reactive.get_mut(id) {
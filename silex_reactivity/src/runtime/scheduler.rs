@@ -1,5 +1,7 @@
 use crate::core::arena::{Index as NodeId, SparseSecondaryMap};
 use std::cell::{Cell, RefCell};
+#[cfg(debug_assertions)]
+use std::collections::HashMap;
 use std::collections::VecDeque;
 
 pub(crate) struct Scheduler {
@@ -8,6 +10,15 @@ pub(crate) struct Scheduler {
     pub(crate) queued_observers: SparseSecondaryMap<()>,
     pub(crate) running_queue: Cell<bool>,
     pub(crate) batch_depth: Cell<usize>,
+    pub(crate) mode: Cell<crate::Scheduler>,
+    /// 微任务模式下是否已安排排空队列的微任务
+    pub(crate) flush_scheduled: Cell<bool>,
+    pub(crate) microtask_queue: Cell<Option<crate::MicrotaskQueue>>,
+    /// 每个 Effect 的累计耗时 (ms) 与运行次数
+    #[cfg(debug_assertions)]
+    pub(crate) effect_timings: RefCell<HashMap<NodeId, (f64, u32)>>,
+    #[cfg(debug_assertions)]
+    pub(crate) effect_clock: Cell<Option<fn() -> f64>>,
 }
 
 impl Scheduler {
@@ -18,6 +29,13 @@ impl Scheduler {
             queued_observers: SparseSecondaryMap::new(),
             running_queue: Cell::new(false),
             batch_depth: Cell::new(0),
+            mode: Cell::new(crate::Scheduler::Sync),
+            flush_scheduled: Cell::new(false),
+            microtask_queue: Cell::new(None),
+            #[cfg(debug_assertions)]
+            effect_timings: RefCell::new(HashMap::new()),
+            #[cfg(debug_assertions)]
+            effect_clock: Cell::new(None),
         }
     }
 }
@@ -34,6 +34,14 @@ impl Runtime {
         t
     }
 
+    pub fn with_owner<T>(&self, owner: Option<NodeId>, f: impl FnOnce() -> T) -> T {
+        let prev_owner = self.current_owner();
+        self.set_owner(owner);
+        let t = f();
+        self.set_owner(prev_owner);
+        t
+    }
+
     pub fn create_scope<F>(&self, f: F) -> NodeId
     where
         F: FnOnce(),
@@ -162,6 +170,10 @@ impl Runtime {
         self.storage.node_aux.remove(id);
         self.storage.reactive.remove(id);
         self.storage.extras.remove(id);
+        #[cfg(feature = "serde")]
+        self.storage.serializers.remove(id);
         self.scheduler.queued_observers.remove(id);
+        #[cfg(debug_assertions)]
+        self.scheduler.effect_timings.borrow_mut().remove(&id);
     }
 }
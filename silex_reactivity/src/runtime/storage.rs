@@ -18,6 +18,7 @@ pub(crate) enum ExtraData {
     StoredValue(StoredValueData),
     Closure(ClosureData),
     Op(OpData),
+    History(HistoryData),
 }
 
 pub(crate) struct Storage {
@@ -25,6 +26,8 @@ pub(crate) struct Storage {
     pub(crate) node_aux: SparseSecondaryMap<NodeAux, 32>,
     pub(crate) reactive: SparseSecondaryMap<ReactiveNode, 64>,
     pub(crate) extras: SparseSecondaryMap<ExtraData, 32>,
+    #[cfg(feature = "serde")]
+    pub(crate) serializers: SparseSecondaryMap<SignalSerde>,
 
     #[cfg(debug_assertions)]
     pub(crate) dead_node_labels: SparseSecondaryMap<String>,
@@ -37,6 +40,8 @@ impl Storage {
             node_aux: SparseSecondaryMap::new(),
             reactive: SparseSecondaryMap::new(),
             extras: SparseSecondaryMap::new(),
+            #[cfg(feature = "serde")]
+            serializers: SparseSecondaryMap::new(),
             #[cfg(debug_assertions)]
             dead_node_labels: SparseSecondaryMap::new(),
         }
@@ -217,12 +222,32 @@ pub(crate) struct EffectData {
     pub(crate) effect_version: u32,
 }
 
+pub(crate) type CallbackFn = Rc<dyn Fn(Box<dyn Any>) -> Box<dyn Any>>;
+
 pub(crate) struct CallbackData {
-    pub(crate) f: Rc<dyn Fn(Box<dyn Any>)>,
+    pub(crate) f: CallbackFn,
 }
 
+pub(crate) type NodeRefCallback = Box<dyn FnOnce(&dyn Any)>;
+
 pub(crate) struct NodeRefData {
     pub(crate) element: Option<Box<dyn Any>>,
+    /// 等待元素加载的一次性回调
+    pub(crate) on_load: Vec<NodeRefCallback>,
+}
+
+/// 开启历史记录的信号在每次修改前保存的旧值
+pub(crate) struct HistoryData {
+    pub(crate) previous: Option<AnyValue>,
+    /// 按具体类型克隆当前值
+    pub(crate) snapshot: fn(&AnyValue) -> Option<AnyValue>,
+}
+
+/// 可序列化信号在创建时登记的类型擦除序列化函数
+#[cfg(feature = "serde")]
+pub(crate) struct SignalSerde {
+    pub(crate) serialize: fn(&AnyValue) -> Option<serde_json::Value>,
+    pub(crate) deserialize: fn(serde_json::Value) -> Option<AnyValue>,
 }
 
 pub(crate) struct StoredValueData {
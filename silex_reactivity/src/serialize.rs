@@ -0,0 +1,95 @@
+//! 信号值的序列化与注水 (SSR / 状态传递)。
+//!
+//! 只有通过 [`signal_serializable`] 创建的信号会登记序列化函数。
+//! [`serialize_scope`] 按创建顺序 (深度优先) 收集作用域内这些信号的值，
+//! 客户端以相同的顺序重建组件树后，[`hydrate_scope`] 按位置恢复。
+
+use crate::core::arena::Index as NodeId;
+use crate::core::value::AnyValue;
+use crate::runtime::RUNTIME;
+use crate::runtime::storage::SignalSerde;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// 创建一个可被 [`serialize_scope`] 收集的信号
+#[track_caller]
+pub fn signal_serializable<T>(value: T) -> NodeId
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let id = crate::signal(value);
+    RUNTIME.with(|rt| {
+        rt.storage.serializers.insert(
+            id,
+            SignalSerde {
+                serialize: |value| serde_json::to_value(value.downcast_ref::<T>()?).ok(),
+                deserialize: |json| serde_json::from_value::<T>(json).ok().map(AnyValue::new),
+            },
+        );
+    });
+    id
+}
+
+/// `root` 子树中所有可序列化信号，按创建顺序排列
+fn collect_serializable(root: NodeId) -> Vec<NodeId> {
+    RUNTIME.with(|rt| {
+        let mut ids = Vec::new();
+        let mut stack = vec![root];
+        while let Some(id) = stack.pop() {
+            if rt.storage.serializers.get(id).is_some() {
+                ids.push(id);
+            }
+            if let Some(aux) = rt.storage.node_aux.get(id) {
+                stack.extend(aux.children.iter().rev().copied());
+            }
+        }
+        ids
+    })
+}
+
+/// 将 `root` 子树中可序列化信号的当前值导出为 JSON 数组
+pub fn serialize_scope(root: NodeId) -> serde_json::Value {
+    let values = collect_serializable(root)
+        .into_iter()
+        .map(|id| {
+            RUNTIME
+                .with(|rt| {
+                    let serde = rt.storage.serializers.get(id)?;
+                    let signal = rt.storage.reactive.get(id)?.signal.as_ref()?;
+                    (serde.serialize)(&signal.value)
+                })
+                .unwrap_or(serde_json::Value::Null)
+        })
+        .collect();
+    serde_json::Value::Array(values)
+}
+
+/// 以 [`serialize_scope`] 的输出按位置恢复 `root` 子树中的信号，并通知订阅者
+///
+/// 数量不一致时只恢复重叠的部分；无法反序列化的条目保持原值。
+pub fn hydrate_scope(root: NodeId, value: &serde_json::Value) {
+    let Some(values) = value.as_array() else {
+        return;
+    };
+    let ids = collect_serializable(root);
+    crate::batch(|| {
+        for (id, json) in ids.into_iter().zip(values) {
+            RUNTIME.with(|rt| {
+                let Some(new_value) = rt
+                    .storage
+                    .serializers
+                    .get(id)
+                    .and_then(|serde| (serde.deserialize)(json.clone()))
+                else {
+                    return;
+                };
+                let mut new_value = Some(new_value);
+                rt.update_signal_untyped(id, &mut |value| {
+                    if let Some(new_value) = new_value.take() {
+                        *value = new_value;
+                    }
+                });
+            });
+        }
+    });
+}
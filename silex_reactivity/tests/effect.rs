@@ -1,5 +1,5 @@
 use silex_reactivity::*;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
 #[test]
@@ -112,3 +112,89 @@ fn test_batch() {
 
     assert_eq!(count.get(), 2);
 }
+
+#[test]
+fn test_batch_inside_effect_defers_to_running_queue() {
+    let a = signal(0);
+    let b = signal(0);
+    let in_first = Rc::new(Cell::new(false));
+    let runs = Rc::new(Cell::new(0));
+
+    effect({
+        let in_first = in_first.clone();
+        move || {
+            let v = try_get_signal::<i32>(a).unwrap();
+            in_first.set(true);
+            // 嵌套 batch：不能在此处排空队列
+            batch(|| {
+                batch(|| update_signal(b, |x: &mut i32| *x = v));
+                update_signal(b, |x: &mut i32| *x = v * 10);
+            });
+            in_first.set(false);
+        }
+    });
+    effect({
+        let in_first = in_first.clone();
+        let runs = runs.clone();
+        move || {
+            let _ = try_get_signal::<i32>(b);
+            assert!(!in_first.get(), "dependent effect ran re-entrantly");
+            runs.set(runs.get() + 1);
+        }
+    });
+    assert_eq!(runs.get(), 1);
+
+    update_signal(a, |x: &mut i32| *x = 1);
+    assert_eq!(runs.get(), 2);
+    assert_eq!(try_get_signal::<i32>(b), Some(10));
+}
+
+thread_local! {
+    static MICROTASKS: RefCell<Vec<Box<dyn FnOnce()>>> = Default::default();
+}
+
+fn run_microtasks() {
+    let tasks = MICROTASKS.with(|tasks| std::mem::take(&mut *tasks.borrow_mut()));
+    for task in tasks {
+        task();
+    }
+}
+
+#[test]
+fn test_microtask_scheduler_coalesces_writes() {
+    set_microtask_queue(|task| MICROTASKS.with(|tasks| tasks.borrow_mut().push(task)));
+    set_scheduler(Scheduler::Microtask);
+
+    let s = signal(0);
+    let runs = Rc::new(Cell::new(0));
+    effect({
+        let runs = runs.clone();
+        move || {
+            let _ = try_get_signal::<i32>(s);
+            runs.set(runs.get() + 1);
+        }
+    });
+    assert_eq!(runs.get(), 1);
+
+    update_signal(s, |v: &mut i32| *v = 1);
+    update_signal(s, |v: &mut i32| *v = 2);
+    update_signal(s, |v: &mut i32| *v = 3);
+    assert_eq!(runs.get(), 1, "effects deferred to the microtask");
+    assert_eq!(MICROTASKS.with(|tasks| tasks.borrow().len()), 1);
+
+    run_microtasks();
+    assert_eq!(runs.get(), 2);
+
+    // 显式 batch 与 flush 仍同步排空
+    batch(|| update_signal(s, |v: &mut i32| *v = 4));
+    assert_eq!(runs.get(), 3);
+    update_signal(s, |v: &mut i32| *v = 5);
+    flush();
+    assert_eq!(runs.get(), 4);
+    run_microtasks();
+    assert_eq!(runs.get(), 4);
+
+    set_scheduler(Scheduler::Sync);
+    update_signal(s, |v: &mut i32| *v = 6);
+    assert_eq!(runs.get(), 5);
+}
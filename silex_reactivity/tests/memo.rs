@@ -1,5 +1,5 @@
 use silex_reactivity::*;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
 #[test]
@@ -62,3 +62,52 @@ fn test_derived_signal() {
     update_signal(s, |v: &mut i32| *v = 2);
     assert_eq!(run_derived::<i32>(d), Some(6));
 }
+
+#[test]
+fn test_cached_derived_runs_once_per_change() {
+    let s = signal(1);
+    let runs = std::rc::Rc::new(std::cell::Cell::new(0));
+    let d = register_cached_derived({
+        let runs = runs.clone();
+        move || {
+            runs.set(runs.get() + 1);
+            try_get_signal::<i32>(s).unwrap_or(0) * 3
+        }
+    });
+
+    assert_eq!(run_derived::<i32>(d), Some(3));
+    assert_eq!(run_derived::<i32>(d), Some(3));
+    assert_eq!(runs.get(), 1);
+
+    update_signal(s, |v: &mut i32| *v = 2);
+    assert_eq!(run_derived::<i32>(d), Some(6));
+    assert_eq!(run_derived::<i32>(d), Some(6));
+    assert_eq!(runs.get(), 2);
+}
+
+#[test]
+fn test_diamond_effect_sees_updated_memo() {
+    let a = signal(1);
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let b = memo({
+        let log = log.clone();
+        move |_| {
+            let v = try_get_signal::<i32>(a).unwrap_or(0) * 10;
+            log.borrow_mut().push(format!("memo {v}"));
+            v
+        }
+    });
+    // Effect 先于 Memo 出队，但读取 b 时会惰性拉取重算，不会看到旧值
+    effect({
+        let log = log.clone();
+        move || {
+            let a = try_get_signal::<i32>(a).unwrap_or(0);
+            let b = try_get_signal::<i32>(b).unwrap_or(0);
+            log.borrow_mut().push(format!("effect {a} {b}"));
+        }
+    });
+    log.borrow_mut().clear();
+
+    update_signal(a, |v: &mut i32| *v = 2);
+    assert_eq!(*log.borrow(), vec!["memo 20", "effect 2 20"]);
+}
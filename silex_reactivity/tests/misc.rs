@@ -45,3 +45,26 @@ fn test_node_ref() {
     set_node_ref(nr, 42i32);
     assert_eq!(get_node_ref::<i32>(nr), Some(42));
 }
+
+#[test]
+fn test_node_ref_on_load() {
+    let nr = register_node_ref();
+    let loaded = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    for tag in ["a", "b"] {
+        let loaded = loaded.clone();
+        on_node_ref_load(nr, move |el: &dyn Any| {
+            // 回调执行时节点已可读取
+            assert_eq!(get_node_ref::<i32>(nr), Some(7));
+            loaded
+                .borrow_mut()
+                .push((tag, *el.downcast_ref::<i32>().unwrap()));
+        });
+    }
+
+    set_node_ref(nr, 7i32);
+    assert_eq!(*loaded.borrow(), vec![("a", 7), ("b", 7)]);
+
+    // 回调只执行一次
+    set_node_ref(nr, 8i32);
+    assert_eq!(loaded.borrow().len(), 2);
+}
@@ -86,3 +86,31 @@ fn test_on_cleanup_recursive() {
     let result = cleanup_order.borrow().clone();
     assert_eq!(result, vec![2, 3, 1]);
 }
+
+#[test]
+fn test_with_owner_attaches_scope_to_given_owner() {
+    let s = signal(1);
+    let runs = Rc::new(Cell::new(0));
+    let owner = create_scope(|| {});
+
+    // 在其他作用域中以 owner 为所有者创建子作用域
+    let other = create_scope(|| {
+        let runs = runs.clone();
+        with_owner(Some(owner), move || {
+            create_scope(move || {
+                effect(move || {
+                    let _ = try_get_signal::<i32>(s);
+                    runs.set(runs.get() + 1);
+                });
+            });
+        });
+    });
+
+    dispose(other);
+    update_signal(s, |v: &mut i32| *v = 2);
+    assert_eq!(runs.get(), 2, "child survives the caller's scope");
+
+    dispose(owner);
+    update_signal(s, |v: &mut i32| *v = 3);
+    assert_eq!(runs.get(), 2);
+}
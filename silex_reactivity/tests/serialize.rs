@@ -0,0 +1,52 @@
+#![cfg(feature = "serde")]
+
+use silex_reactivity::*;
+
+#[test]
+fn test_serialize_and_hydrate_scope() {
+    let mut server = Vec::new();
+    let root = create_scope(|| {
+        server.push(signal_serializable(42i32));
+        // 未登记序列化的信号不参与
+        signal(String::from("skipped"));
+        create_scope(|| {
+            server.push(signal_serializable(String::from("hello")));
+            server.push(signal_serializable(true));
+        });
+    });
+    update_signal(server[0], |v: &mut i32| *v = 7);
+
+    let snapshot = serialize_scope(root);
+    assert_eq!(snapshot, serde_json::json!([7, "hello", true]));
+
+    // 客户端以相同顺序创建信号，再按位置恢复
+    let mut client = Vec::new();
+    let client_root = create_scope(|| {
+        client.push(signal_serializable(0i32));
+        signal(String::new());
+        create_scope(|| {
+            client.push(signal_serializable(String::new()));
+            client.push(signal_serializable(false));
+        });
+    });
+
+    let runs = std::rc::Rc::new(std::cell::Cell::new(0));
+    let count = client[0];
+    effect({
+        let runs = runs.clone();
+        move || {
+            let _ = try_get_signal::<i32>(count);
+            runs.set(runs.get() + 1);
+        }
+    });
+
+    hydrate_scope(client_root, &snapshot);
+    assert_eq!(try_get_signal::<i32>(client[0]), Some(7));
+    assert_eq!(
+        try_get_signal::<String>(client[1]).as_deref(),
+        Some("hello")
+    );
+    assert_eq!(try_get_signal::<bool>(client[2]), Some(true));
+    assert_eq!(runs.get(), 2, "subscribers are notified");
+    assert_eq!(serialize_scope(client_root), snapshot);
+}
@@ -42,3 +42,21 @@ fn test_update_signal_silent() {
     assert_eq!(updated, Some(40));
     assert_eq!(try_get_signal_untracked::<i32>(s), Some(20));
 }
+
+#[test]
+fn test_stale_id_after_slot_reuse() {
+    let old = signal(1);
+    dispose(old);
+
+    let new = signal(2);
+    assert_eq!(new.index, old.index, "slot is reused");
+    assert_ne!(new.generation, old.generation);
+
+    // 旧 id 不能读写复用槽位的新信号
+    assert!(!is_signal_valid(old));
+    assert_eq!(try_get_signal_untracked::<i32>(old), None);
+    assert_eq!(try_with_signal(old, |v: &i32| *v), None);
+    assert_eq!(try_update_signal_silent(old, |v: &mut i32| *v = 3), None);
+    update_signal(old, |v: &mut i32| *v = 4);
+    assert_eq!(try_get_signal_untracked::<i32>(new), Some(2));
+}
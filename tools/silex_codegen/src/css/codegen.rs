@@ -6,8 +6,9 @@ pub fn generate_properties_macro(props: &[ProcessedProp]) -> String {
     code.push_str("/// 自动生成的 CSS 属性注册表\n");
     code.push_str("#[macro_export]\n");
     code.push_str("macro_rules! for_all_properties {\n");
-    code.push_str("    ($callback:ident) => {\n");
+    code.push_str("    ($callback:path $(, $arg:tt)*) => {\n");
     code.push_str("        $callback! {\n");
+    code.push_str("            $($arg,)*\n");
 
     let items: Vec<String> = props
         .iter()
@@ -73,8 +74,9 @@ pub fn generate_keywords_code(props: &[ProcessedProp]) -> String {
 
     // Generate a helper macro to implement traits for all keywords
     code.push_str("macro_rules! register_generated_keywords {\n");
-    code.push_str("    ($callback:ident) => {\n");
+    code.push_str("    ($callback:path $(, $arg:tt)*) => {\n");
     code.push_str("        $callback! {\n");
+    code.push_str("            $($arg,)*\n");
 
     // Ensure deterministic order for keyword list
     keyword_types.sort();
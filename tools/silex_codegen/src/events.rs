@@ -0,0 +1,149 @@
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// 扫描这些接口上的 `*_event` 条目，覆盖元素、表单、媒体、文档与窗口事件
+const EVENT_INTERFACES: &[&str] = &[
+    "Element",
+    "HTMLElement",
+    "HTMLInputElement",
+    "HTMLFormElement",
+    "HTMLSelectElement",
+    "HTMLTextAreaElement",
+    "HTMLMediaElement",
+    "HTMLDialogElement",
+    "HTMLDetailsElement",
+    "Document",
+    "Window",
+];
+
+/// 已废弃或非标准、但此前已提供的事件，保留以免破坏现有代码
+const LEGACY_EVENTS: &[&str] = &["dragexit", "keypress"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventDef {
+    pub name: String,
+    pub event_type: &'static str,
+}
+
+/// 从 MDN browser-compat-data 收集标准 DOM 事件
+///
+/// 只保留标准、未废弃且非实验性的事件，名称需为纯小写字母 (排除 `DOMContentLoaded`、`webkit*` 等)。
+pub fn parse_events(compat_data_str: &str) -> Result<Vec<EventDef>, Box<dyn std::error::Error>> {
+    let data: Value = serde_json::from_str(compat_data_str)?;
+    let mut names: BTreeSet<String> = LEGACY_EVENTS.iter().map(|s| s.to_string()).collect();
+
+    for interface in EVENT_INTERFACES {
+        let Some(members) = data["api"][interface].as_object() else {
+            continue;
+        };
+        for (key, member) in members {
+            let Some(name) = key.strip_suffix("_event") else {
+                continue;
+            };
+            let status = &member["__compat"]["status"];
+            let stable = status["standard_track"].as_bool() == Some(true)
+                && status["deprecated"].as_bool() != Some(true)
+                && status["experimental"].as_bool() != Some(true);
+            if stable && !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase()) {
+                names.insert(name.to_string());
+            }
+        }
+    }
+
+    Ok(names
+        .into_iter()
+        .map(|name| EventDef {
+            event_type: event_type(&name),
+            name,
+        })
+        .collect())
+}
+
+/// 事件对应的 `web_sys` 类型，未知事件使用基础的 `Event`
+///
+/// 仅使用工作区已开启 feature 的类型；`change`、`load`、`scroll` 等本就派发基础 `Event`。
+fn event_type(name: &str) -> &'static str {
+    match name {
+        "click" | "dblclick" | "auxclick" | "contextmenu" | "mousedown" | "mouseup"
+        | "mousemove" | "mouseover" | "mouseout" | "mouseenter" | "mouseleave" => "MouseEvent",
+        "keydown" | "keyup" | "keypress" => "KeyboardEvent",
+        "input" | "beforeinput" => "InputEvent",
+        "submit" => "SubmitEvent",
+        "focus" | "blur" | "focusin" | "focusout" => "FocusEvent",
+        "gotpointercapture" | "lostpointercapture" => "PointerEvent",
+        "drag" | "dragend" | "dragenter" | "dragexit" | "dragleave" | "dragover" | "dragstart"
+        | "drop" => "DragEvent",
+        "touchstart" | "touchend" | "touchmove" | "touchcancel" => "TouchEvent",
+        "wheel" => "WheelEvent",
+        "copy" | "cut" | "paste" => "ClipboardEvent",
+        "compositionstart" | "compositionupdate" | "compositionend" => "CompositionEvent",
+        "hashchange" => "HashChangeEvent",
+        "popstate" => "PopStateEvent",
+        "pageshow" | "pagehide" => "PageTransitionEvent",
+        "beforeunload" => "BeforeUnloadEvent",
+        "storage" => "StorageEvent",
+        "message" | "messageerror" => "MessageEvent",
+        "unhandledrejection" | "rejectionhandled" => "PromiseRejectionEvent",
+        "toggle" | "beforetoggle" => "ToggleEvent",
+        "securitypolicyviolation" => "SecurityPolicyViolationEvent",
+        "devicemotion" => "DeviceMotionEvent",
+        "gamepadconnected" | "gamepaddisconnected" => "GamepadEvent",
+        "progress" => "ProgressEvent",
+        "close" => "CloseEvent",
+        "error" => "ErrorEvent",
+        "resize" | "abort" => "UiEvent",
+        name if name.starts_with("pointer") && !name.starts_with("pointerlock") => "PointerEvent",
+        name if name.starts_with("animation") => "AnimationEvent",
+        name if name.starts_with("transition") => "TransitionEvent",
+        _ => "Event",
+    }
+}
+
+/// 写在生成行末尾的说明，解释类型选择不直观的事件
+fn event_note(name: &str) -> Option<&'static str> {
+    match name {
+        "change" => Some("'change' target is useful, but the event itself is generic"),
+        _ => None,
+    }
+}
+
+/// 生成 `silex_dom/src/event/types.rs`
+pub fn generate_events_code(events: &[EventDef]) -> String {
+    let mut code = String::new();
+    code.push_str("// 自动生成的 DOM 事件类型 (silex_codegen)，请勿手动修改\n\n");
+    code.push_str("use super::EventDescriptor;\n");
+    code.push_str("use std::borrow::Cow;\n\n");
+    code.push_str(
+        r#"macro_rules! generate_events {
+    ($($name:ident : $type:ty),* $(,)?) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[derive(Copy, Clone, Debug, Default)]
+            pub struct $name;
+
+            impl EventDescriptor for $name {
+                type EventType = $type;
+                fn name(&self) -> Cow<'static, str> {
+                    stringify!($name).into()
+                }
+            }
+        )*
+    };
+}
+
+generate_events! {
+"#,
+    );
+    for event in events {
+        code.push_str(&format!(
+            "    {}: web_sys::{},",
+            event.name, event.event_type
+        ));
+        if let Some(note) = event_note(&event.name) {
+            code.push_str(&format!(" // {}", note));
+        }
+        code.push('\n');
+    }
+    code.push_str("}\n");
+    code
+}
@@ -3,6 +3,7 @@ use std::fs;
 use std::path::Path;
 
 mod css;
+mod events;
 mod tags;
 
 use tags::codegen::generate_module_content;
@@ -13,37 +14,38 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 1. Determine paths
     let current_dir = std::env::current_dir()?;
-    let (mdn_compat_path, mdn_props_path, mdn_syntaxes_path, out_dir, css_out_dir) = if current_dir
-        .join("tools/silex_codegen")
-        .exists()
-    {
-        (
-            current_dir.join("tools/silex_codegen/mdn_compat_data.json"),
-            current_dir.join("tools/silex_codegen/mdn_css_properties.json"),
-            current_dir.join("tools/silex_codegen/mdn_css_syntaxes.json"),
-            current_dir.join("silex_html/src/tags"),
-            current_dir.join("silex_css/src"),
-        )
-    } else if current_dir.ends_with("silex_codegen") {
-        (
-            current_dir.join("mdn_compat_data.json"),
-            current_dir.join("mdn_css_properties.json"),
-            current_dir.join("mdn_css_syntaxes.json"),
-            current_dir.join("../../silex_html/src/tags"),
-            current_dir.join("../../silex_css/src"),
-        )
-    } else {
-        return Err(
+    let (mdn_compat_path, mdn_props_path, mdn_syntaxes_path, out_dir, css_out_dir, event_out_dir) =
+        if current_dir.join("tools/silex_codegen").exists() {
+            (
+                current_dir.join("tools/silex_codegen/mdn_compat_data.json"),
+                current_dir.join("tools/silex_codegen/mdn_css_properties.json"),
+                current_dir.join("tools/silex_codegen/mdn_css_syntaxes.json"),
+                current_dir.join("silex_html/src/tags"),
+                current_dir.join("silex_css/src"),
+                current_dir.join("silex_dom/src/event"),
+            )
+        } else if current_dir.ends_with("silex_codegen") {
+            (
+                current_dir.join("mdn_compat_data.json"),
+                current_dir.join("mdn_css_properties.json"),
+                current_dir.join("mdn_css_syntaxes.json"),
+                current_dir.join("../../silex_html/src/tags"),
+                current_dir.join("../../silex_css/src"),
+                current_dir.join("../../silex_dom/src/event"),
+            )
+        } else {
+            return Err(
                 "Could not detect project root. Please run from workspace root or tools/silex_codegen directory."
                     .into(),
             );
-    };
+        };
 
     println!("MDN Compat: {}", mdn_compat_path.display());
     println!("MDN Props:  {}", mdn_props_path.display());
     println!("MDN Syntax: {}", mdn_syntaxes_path.display());
     println!("Output dir: {}", out_dir.display());
     println!("CSS dir:    {}", css_out_dir.display());
+    println!("Event dir:  {}", event_out_dir.display());
 
     // 2. FETCH MODE: Raw data downloader
     if should_fetch {
@@ -94,6 +96,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let config = tags::parse_tags(&compat_str)?;
     let css_config = css::parse_css(&props_str, &syntaxes_str)?;
+    let event_config = events::parse_events(&compat_str)?;
 
     println!("[CODEGEN MODE] Applying in-memory patches...");
     let mut gen_config = config.clone();
@@ -137,6 +140,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     fs::write(out_dir.join("svg.rs"), svg_code)?;
     println!("Generated svg.rs");
 
+    // Generate DOM event types
+    let events_code = events::generate_events_code(&event_config);
+    fs::write(event_out_dir.join("types.rs"), events_code)?;
+    println!("Generated event/types.rs ({} events)", event_config.len());
+
     println!("\nSuccessfully completed!");
     Ok(())
 }
@@ -14,7 +14,7 @@ pub struct TagDef {
     // Optional function name override, defaults to snake_case of struct_name
     pub func_name: Option<String>,
     pub is_void: bool,
-    // List of trait names to implement (e.g. "GlobalAttributes", "FormTag")
+    // List of marker traits to implement (e.g. "TextTag", "FormTag")
     #[serde(default)]
     pub traits: Vec<String>,
 }
@@ -161,6 +161,8 @@ pub fn apply_memory_only_patches(config: &mut TagConfig) {
     }
 
     // 3. Apply Trait patches
+    // GlobalAttributes / AriaAttributes 由 silex_dom 为所有 AttributeBuilder 统一实现，
+    // 逐个标签生成实现会与其冲突，这里只添加按标签区分的 Marker
     for tag in &mut config.html {
         let name = tag.tag_name.clone();
         match name.as_str() {